@@ -0,0 +1,85 @@
+//! Compares the cost of `io::read` and `io::write` with the cost of the codec (image crate) alone
+//!
+//! Run from project directory using
+//! cargo bench --bench io
+extern crate vision_x;
+
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use image::{DynamicImage, ImageBuffer, Rgb};
+use vision_x::core::Image;
+use vision_x::errors::VisionXResult;
+use vision_x::io;
+
+const ITERATIONS: u32 = 10;
+
+/// Runs the closure `ITERATIONS` times and returns the average time taken
+fn measure<F: FnMut() -> VisionXResult<()>>(mut f: F) -> VisionXResult<Duration> {
+    // warm up (file system cache, allocator)
+    f()?;
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f()?;
+    }
+
+    Ok(start.elapsed() / ITERATIONS)
+}
+
+/// Prints the average time taken by vision_x and the codec alone
+fn report(name: &str, vision_x: Duration, codec: Duration) {
+    let overhead = vision_x.as_secs_f64() / codec.as_secs_f64();
+    println!(
+        "{:<28} vision_x: {:>10.2?}  codec: {:>10.2?}  ratio: {:.2}",
+        name, vision_x, codec, overhead
+    );
+}
+
+/// Benchmarks read and write operation for the image stored at path
+fn bench_file(name: &str, path: &Path, output: &Path) -> VisionXResult<()> {
+    let read = measure(|| {
+        black_box(io::read(path)?);
+        Ok(())
+    })?;
+    let decode = measure(|| {
+        black_box(image::open(path)?);
+        Ok(())
+    })?;
+    report(&format!("read {name}"), read, decode);
+
+    let img: Image = io::read(path)?;
+    let raw_image: DynamicImage = image::open(path)?;
+    let write = measure(|| io::write(output, &img))?;
+    let encode = measure(|| {
+        raw_image.save(output)?;
+        Ok(())
+    })?;
+    report(&format!("write {name}"), write, encode);
+
+    Ok(())
+}
+
+fn main() -> VisionXResult<()> {
+    let dir = std::env::temp_dir().join("vision_x-bench");
+    std::fs::create_dir_all(&dir)?;
+
+    bench_file(
+        "jpg (lenna)",
+        Path::new("images/jpg/lenna.jpg"),
+        &dir.join("lenna.bmp"),
+    )?;
+
+    // a large synthetic image stored with a fast codec, so that copies are not hidden by the codec
+    let (width, height) = (4000, 3000);
+    let large: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::from_fn(width, height, |x, y| {
+        Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8])
+    });
+    let large_path = dir.join("large.bmp");
+    large.save(&large_path)?;
+    bench_file("bmp (4000x3000)", &large_path, &dir.join("large-out.bmp"))?;
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use crate::{
+    core::{Image, ImageData},
+    errors::{VisionXError, VisionXErrorKind},
+};
+
+use image::{DynamicImage, ImageBuffer, Luma, LumaA, Rgb, Rgba};
+
+/// Implements conversions between `ImageData<T, N>` and `image::ImageBuffer<P, Vec<T>>` for a pixel type `P` with `N` channels
+///
+/// Both types store pixel values contiguously in row-major order, hence, the buffer is moved instead of copying pixel by pixel
+macro_rules! impl_image_buffer_conversion {
+    ($($pixel:ident<$subpixel:ty>, $channels:literal;)*) => {
+        $(
+            /// Converts an `image::ImageBuffer` into `ImageData` by moving its buffer (no pixel is copied)
+            impl From<ImageBuffer<$pixel<$subpixel>, Vec<$subpixel>>> for ImageData<$subpixel, $channels> {
+                fn from(buffer: ImageBuffer<$pixel<$subpixel>, Vec<$subpixel>>) -> Self {
+                    let (width, height) = buffer.dimensions();
+                    let mut buffer: Vec<$subpixel> = buffer.into_raw();
+                    // `ImageBuffer` allows a container which is larger than the image
+                    buffer.truncate(width as usize * height as usize * $channels);
+
+                    ImageData::from_raw_vec(width, height, buffer)
+                        .expect("buffer length always matches the image dimension")
+                }
+            }
+
+            /// Converts `ImageData` into an `image::ImageBuffer` by moving its buffer (no pixel is copied)
+            ///
+            /// Returns `Err` if the width and height of the `ImageData` don't match its pixels
+            impl TryFrom<ImageData<$subpixel, $channels>> for ImageBuffer<$pixel<$subpixel>, Vec<$subpixel>> {
+                type Error = VisionXError;
+
+                fn try_from(img: ImageData<$subpixel, $channels>) -> Result<Self, Self::Error> {
+                    let (width, height) = (*img.width(), *img.height());
+                    let buffer = img.into_raw_vec();
+                    let actual = buffer.len();
+                    ImageBuffer::from_raw(width, height, buffer).ok_or(VisionXError::BufferSize {
+                        expected: width as usize * height as usize * $channels,
+                        actual,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_image_buffer_conversion! {
+    Luma<u8>, 1;
+    LumaA<u8>, 2;
+    Rgb<u8>, 3;
+    Rgba<u8>, 4;
+    Luma<u16>, 1;
+    LumaA<u16>, 2;
+    Rgb<u16>, 3;
+    Rgba<u16>, 4;
+    Luma<f32>, 1;
+    LumaA<f32>, 2;
+    Rgb<f32>, 3;
+    Rgba<f32>, 4;
+}
+
+/// Converts an `image::DynamicImage` into `Image` by moving its buffer (no pixel is copied)
+///
+/// Returns `Err` for color types which are not represented by `Image` (`image::DynamicImage` is non-exhaustive)
+///
+/// # Example
+///
+/// ```
+/// use vision_x::core::Image;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let dynamic_img = image::open("images/jpg/lenna.jpg")?;
+/// let img = Image::try_from(dynamic_img)?;
+/// assert_eq!(img.to_str(), "rgb");
+/// # Ok(()) }
+/// ```
+impl TryFrom<DynamicImage> for Image {
+    type Error = VisionXError;
+
+    fn try_from(raw_image: DynamicImage) -> Result<Self, Self::Error> {
+        let img = match raw_image {
+            DynamicImage::ImageLuma8(grayscale) => Image::ImageGrayscale(grayscale.into()),
+            DynamicImage::ImageLumaA8(grayscale_alpha) => {
+                Image::ImageGrayscaleAlpha(grayscale_alpha.into())
+            }
+            DynamicImage::ImageRgb8(rgb) => Image::ImageRgb(rgb.into()),
+            DynamicImage::ImageRgba8(rgba) => Image::ImageRgba(rgba.into()),
+            DynamicImage::ImageLuma16(grayscale16) => Image::ImageGrayscale16(grayscale16.into()),
+            DynamicImage::ImageLumaA16(grayscale_alpha16) => {
+                Image::ImageGrayscaleAlpha16(grayscale_alpha16.into())
+            }
+            DynamicImage::ImageRgb16(rgb16) => Image::ImageRgb16(rgb16.into()),
+            DynamicImage::ImageRgba16(rgba16) => Image::ImageRgba16(rgba16.into()),
+            DynamicImage::ImageRgb32F(rgb32f) => Image::ImageRgb32F(rgb32f.into()),
+            DynamicImage::ImageRgba32F(rgba32f) => Image::ImageRgba32F(rgba32f.into()),
+            value => {
+                return Err(VisionXErrorKind::InvalidColorType(format!(
+                    "converting dynamic image of color type {:?} to image",
+                    value.color()
+                ))
+                .into())
+            }
+        };
+        Ok(img)
+    }
+}
+
+/// Converts `Image` into an `image::DynamicImage` by moving its buffer (no pixel is copied)
+///
+/// Returns `Err` for `Image::ImageHsv`, `Image::ImageGrayscale32F` and `Image::ImageGrayscaleAlpha32F` as `image::DynamicImage` has no equivalent color type
+///
+/// # Example
+///
+/// ```
+/// use image::DynamicImage;
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let img = io::read("images/jpg/lenna.jpg")?;
+/// let dynamic_img = DynamicImage::try_from(img.grayscale())?;
+/// assert!(dynamic_img.as_luma8().is_some());
+/// # Ok(()) }
+/// ```
+impl TryFrom<Image> for DynamicImage {
+    type Error = VisionXError;
+
+    fn try_from(img: Image) -> Result<Self, Self::Error> {
+        match img {
+            Image::ImageGrayscale(grayscale) => Ok(DynamicImage::ImageLuma8(grayscale.try_into()?)),
+            Image::ImageGrayscaleAlpha(grayscale_alpha) => {
+                Ok(DynamicImage::ImageLumaA8(grayscale_alpha.try_into()?))
+            }
+            Image::ImageRgb(rgb) => Ok(DynamicImage::ImageRgb8(rgb.try_into()?)),
+            Image::ImageRgba(rgba) => Ok(DynamicImage::ImageRgba8(rgba.try_into()?)),
+            Image::ImageGrayscale16(grayscale16) => {
+                Ok(DynamicImage::ImageLuma16(grayscale16.try_into()?))
+            }
+            Image::ImageGrayscaleAlpha16(grayscale_alpha16) => {
+                Ok(DynamicImage::ImageLumaA16(grayscale_alpha16.try_into()?))
+            }
+            Image::ImageRgb16(rgb16) => Ok(DynamicImage::ImageRgb16(rgb16.try_into()?)),
+            Image::ImageRgba16(rgba16) => Ok(DynamicImage::ImageRgba16(rgba16.try_into()?)),
+            Image::ImageRgb32F(rgb32f) => Ok(DynamicImage::ImageRgb32F(rgb32f.try_into()?)),
+            Image::ImageRgba32F(rgba32f) => Ok(DynamicImage::ImageRgba32F(rgba32f.try_into()?)),
+            value => Err(VisionXErrorKind::InvalidColorType(format!(
+                "converting {} image to dynamic image",
+                value.to_str()
+            ))
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod convert_test {
+    use crate::core::{Image, ImageData};
+    use image::{DynamicImage, ImageBuffer, Luma, Rgb};
+    use ndarray::Array2;
+
+    // Buffers are moved between `ImageBuffer` and `ImageData` without copying
+    #[test]
+    fn image_buffer_roundtrip() {
+        let buffer: ImageBuffer<Rgb<u16>, Vec<u16>> =
+            ImageBuffer::from_fn(5, 3, |x, y| Rgb([x as u16, y as u16, 1000]));
+        let ptr = buffer.as_raw().as_ptr();
+
+        let raw_img: ImageData<u16, 3> = buffer.into();
+        assert_eq!(raw_img.get_pixel_at(4, 2), Some(&[4, 2, 1000]));
+        assert_eq!(raw_img.as_raw_slice().as_ptr(), ptr);
+
+        let buffer: ImageBuffer<Rgb<u16>, Vec<u16>> = raw_img.try_into().unwrap();
+        assert_eq!(buffer.as_raw().as_ptr(), ptr);
+        assert_eq!(buffer.get_pixel(4, 2), &Rgb([4, 2, 1000]));
+    }
+
+    // `ImageData` with a width and height not matching its pixels can't be converted
+    #[test]
+    fn image_buffer_invalid_size() {
+        let mut raw_img: ImageData<u8, 1> =
+            ImageData::new(4, 4, Array2::from_elem((4, 4), [0u8; 1]));
+        raw_img.set_width(8);
+
+        let res: Result<ImageBuffer<Luma<u8>, Vec<u8>>, _> = raw_img.try_into();
+        assert!(res.is_err());
+    }
+
+    // Every color type of `DynamicImage` is converted to its `Image` equivalent and back
+    #[test]
+    fn dynamic_image_roundtrip() {
+        let dynamic_imgs: [DynamicImage; 4] = [
+            DynamicImage::new_luma8(4, 3),
+            DynamicImage::new_luma_a16(4, 3),
+            DynamicImage::new_rgba8(4, 3),
+            DynamicImage::new_rgb32f(4, 3),
+        ];
+        let expected: [&str; 4] = ["grayscale", "grayscale_alpha16", "rgba", "rgb32f"];
+
+        for (dynamic_img, expected) in dynamic_imgs.into_iter().zip(expected) {
+            let color = dynamic_img.color();
+            let img = Image::try_from(dynamic_img).unwrap();
+            assert_eq!(img.to_str(), expected);
+
+            let dynamic_img = DynamicImage::try_from(img).unwrap();
+            assert_eq!(dynamic_img.color(), color);
+            assert_eq!((dynamic_img.width(), dynamic_img.height()), (4, 3));
+        }
+
+        let hsv = Image::ImageHsv(ImageData::new(4, 3, Array2::from_elem((3, 4), [0f32; 3])));
+        assert!(DynamicImage::try_from(hsv).is_err());
+    }
+}
@@ -1,6 +1,6 @@
-use crate::errors::{VisionXErrorKind, VisionXResult};
+use crate::errors::{VisionXError, VisionXErrorKind, VisionXResult};
 
-use ndarray::Array2;
+use ndarray::{Array2, Array3, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, Axis};
 
 /// Store pixel values of an image in HSV colorspace
 pub type Hsv = ImageData<f32, 3>;
@@ -20,6 +20,72 @@ pub type GrayscaleAlpha16 = ImageData<u16, 2>;
 pub type Rgb16 = ImageData<u16, 3>;
 /// Store pixel values of an image in RGBA colorspace with 16bit color depth
 pub type Rgba16 = ImageData<u16, 4>;
+/// Store pixel values of a grayscale image with 32bit float color depth (normalised to `[0.0, 1.0]`)
+pub type Grayscale32F = ImageData<f32, 1>;
+/// Store pixel values of a grayscale + alpha channel image with 32bit float color depth (normalised to `[0.0, 1.0]`)
+pub type GrayscaleAlpha32F = ImageData<f32, 2>;
+/// Store pixel values of an image in RGB colorspace with 32bit float color depth (normalised to `[0.0, 1.0]`)
+pub type Rgb32F = ImageData<f32, 3>;
+/// Store pixel values of an image in RGBA colorspace with 32bit float color depth (normalised to `[0.0, 1.0]`)
+pub type Rgba32F = ImageData<f32, 4>;
+/// Store the labels of an image (e.g. connected components) as 32bit unsigned integers, `0` being the background
+pub type Labels = ImageData<u32, 1>;
+
+/// `PixelDepth` is implemented by every channel type that can be stored inside `ImageData`
+///
+/// Each depth maps its values onto the normalised range `[0.0, 1.0]`, which is used as the common ground while converting between depths (see `ImageData::convert_depth()`)
+///
+/// # Example
+///
+/// ```
+/// use vision_x::core::PixelDepth;
+///
+/// # fn main() {
+/// assert_eq!(255u8.to_normalized(), 1.0);
+/// assert_eq!(u16::from_normalized(1.0), u16::MAX);
+/// assert_eq!(u16::from_normalized(128u8.to_normalized()), 128 * 257);
+/// # }
+/// ```
+pub trait PixelDepth: Default + Copy {
+    /// Returns the channel value mapped onto `[0.0, 1.0]`
+    fn to_normalized(self) -> f32;
+
+    /// Creates a channel value from a normalised value. Values outside `[0.0, 1.0]` are clamped for integer depths
+    fn from_normalized(value: f32) -> Self;
+}
+
+/// Implements `PixelDepth` for 8bit color depth
+impl PixelDepth for u8 {
+    fn to_normalized(self) -> f32 {
+        self as f32 / u8::MAX as f32
+    }
+
+    fn from_normalized(value: f32) -> Self {
+        (value.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8
+    }
+}
+
+/// Implements `PixelDepth` for 16bit color depth
+impl PixelDepth for u16 {
+    fn to_normalized(self) -> f32 {
+        self as f32 / u16::MAX as f32
+    }
+
+    fn from_normalized(value: f32) -> Self {
+        (value.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+    }
+}
+
+/// Implements `PixelDepth` for 32bit float color depth. Values are stored as is (no clamping)
+impl PixelDepth for f32 {
+    fn to_normalized(self) -> f32 {
+        self
+    }
+
+    fn from_normalized(value: f32) -> Self {
+        value
+    }
+}
 
 /// `Image` represents a set of colors available in the image processing library. The supported color spaces are: **Rgb, Rgba, Grayscale, GrayscaleAlpha, Hsv**
 ///
@@ -67,6 +133,18 @@ pub enum Image {
     ImageRgb16(Rgb16),
     /// Represents an image in RGBA colorspace with 16bit color depth
     ImageRgba16(Rgba16),
+    /// Represents a grayscale image with 32bit float color depth
+    ///
+    /// Note: Cannot be used in `io::write()`
+    ImageGrayscale32F(Grayscale32F),
+    /// Represents a grayscale + alpha channel image with 32bit float color depth
+    ///
+    /// Note: Cannot be used in `io::write()`
+    ImageGrayscaleAlpha32F(GrayscaleAlpha32F),
+    /// Represents an image in RGB colorspace with 32bit float color depth
+    ImageRgb32F(Rgb32F),
+    /// Represents an image in RGBA colorspace with 32bit float color depth
+    ImageRgba32F(Rgba32F),
     /// Represents an image in the HSV colorspace
     ///
     /// Note: Cannot be used in `io::write()`
@@ -112,6 +190,10 @@ impl Image {
             Image::ImageRgb16(_) => "rgb16",
             Image::ImageRgba(_) => "rgba",
             Image::ImageRgba16(_) => "rgba16",
+            Image::ImageGrayscale32F(_) => "grayscale32f",
+            Image::ImageGrayscaleAlpha32F(_) => "grayscale_alpha32f",
+            Image::ImageRgb32F(_) => "rgb32f",
+            Image::ImageRgba32F(_) => "rgba32f",
             Image::ImageHsv(_) => "hsv",
         }
     }
@@ -163,7 +245,7 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
         Self {
             width,
             height,
-            pixels,
+            pixels: Self::standard_layout(pixels),
         }
     }
 
@@ -196,14 +278,7 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
     /// # }
     /// ```
     pub fn flatten_pixels(&self) -> Vec<T> {
-        let mut values = Vec::new();
-        self.pixels.for_each(|px_vec| {
-            for px in px_vec {
-                values.push(*px);
-            }
-        });
-
-        values
+        self.as_raw_slice().to_vec()
     }
 
     // Getters
@@ -298,6 +373,46 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
         &self.pixels
     }
 
+    /// Returns a read-only view (`ImageView`) of the whole image without copying any pixel
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// # let pixels = Array2::from_elem((128, 128), [0u8; 1]);
+    /// // ..
+    /// let raw_img = ImageData::new(128, 128, pixels);
+    /// let view = raw_img.view();
+    /// assert_eq!(view.get_pixel_at(12, 12), raw_img.get_pixel_at(12, 12));
+    /// # }
+    /// ```
+    pub fn view(&self) -> ImageView<'_, T, N> {
+        ImageView::new(self.pixels.view())
+    }
+
+    /// Returns a mutable view (`ImageViewMut`) of the whole image without copying any pixel. Changes made through the view are written into the image
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// # let pixels = Array2::from_elem((128, 128), [0u8; 1]);
+    /// // ..
+    /// let mut raw_img = ImageData::new(128, 128, pixels);
+    /// raw_img.view_mut().fill([128]);
+    /// assert_eq!(raw_img.get_pixel_at(12, 12), Some(&[128]));
+    /// # }
+    /// ```
+    pub fn view_mut(&mut self) -> ImageViewMut<'_, T, N> {
+        ImageViewMut::new(self.pixels.view_mut())
+    }
+
     // Setters
 
     /// Set height of the image. Requires a mutable object of `ImageData` and accepts height in `u32`
@@ -401,7 +516,7 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
     /// # }
     /// ```
     pub fn set_pixels(&mut self, pixels: PixelNdArray<T, N>) {
-        self.pixels = pixels;
+        self.pixels = Self::standard_layout(pixels);
     }
 
     // pixel manipulators
@@ -477,12 +592,642 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
             return Ok(());
         }
 
-        //
-        let err = format!(
-            "({}, {}) for size ({}, {})",
-            &x, &y, &self.width, &self.height
+        Err(VisionXError::OutOfBounds {
+            point: (x as u32, y as u32),
+            size: (self.width, self.height),
+        })
+    }
+
+    /// Makes sure the pixels are stored contiguously in row-major order. Copies only if the array has a different memory layout
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn standard_layout(pixels: PixelNdArray<T, N>) -> PixelNdArray<T, N> {
+        if pixels.is_standard_layout() {
+            pixels
+        } else {
+            pixels.as_standard_layout().into_owned()
+        }
+    }
+}
+
+/// Interop implementation of struct `ImageData` with `ndarray` and raw buffers
+///
+/// Pixels are always stored contiguously in row-major order (height, width, channel), i.e., **HWC** layout. Hence, the views and conversions to/from HWC buffers share the same memory and do not copy any pixel value
+impl<T: Default + Copy, const N: usize> ImageData<T, N> {
+    /// Creates a new `ImageData` object from a tightly packed HWC buffer without copying it. Accepts width, height and the buffer in `Vec<T>` of length `width * height * N`
+    ///
+    /// Returns `Err` if the length of the buffer doesn't match the dimension of the image
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let buffer: Vec<u8> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    /// let raw_img: ImageData<u8, 3> = ImageData::from_raw_vec(2, 2, buffer)?;
+    /// assert_eq!(raw_img.get_pixel_at(1, 0), Some(&[3, 4, 5]));
+    /// # Ok(()) }
+    /// ```
+    pub fn from_raw_vec(width: u32, height: u32, mut buffer: Vec<T>) -> VisionXResult<Self> {
+        let (rows, cols) = (height as usize, width as usize);
+        if N == 0 {
+            let err = format!(
+                "creating image data of size ({}, {}) with 0 channels",
+                width, height
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+        let Some(expected) = rows.checked_mul(cols).and_then(|len| len.checked_mul(N)) else {
+            let err = format!(
+                "creating image data of size ({}, {}) with {} channel(s), which overflows the buffer length",
+                width, height, N
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        };
+        if buffer.len() != expected {
+            return Err(VisionXError::BufferSize {
+                expected,
+                actual: buffer.len(),
+            });
+        }
+
+        // the allocation is reused only if it can be handed over as whole pixels
+        if buffer.capacity() % N != 0 {
+            buffer.shrink_to_fit();
+        }
+        let pixels_vec: Vec<[T; N]> = if buffer.capacity() % N == 0 {
+            let mut buffer = std::mem::ManuallyDrop::new(buffer);
+            let (ptr, len, capacity) = (buffer.as_mut_ptr(), buffer.len(), buffer.capacity());
+            // SAFETY: `[T; N]` has the same alignment as `T` and a size of `N * size_of::<T>()`,
+            // both length and capacity are multiples of `N`, and ownership of the allocation is
+            // moved out of `buffer` which is never dropped
+            unsafe { Vec::from_raw_parts(ptr as *mut [T; N], len / N, capacity / N) }
+        } else {
+            buffer
+                .chunks_exact(N)
+                .map(|px_vec: &[T]| std::array::from_fn(|i| px_vec[i]))
+                .collect()
+        };
+
+        let actual = pixels_vec.len() * N;
+        let pixels = Array2::from_shape_vec((rows, cols), pixels_vec)
+            .map_err(|_| VisionXError::BufferSize { expected, actual })?;
+        Ok(Self::new(width, height, pixels))
+    }
+
+    /// Creates a new `ImageData` object by copying an HWC buffer. Accepts width, height, the buffer in `&[T]` and row stride (number of `T` values between the start of two consecutive rows)
+    ///
+    /// Row stride can be larger than `width * N` for buffers having padded rows. Returns `Err` if the row stride or the buffer is too small
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // each row holds two grayscale pixels followed by two bytes of padding
+    /// let buffer: [u8; 8] = [10, 20, 0, 0, 30, 40, 0, 0];
+    /// let raw_img: ImageData<u8, 1> = ImageData::from_raw_slice(2, 2, &buffer, 4)?;
+    /// assert_eq!(raw_img.flatten_pixels(), vec![10, 20, 30, 40]);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_raw_slice(
+        width: u32,
+        height: u32,
+        buffer: &[T],
+        row_stride: usize,
+    ) -> VisionXResult<Self> {
+        let (rows, cols) = (height as usize, width as usize);
+        let row_len = cols.checked_mul(N);
+        // `None` if the size of the buffer can't be represented
+        let required_len = row_len.and_then(|row_len| match rows {
+            0 => Some(0),
+            _ => (rows - 1)
+                .checked_mul(row_stride)
+                .and_then(|len| len.checked_add(row_len)),
+        });
+        let (Some(row_len), Some(required_len)) = (row_len, required_len) else {
+            let err = format!(
+                "creating image data of size ({}, {}) with {} channel(s) and row stride {}, which overflows the buffer length",
+                width, height, N, row_stride
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        };
+        if N == 0 || row_stride < row_len {
+            let err = format!(
+                "creating image data of size ({}, {}) with {} channel(s) from a buffer of length {} and row stride {}",
+                width,
+                height,
+                N,
+                buffer.len(),
+                row_stride
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+        if buffer.len() < required_len {
+            return Err(VisionXError::BufferSize {
+                expected: required_len,
+                actual: buffer.len(),
+            });
+        }
+
+        let mut pixels_vec: Vec<T> = Vec::with_capacity(rows * row_len);
+        for y in 0..rows {
+            pixels_vec.extend_from_slice(&buffer[y * row_stride..y * row_stride + row_len]);
+        }
+
+        Self::from_raw_vec(width, height, pixels_vec)
+    }
+
+    /// Creates a new `ImageData` object from an array in HWC layout (height, width, channel). Doesn't copy the pixel values if the array is in standard (row-major) layout
+    ///
+    /// Returns `Err` if the size of the channel axis is not `N`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array3;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let tensor: Array3<f32> = Array3::zeros((64, 128, 3));
+    /// let raw_img: ImageData<f32, 3> = ImageData::from_hwc(tensor)?;
+    /// assert_eq!(*raw_img.width(), 128);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_hwc(array: Array3<T>) -> VisionXResult<Self> {
+        let (rows, cols, channels) = array.dim();
+        if channels != N {
+            let err = format!(
+                "creating image data with {} channel(s) from an array with {} channel(s)",
+                N, channels
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+
+        let buffer: Vec<T> = if array.is_standard_layout() {
+            array.into_raw_vec()
+        } else {
+            array.iter().copied().collect()
+        };
+        Self::from_raw_vec(cols as u32, rows as u32, buffer)
+    }
+
+    /// Creates a new `ImageData` object from an array in CHW layout (channel, height, width). Pixel values are copied as the memory layout differs from `ImageData`
+    ///
+    /// Returns `Err` if the size of the channel axis is not `N`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array3;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let tensor: Array3<f32> = Array3::zeros((3, 64, 128));
+    /// let raw_img: ImageData<f32, 3> = ImageData::from_chw(tensor.view())?;
+    /// assert_eq!(*raw_img.height(), 64);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_chw(array: ArrayView3<T>) -> VisionXResult<Self> {
+        let hwc = array.permuted_axes([1, 2, 0]);
+        let (rows, cols, channels) = hwc.dim();
+        if channels != N {
+            let err = format!(
+                "creating image data with {} channel(s) from an array with {} channel(s)",
+                N, channels
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+
+        Self::from_raw_vec(cols as u32, rows as u32, hwc.iter().copied().collect())
+    }
+
+    /// Returns all the pixel values of the image as a flat HWC slice (`&[T]`) without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let raw_img = ImageData::new(3, 2, pixels);
+    /// assert_eq!(raw_img.as_raw_slice().len(), 2 * 3 * 3);
+    /// # }
+    /// ```
+    pub fn as_raw_slice(&self) -> &[T] {
+        let pixels: &[[T; N]] = self
+            .pixels
+            .as_slice()
+            .expect("pixels are always stored in standard layout");
+        // SAFETY: `[T; N]` is laid out as `N` consecutive values of `T`
+        unsafe { std::slice::from_raw_parts(pixels.as_ptr() as *const T, pixels.len() * N) }
+    }
+
+    /// Returns all the pixel values of the image as a mutable flat HWC slice (`&mut [T]`) without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let mut raw_img = ImageData::new(3, 2, pixels);
+    /// raw_img.as_raw_slice_mut()[0] = 255;
+    /// assert_eq!(raw_img.get_pixel_at(0, 0), Some(&[255, 2, 3]));
+    /// # }
+    /// ```
+    pub fn as_raw_slice_mut(&mut self) -> &mut [T] {
+        let pixels: &mut [[T; N]] = self
+            .pixels
+            .as_slice_mut()
+            .expect("pixels are always stored in standard layout");
+        // SAFETY: `[T; N]` is laid out as `N` consecutive values of `T`
+        unsafe { std::slice::from_raw_parts_mut(pixels.as_mut_ptr() as *mut T, pixels.len() * N) }
+    }
+
+    /// Consumes the object and returns the pixel values as a flat HWC buffer (`Vec<T>`) without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let raw_img = ImageData::new(3, 2, pixels);
+    /// assert_eq!(raw_img.into_raw_vec()[..3], [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn into_raw_vec(self) -> Vec<T> {
+        let mut pixels_vec = std::mem::ManuallyDrop::new(self.pixels.into_raw_vec());
+        let (ptr, len, capacity) = (
+            pixels_vec.as_mut_ptr(),
+            pixels_vec.len(),
+            pixels_vec.capacity(),
         );
-        Err(Box::new(VisionXErrorKind::IndexOutofBound(err)))
+        // SAFETY: `[T; N]` has the same alignment as `T` and a size of `N * size_of::<T>()`, and
+        // ownership of the allocation is moved out of `pixels_vec` which is never dropped
+        unsafe { Vec::from_raw_parts(ptr as *mut T, len * N, capacity * N) }
+    }
+
+    /// Returns a view of the pixel values as `ArrayView3<T>` in HWC layout (height, width, channel) sharing the same memory
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let raw_img = ImageData::new(3, 2, pixels);
+    /// let view = raw_img.as_hwc();
+    /// assert_eq!(view.dim(), (2, 3, 3));
+    /// assert_eq!(view[(1, 2, 2)], 3);
+    /// # }
+    /// ```
+    pub fn as_hwc(&self) -> ArrayView3<'_, T> {
+        let shape = (self.pixels.nrows(), self.pixels.ncols(), N);
+        ArrayView3::from_shape(shape, self.as_raw_slice())
+            .expect("pixel buffer always matches the image dimension")
+    }
+
+    /// Returns a mutable view of the pixel values as `ArrayViewMut3<T>` in HWC layout (height, width, channel) sharing the same memory
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let mut raw_img = ImageData::new(3, 2, pixels);
+    /// raw_img.as_hwc_mut()[(1, 2, 0)] = 0;
+    /// assert_eq!(raw_img.get_pixel_at(2, 1), Some(&[0, 2, 3]));
+    /// # }
+    /// ```
+    pub fn as_hwc_mut(&mut self) -> ArrayViewMut3<'_, T> {
+        let shape = (self.pixels.nrows(), self.pixels.ncols(), N);
+        ArrayViewMut3::from_shape(shape, self.as_raw_slice_mut())
+            .expect("pixel buffer always matches the image dimension")
+    }
+
+    /// Returns a view of the pixel values as `ArrayView3<T>` in CHW layout (channel, height, width) sharing the same memory
+    ///
+    /// The view is not contiguous, use `ImageData::to_chw()` to get a contiguous array
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let raw_img = ImageData::new(3, 2, pixels);
+    /// let view = raw_img.as_chw();
+    /// assert_eq!(view.dim(), (3, 2, 3));
+    /// assert_eq!(view[(2, 1, 0)], 3);
+    /// # }
+    /// ```
+    pub fn as_chw(&self) -> ArrayView3<'_, T> {
+        self.as_hwc().permuted_axes([2, 0, 1])
+    }
+
+    /// Creates a contiguous array of the pixel values in CHW layout (channel, height, width). Pixel values are copied as the memory layout differs from `ImageData`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((2, 3), [1u8, 2, 3]);
+    /// let raw_img = ImageData::new(3, 2, pixels);
+    /// let tensor = raw_img.to_chw();
+    /// assert!(tensor.is_standard_layout());
+    /// assert_eq!(tensor.index_axis(ndarray::Axis(0), 1).sum(), 2 * 6);
+    /// # }
+    /// ```
+    pub fn to_chw(&self) -> Array3<T> {
+        let mut tensor: Array3<T> =
+            Array3::from_elem((N, self.pixels.nrows(), self.pixels.ncols()), T::default());
+        for (c, mut plane) in tensor.axis_iter_mut(Axis(0)).enumerate() {
+            plane.zip_mut_with(&self.pixels, |px: &mut T, px_vec: &[T; N]| *px = px_vec[c]);
+        }
+
+        tensor
+    }
+}
+
+/// `ImageView` is a borrowed, read-only view of an image (or a region of interest of an image). It shares the pixels of an `ImageData` and never copies them
+///
+/// Created using `ImageData::view()`, `ImageData::roi()` or from any `ndarray::ArrayView2<[T; N]>`
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::{ImageData, ImageView};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let pixels = Array2::from_elem((128, 128), [0u8; 1]);
+/// let raw_img = ImageData::new(128, 128, pixels);
+///
+/// let tile: ImageView<u8, 1> = raw_img.roi((32, 32), (96, 64))?;
+/// assert_eq!(*tile.width(), 64);
+/// assert_eq!(*tile.height(), 32);
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ImageView<'a, T, const N: usize> {
+    height: u32,
+    width: u32,
+    pixels: ArrayView2<'a, [T; N]>,
+}
+
+/// Core implementation of struct `ImageView`
+impl<'a, T: Default + Copy, const N: usize> ImageView<'a, T, N> {
+    /// Creates a new `ImageView` object from a view of the pixels (type: `ndarray::ArrayView2<_>`). Width and height are taken from the shape of the view
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageView;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((64, 128), [0u8; 3]);
+    /// let view = ImageView::new(pixels.view());
+    /// assert_eq!(*view.width(), 128);
+    /// # }
+    /// ```
+    pub fn new(pixels: ArrayView2<'a, [T; N]>) -> Self {
+        let (height, width) = pixels.dim();
+        Self {
+            height: height as u32,
+            width: width as u32,
+            pixels,
+        }
+    }
+
+    /// Returns the height of the view in `&u32`
+    pub fn height(&self) -> &u32 {
+        &self.height
+    }
+
+    /// Returns the width of the view in `&u32`
+    pub fn width(&self) -> &u32 {
+        &self.width
+    }
+
+    /// Returns all the pixel values of the view in `&ArrayView2<[T; N]>`
+    pub fn pixels(&self) -> &ArrayView2<'a, [T; N]> {
+        &self.pixels
+    }
+
+    /// Get pixel values at coordinate (x, y), relative to the top-left corner of the view. Returns `None` if (x, y) is not within the view's dimension
+    pub fn get_pixel_at(&self, x: usize, y: usize) -> Option<&[T; N]> {
+        self.pixels.get((y, x))
+    }
+
+    /// Copies the pixels of the view into a new `ImageData` object
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::{ImageData, ImageView};
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((64, 128), [0u8; 3]);
+    /// let raw_img: ImageData<u8, 3> = ImageView::new(pixels.view()).to_owned();
+    /// assert_eq!(*raw_img.height(), 64);
+    /// # }
+    /// ```
+    pub fn to_owned(&self) -> ImageData<T, N> {
+        ImageData::new(self.width, self.height, self.pixels.to_owned())
+    }
+}
+
+/// `ImageViewMut` is a borrowed, mutable view of an image (or a region of interest of an image). Any change made through the view is written directly into the pixels of the `ImageData` it borrows from
+///
+/// Created using `ImageData::view_mut()`, `ImageData::roi_mut()` or from any `ndarray::ArrayViewMut2<[T; N]>`
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::ImageData;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let pixels = Array2::from_elem((128, 128), [0u8; 1]);
+/// let mut raw_img = ImageData::new(128, 128, pixels);
+///
+/// let mut tile = raw_img.roi_mut((32, 32), (96, 64))?;
+/// tile.fill([255]);
+/// assert_eq!(raw_img.get_pixel_at(32, 32), Some(&[255]));
+/// assert_eq!(raw_img.get_pixel_at(96, 32), Some(&[0]));
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct ImageViewMut<'a, T, const N: usize> {
+    height: u32,
+    width: u32,
+    pixels: ArrayViewMut2<'a, [T; N]>,
+}
+
+/// Core implementation of struct `ImageViewMut`
+impl<'a, T: Default + Copy, const N: usize> ImageViewMut<'a, T, N> {
+    /// Creates a new `ImageViewMut` object from a mutable view of the pixels (type: `ndarray::ArrayViewMut2<_>`). Width and height are taken from the shape of the view
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageViewMut;
+    ///
+    /// # fn main() {
+    /// let mut pixels = Array2::from_elem((64, 128), [0u8; 3]);
+    /// let view = ImageViewMut::new(pixels.view_mut());
+    /// assert_eq!(*view.height(), 64);
+    /// # }
+    /// ```
+    pub fn new(pixels: ArrayViewMut2<'a, [T; N]>) -> Self {
+        let (height, width) = pixels.dim();
+        Self {
+            height: height as u32,
+            width: width as u32,
+            pixels,
+        }
+    }
+
+    /// Returns the height of the view in `&u32`
+    pub fn height(&self) -> &u32 {
+        &self.height
+    }
+
+    /// Returns the width of the view in `&u32`
+    pub fn width(&self) -> &u32 {
+        &self.width
+    }
+
+    /// Returns all the pixel values of the view in `&ArrayViewMut2<[T; N]>`
+    pub fn pixels(&self) -> &ArrayViewMut2<'a, [T; N]> {
+        &self.pixels
+    }
+
+    /// Returns all the pixel values of the view in `&mut ArrayViewMut2<[T; N]>`
+    pub fn pixels_mut(&mut self) -> &mut ArrayViewMut2<'a, [T; N]> {
+        &mut self.pixels
+    }
+
+    /// Consumes the view and returns the underlying `ArrayViewMut2<[T; N]>` keeping the original lifetime
+    pub fn into_pixels(self) -> ArrayViewMut2<'a, [T; N]> {
+        self.pixels
+    }
+
+    /// Returns a read-only view reborrowing the same pixels
+    pub fn view(&self) -> ImageView<'_, T, N> {
+        ImageView::new(self.pixels.view())
+    }
+
+    /// Get pixel values at coordinate (x, y), relative to the top-left corner of the view. Returns `None` if (x, y) is not within the view's dimension
+    pub fn get_pixel_at(&self, x: usize, y: usize) -> Option<&[T; N]> {
+        self.pixels.get((y, x))
+    }
+
+    /// Set pixel values at coordinate (x, y), relative to the top-left corner of the view. Returns `Err` if (x, y) is not within the view's dimension
+    pub fn set_pixel_at(&mut self, x: usize, y: usize, value: [T; N]) -> VisionXResult<()> {
+        if let Some(pixel) = self.pixels.get_mut((y, x)) {
+            *pixel = value;
+
+            return Ok(());
+        }
+
+        Err(VisionXError::OutOfBounds {
+            point: (x as u32, y as u32),
+            size: (self.width, self.height),
+        })
+    }
+
+    /// Set every pixel of the view to the given value
+    pub fn fill(&mut self, value: [T; N]) {
+        self.pixels.fill(value);
+    }
+
+    /// Modify every pixel of the view in-place using the given closure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let pixels = Array2::from_elem((128, 128), [100u8; 3]);
+    /// let mut raw_img = ImageData::new(128, 128, pixels);
+    ///
+    /// // invert a region of the image
+    /// raw_img
+    ///     .roi_mut((0, 0), (64, 64))?
+    ///     .map_inplace(|px| *px = px.map(|value| 255 - value));
+    /// assert_eq!(raw_img.get_pixel_at(10, 10), Some(&[155; 3]));
+    /// # Ok(()) }
+    /// ```
+    pub fn map_inplace<F: FnMut(&mut [T; N])>(&mut self, f: F) {
+        self.pixels.map_inplace(f);
+    }
+
+    /// Copies the pixels of another view (of the same dimension) into this view. Returns `Err` if the dimensions differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let mut raw_img = ImageData::new(128, 128, Array2::from_elem((128, 128), [0u8; 1]));
+    /// let tile = ImageData::new(16, 16, Array2::from_elem((16, 16), [255u8; 1]));
+    ///
+    /// raw_img.roi_mut((16, 16), (32, 32))?.copy_from(&tile.view())?;
+    /// assert_eq!(raw_img.get_pixel_at(20, 20), Some(&[255]));
+    /// # Ok(()) }
+    /// ```
+    pub fn copy_from(&mut self, src: &ImageView<T, N>) -> VisionXResult<()> {
+        if self.pixels.dim() != src.pixels().dim() {
+            return Err(VisionXError::SizeMismatch {
+                expected: (self.width, self.height),
+                actual: (*src.width(), *src.height()),
+            });
+        }
+
+        self.pixels.assign(src.pixels());
+        Ok(())
+    }
+
+    /// Copies the pixels of the view into a new `ImageData` object
+    pub fn to_owned(&self) -> ImageData<T, N> {
+        ImageData::new(self.width, self.height, self.pixels.to_owned())
     }
 }
 
@@ -515,4 +1260,65 @@ mod core_test {
         let res = io::write(path, &img);
         assert!(res.is_ok());
     }
+
+    // Views and raw buffers share the memory of the pixels
+    #[test]
+    fn test_zero_copy_interop() {
+        let buffer: Vec<u16> = (0..24).collect();
+        let ptr = buffer.as_ptr();
+
+        let raw_img: ImageData<u16, 3> = ImageData::from_raw_vec(4, 2, buffer).unwrap();
+        assert_eq!(raw_img.get_pixel_at(3, 1), Some(&[21, 22, 23]));
+        assert_eq!(raw_img.as_raw_slice().as_ptr(), ptr);
+        assert_eq!(raw_img.as_hwc().as_ptr(), ptr);
+        assert_eq!(raw_img.as_hwc()[(1, 0, 2)], 14);
+
+        let chw = raw_img.to_chw();
+        assert_eq!(chw.dim(), (3, 2, 4));
+        assert_eq!(chw, raw_img.as_chw());
+        assert_eq!(chw[(1, 1, 3)], 22);
+
+        let from_chw: ImageData<u16, 3> = ImageData::from_chw(chw.view()).unwrap();
+        assert_eq!(from_chw.pixels(), raw_img.pixels());
+
+        let buffer: Vec<u16> = raw_img.into_raw_vec();
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer, (0..24).collect::<Vec<u16>>());
+    }
+
+    // Buffers with invalid sizes return `Err`
+    #[test]
+    fn test_raw_buffer_size() {
+        let res = ImageData::<u8, 3>::from_raw_vec(4, 2, vec![0u8; 23]);
+        assert!(res.is_err());
+
+        let res = ImageData::<u8, 1>::from_raw_slice(4, 2, &[0u8; 8], 3);
+        assert!(res.is_err());
+
+        let res = ImageData::<u8, 1>::from_raw_slice(4, 2, &[0u8; 9], 5);
+        assert!(res.is_ok());
+
+        // sizes which overflow the buffer length
+        let res = ImageData::<u8, 1>::from_raw_slice(2, 2, &[0u8; 16], usize::MAX);
+        assert!(res.is_err());
+        let res = ImageData::<u8, 1>::from_raw_slice(u32::MAX, 3, &[0u8; 16], usize::MAX / 2);
+        assert!(res.is_err());
+        let res = ImageData::<u64, 4096>::from_raw_vec(u32::MAX, u32::MAX, Vec::new());
+        assert!(res.is_err());
+
+        let res = ImageData::<u8, 3>::from_chw(ndarray::Array3::zeros((4, 2, 2)).view());
+        assert!(res.is_err());
+    }
+
+    // Pixels which are not in standard layout are reordered on creation
+    #[test]
+    fn test_non_standard_layout() {
+        let mut pixels = Array2::from_elem((2, 3), [0u8; 1]);
+        pixels[(0, 2)] = [7];
+        let transposed = pixels.reversed_axes();
+
+        let raw_img = ImageData::new(2, 3, transposed);
+        assert_eq!(raw_img.get_pixel_at(0, 2), Some(&[7]));
+        assert_eq!(raw_img.as_raw_slice()[4], 7);
+    }
 }
@@ -1,13 +1,171 @@
 use core::fmt;
 use std::error::Error;
 
-/// Type alias for std::error::Error
-pub type VisionXError = Box<dyn Error + 'static>;
-/// Type alias for Result<T, E>
+/// Type alias for Result<T, VisionXError>
 pub type VisionXResult<T> = Result<T, VisionXError>;
 
-/// enum VisionXErrorKind is a custom errorkind
+/// enum VisionXError is the error returned by every fallible operation
+///
+/// Errors raised by vision-x are either described by a `VisionXErrorKind` or carry structured fields (e.g. expected vs actual size). Errors raised while reading/writing files or by the codecs are wrapped as it is and returned by `Error::source()`. The error is `Send + Sync`, so it can be moved across threads
+///
+/// # Example
+///
+/// ```
+/// use vision_x::core::ImageData;
+/// use vision_x::errors::VisionXError;
+///
+/// let res = ImageData::<u8, 3>::from_raw_vec(2, 2, vec![0; 10]);
+/// match res {
+///     Err(VisionXError::BufferSize { expected, actual }) => {
+///         assert_eq!((12, 10), (expected, actual));
+///     }
+///     _ => panic!("expected a buffer size error"),
+/// }
+/// ```
 #[derive(Debug)]
+pub enum VisionXError {
+    /// Error raised by vision-x, described by its kind
+    Kind(VisionXErrorKind),
+    /// A buffer doesn't hold the number of samples (or bytes) required by the image size
+    BufferSize {
+        /// Number of samples required
+        expected: usize,
+        /// Number of samples in the buffer
+        actual: usize,
+    },
+    /// The size (width, height) of an image doesn't match the size required by an operation
+    SizeMismatch {
+        /// Size required by the operation
+        expected: (u32, u32),
+        /// Size of the image
+        actual: (u32, u32),
+    },
+    /// A point (x, y) lies outside of an image
+    OutOfBounds {
+        /// Point which is out of bounds
+        point: (u32, u32),
+        /// Size (width, height) of the image
+        size: (u32, u32),
+    },
+    /// Error while reading or writing a file
+    Io(std::io::Error),
+    /// Error raised by a codec of the image crate
+    Image(image::ImageError),
+    /// Error raised by any other codec (png, gif, tiff, webp, zstd) or the color management module
+    Codec {
+        /// Name of the codec
+        codec: &'static str,
+        /// Error raised by the codec
+        source: Box<dyn Error + Send + Sync + 'static>,
+    },
+}
+
+/// Core implementation of enum `VisionXError`
+impl VisionXError {
+    /// Returns the kind of an error raised by vision-x, `None` for errors with structured fields and errors raised while reading/writing files or by the codecs
+    pub fn kind(&self) -> Option<&VisionXErrorKind> {
+        match self {
+            VisionXError::Kind(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Implements `fmt::Display` for our custom Error
+impl fmt::Display for VisionXError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VisionXError::Kind(kind) => write!(f, "{kind}"),
+            VisionXError::BufferSize { expected, actual } => write!(
+                f,
+                "buffer size error, expected {expected} samples but found {actual}"
+            ),
+            VisionXError::SizeMismatch { expected, actual } => write!(
+                f,
+                "size error, expected size ({}, {}) but found ({}, {})",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            VisionXError::OutOfBounds { point, size } => write!(
+                f,
+                "index error at ({}, {}) for size ({}, {})",
+                point.0, point.1, size.0, size.1
+            ),
+            VisionXError::Io(err) => write!(f, "io error: {err}"),
+            VisionXError::Image(err) => write!(f, "image codec error: {err}"),
+            VisionXError::Codec { codec, source } => write!(f, "{codec} codec error: {source}"),
+        }
+    }
+}
+
+/// Implements `std::error::Error` for our custom Error, wrapped errors are returned as source
+impl Error for VisionXError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            VisionXError::Io(err) => Some(err),
+            VisionXError::Image(err) => Some(err),
+            VisionXError::Codec { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Converts a `VisionXErrorKind` into `VisionXError::Kind`
+impl From<VisionXErrorKind> for VisionXError {
+    fn from(kind: VisionXErrorKind) -> Self {
+        VisionXError::Kind(kind)
+    }
+}
+
+/// Converts an I/O error into `VisionXError::Io`
+impl From<std::io::Error> for VisionXError {
+    fn from(err: std::io::Error) -> Self {
+        VisionXError::Io(err)
+    }
+}
+
+/// Converts an error of the image crate into `VisionXError::Image`, I/O errors are unwrapped into `VisionXError::Io`
+impl From<image::ImageError> for VisionXError {
+    fn from(err: image::ImageError) -> Self {
+        match err {
+            image::ImageError::IoError(err) => VisionXError::Io(err),
+            err => VisionXError::Image(err),
+        }
+    }
+}
+
+/// Implements `From` for the error types of a codec, converting them into `VisionXError::Codec`
+///
+/// ## Note
+/// Macro is used internally (private scope)
+macro_rules! impl_from_codec_error {
+    ($($codec:literal => $error:ty),* $(,)?) => {
+        $(
+            /// Converts an error of the codec into `VisionXError::Codec`
+            impl From<$error> for VisionXError {
+                fn from(err: $error) -> Self {
+                    VisionXError::Codec {
+                        codec: $codec,
+                        source: Box::new(err),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_codec_error!(
+    "png" => png::DecodingError,
+    "png" => png::EncodingError,
+    "gif" => gif::DecodingError,
+    "gif" => gif::EncodingError,
+    "tiff" => tiff::TiffError,
+    "webp" => image_webp::DecodingError,
+    "zstd" => ruzstd::decoding::errors::FrameDecoderError,
+    "icc" => moxcms::CmsError,
+);
+
+/// enum VisionXErrorKind is a custom errorkind
+#[derive(Debug, Clone, PartialEq)]
 pub enum VisionXErrorKind {
     /// Used to indicate when array index is overflowed, in cases where array is smaller than the required iterator size
     IndexOutofBound(String),
@@ -19,6 +177,14 @@ pub enum VisionXErrorKind {
     InvalidImageDepthSize(String),
     // Used when buffer space is small to accomodate a strem of bytes
     InsufficientBufferSize(String),
+    /// Used when an image format (file type) isn't supported for an operation
+    UnsupportedFormat(String),
+    /// Used when the data of an image file is malformed or truncated
+    InvalidData(String),
+    /// Used when an image exceeds a limit set for reading it (size, allocation), e.g. for untrusted inputs
+    LimitExceeded(String),
+    /// Used when a parameter of an operation is outside of its valid range (e.g. a negative step)
+    InvalidParameter(String),
 }
 
 /// Implements `fmt::Display` for our custom Errorkind
@@ -36,9 +202,63 @@ impl fmt::Display for VisionXErrorKind {
             VisionXErrorKind::InsufficientBufferSize(err) => {
                 write!(f, "buffer size error while {err}")
             }
+            VisionXErrorKind::UnsupportedFormat(err) => {
+                write!(f, "unsupported image format used while {err}")
+            }
+            VisionXErrorKind::InvalidData(err) => write!(f, "invalid data found while {err}"),
+            VisionXErrorKind::LimitExceeded(err) => write!(f, "limit exceeded while {err}"),
+            VisionXErrorKind::InvalidParameter(err) => {
+                write!(f, "invalid parameter used while {err}")
+            }
         }
     }
 }
 
 /// Implements `std::error::Error` for our custom Errorkind
 impl std::error::Error for VisionXErrorKind {}
+
+#[cfg(test)]
+mod errors_test {
+    use super::{VisionXError, VisionXErrorKind};
+    use crate::io;
+    use std::error::Error;
+
+    // Errors can be moved across threads
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<VisionXError>();
+
+        let handle = std::thread::spawn(|| io::read("images/jpg/missing.jpg"));
+        assert!(handle.join().unwrap().is_err());
+    }
+
+    // Errors are matched by their variant and wrapped errors are chained as source
+    #[test]
+    fn matching_and_source() {
+        match io::read("images/jpg/missing.jpg") {
+            Err(VisionXError::Io(err)) => assert_eq!(std::io::ErrorKind::NotFound, err.kind()),
+            _ => panic!("expected an io error"),
+        }
+
+        let err = io::read_frames("images/test/README.md").err().unwrap();
+        assert!(matches!(
+            err.kind(),
+            Some(VisionXErrorKind::UnsupportedFormat(_))
+        ));
+        let png = std::fs::read("images/png/basn2c16.png").unwrap();
+        std::fs::write("images/test/jade_errors-truncated.png", &png[..40]).unwrap();
+        let err = io::read_frames("images/test/jade_errors-truncated.png")
+            .err()
+            .unwrap();
+        assert!(err.source().is_some());
+
+        let err = VisionXError::from(VisionXErrorKind::InvalidSize("testing".to_string()));
+        assert!(err.source().is_none());
+        assert_eq!(
+            Some(&VisionXErrorKind::InvalidSize("testing".to_string())),
+            err.kind()
+        );
+        assert_eq!("size error while testing", err.to_string());
+    }
+}
@@ -1,18 +1,10 @@
 use crate::{
-    core::{Image, ImageData},
-    errors::{VisionXErrorKind, VisionXResult},
+    core::{Grayscale, Hsv, Image, ImageData, ImageView, PixelDepth, Rgb},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
 };
 
 /// Implementation to convert an image into RGB, Grayscale, and HSV colorspace
 impl Image {
-    /// Convert pixel's color depth (16bit) to 8bit
-    ///
-    /// ## Note
-    /// Function is used internally (private scope)
-    fn downcast_8bit(pixel: u16) -> u8 {
-        (pixel as f32 / u16::MAX as f32) as u8
-    }
-
     /// Cast pixel's value from rgb to grayscale colorspace
     ///
     /// ## Note
@@ -23,11 +15,11 @@ impl Image {
         [(0.299 * rgb[0] as f32 + 0.587 * rgb[1] as f32 + 0.114 * rgb[2] as f32).round() as u8]
     }
 
-    /// Cast pixel's value from rgb to hsv colorspace
+    /// Cast pixel's value from rgb to hsv colorspace. Accepts rgb values normalised to `[0.0, 1.0]` (see `PixelDepth`)
     ///
     /// ## Note
     /// Function is used internally (private scope)
-    fn rgb_to_hsv(rgb: &[u8; 3]) -> [f32; 3] {
+    fn rgb_to_hsv(rgb: &[f32; 3]) -> [f32; 3] {
         // link to formula and more on conversion:
         // https://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB
         //
@@ -52,9 +44,9 @@ impl Image {
         //
         // s = if c_max == 0.0 { 0.0 } else { delta / c_max }
         // v = c_max
-        let r_prime: f32 = rgb[0] as f32 / 255.0;
-        let g_prime: f32 = rgb[1] as f32 / 255.0;
-        let b_prime: f32 = rgb[1] as f32 / 255.0;
+        let r_prime: f32 = rgb[0];
+        let g_prime: f32 = rgb[1];
+        let b_prime: f32 = rgb[2];
 
         let c_max: f32 = f32::max(r_prime, f32::max(g_prime, b_prime));
         let c_min: f32 = f32::min(r_prime, f32::min(g_prime, b_prime));
@@ -152,128 +144,22 @@ impl Image {
     pub fn grayscale(&self) -> Self {
         let grayscale_image: ImageData<u8, 1> = match self {
             Image::ImageGrayscale(grayscale) => grayscale.clone(), // expensive operation, please avoid at any cost
-            Image::ImageGrayscaleAlpha(grayscale_alpha) => {
-                let width: &u32 = grayscale_alpha.width();
-                let height: &u32 = grayscale_alpha.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 2]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = grayscale_alpha.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u8; 2]| [px_vec[0]]);
-
-                ImageData::new(*width, *height, gray_pixels)
-            }
-            Image::ImageGrayscale16(grayscale16) => {
-                // TODO: Requires dithering algorithm to account for the loss in visual quality
-                let width: &u32 = grayscale16.width();
-                let height: &u32 = grayscale16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = grayscale16.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels
-                    .map(|px_vec: &[u16; 1]| [(px_vec[0] as f32 / 65535.0 * 255.0).round() as u8]);
-
-                ImageData::new(*width, *height, gray_pixels)
-            }
+            Image::ImageGrayscaleAlpha(grayscale_alpha) => gray_image(&grayscale_alpha.view()),
+            // TODO: Requires dithering algorithm to account for the loss in visual quality
+            Image::ImageGrayscale16(grayscale16) => gray_image(&grayscale16.view()),
             Image::ImageGrayscaleAlpha16(grayscale_alpha16) => {
-                // TODO: Requires dithering algorithm to account for the loss in visual quality
-                let width: &u32 = grayscale_alpha16.width();
-                let height: &u32 = grayscale_alpha16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 2]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = grayscale_alpha16.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels
-                    .map(|px_vec: &[u16; 2]| [(px_vec[0] as f32 / 65535.0 * 255.0).round() as u8]);
-
-                ImageData::new(*width, *height, gray_pixels)
-            }
-            Image::ImageRgb(rgb) => {
-                let width: &u32 = rgb.width();
-                let height: &u32 = rgb.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgb.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u8; 3]| Self::rgb_to_gray(px_vec));
-
-                ImageData::new(*width, *height, gray_pixels)
-            }
-            Image::ImageRgba(rgba) => {
-                let width: &u32 = rgba.width();
-                let height: &u32 = rgba.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 4]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgba.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels
-                    .map(|px_vec: &[u8; 4]| Self::rgb_to_gray(&[px_vec[0], px_vec[1], px_vec[2]]));
-
-                ImageData::new(*width, *height, gray_pixels)
+                gray_image(&grayscale_alpha16.view())
             }
-            Image::ImageRgb16(rgb16) => {
-                let width: &u32 = rgb16.width();
-                let height: &u32 = rgb16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgb16.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u16; 3]| {
-                    Self::rgb_to_gray(&[
-                        Self::downcast_8bit(px_vec[0]),
-                        Self::downcast_8bit(px_vec[1]),
-                        Self::downcast_8bit(px_vec[2]),
-                    ])
-                });
-
-                ImageData::new(*width, *height, gray_pixels)
-            }
-            Image::ImageRgba16(rgba16) => {
-                let width: &u32 = rgba16.width();
-                let height: &u32 = rgba16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 4]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgba16.pixels();
-
-                let gray_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 1]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u16; 4]| {
-                    Self::rgb_to_gray(&[
-                        Self::downcast_8bit(px_vec[0]),
-                        Self::downcast_8bit(px_vec[1]),
-                        Self::downcast_8bit(px_vec[2]),
-                    ])
-                });
-
-                ImageData::new(*width, *height, gray_pixels)
+            Image::ImageRgb(rgb) => gray_image(&rgb.view()),
+            Image::ImageRgba(rgba) => gray_image(&rgba.view()),
+            Image::ImageRgb16(rgb16) => gray_image(&rgb16.view()),
+            Image::ImageRgba16(rgba16) => gray_image(&rgba16.view()),
+            Image::ImageGrayscale32F(grayscale32f) => gray_image(&grayscale32f.view()),
+            Image::ImageGrayscaleAlpha32F(grayscale_alpha32f) => {
+                gray_image(&grayscale_alpha32f.view())
             }
+            Image::ImageRgb32F(rgb32f) => gray_image(&rgb32f.view()),
+            Image::ImageRgba32F(rgba32f) => gray_image(&rgba32f.view()),
             Image::ImageHsv(hsv) => {
                 let width: &u32 = hsv.width();
                 let height: &u32 = hsv.height();
@@ -285,12 +171,7 @@ impl Image {
                 let gray_pixels: ndarray::ArrayBase<
                     ndarray::OwnedRepr<[u8; 1]>,
                     ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[f32; 3]| {
-                    [(0.299 * (px_vec[0] as f32 / 65535.0 * 255.0)
-                        + 0.587 * (px_vec[1] as f32 / 65535.0 * 255.0)
-                        + 0.114 * (px_vec[2] as f32 / 65535.0 * 255.0))
-                        .round() as u8]
-                });
+                > = pixels.map(|px_vec: &[f32; 3]| Self::rgb_to_gray(&Self::hsv_to_rgb(px_vec)));
 
                 ImageData::new(*width, *height, gray_pixels)
             }
@@ -300,7 +181,7 @@ impl Image {
         Image::ImageGrayscale(grayscale_image)
     }
 
-    /// Convert an image to RGB colorspace. Supports only RGBA, RGB16, RGBA16, and HSV colorspace
+    /// Convert an image to RGB colorspace. Supports only RGBA, RGB16, RGBA16, RGB32F, RGBA32F and HSV colorspace
     ///
     /// # Example
     ///
@@ -331,63 +212,11 @@ impl Image {
                     pixels.clone(),
                 )))
             } // expensive operation, please avoid at any cost
-            Image::ImageRgba(rgba) => {
-                let width: &u32 = rgba.width();
-                let height: &u32 = rgba.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 4]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgba.pixels();
-
-                let rgb_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u8; 4]| [px_vec[0], px_vec[1], px_vec[2]]);
-
-                Ok(Image::ImageRgb(ImageData::new(*width, *height, rgb_pixels)))
-            }
-            Image::ImageRgb16(rgb16) => {
-                let width: &u32 = rgb16.width();
-                let height: &u32 = rgb16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgb16.pixels();
-
-                let rgb_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u16; 3]| {
-                    [
-                        Self::downcast_8bit(px_vec[0]),
-                        Self::downcast_8bit(px_vec[1]),
-                        Self::downcast_8bit(px_vec[2]),
-                    ]
-                });
-
-                Ok(Image::ImageRgb(ImageData::new(*width, *height, rgb_pixels)))
-            }
-            Image::ImageRgba16(rgba16) => {
-                let width: &u32 = rgba16.width();
-                let height: &u32 = rgba16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 4]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgba16.pixels();
-
-                let rgb_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u16; 4]| {
-                    [
-                        Self::downcast_8bit(px_vec[0]),
-                        Self::downcast_8bit(px_vec[1]),
-                        Self::downcast_8bit(px_vec[2]),
-                    ]
-                });
-
-                Ok(Image::ImageRgb(ImageData::new(*width, *height, rgb_pixels)))
-            }
+            Image::ImageRgba(rgba) => Ok(Image::ImageRgb(rgb_image(&rgba.view()))),
+            Image::ImageRgb16(rgb16) => Ok(Image::ImageRgb(rgb_image(&rgb16.view()))),
+            Image::ImageRgba16(rgba16) => Ok(Image::ImageRgb(rgb_image(&rgba16.view()))),
+            Image::ImageRgb32F(rgb32f) => Ok(Image::ImageRgb(rgb_image(&rgb32f.view()))),
+            Image::ImageRgba32F(rgba32f) => Ok(Image::ImageRgb(rgb_image(&rgba32f.view()))),
             Image::ImageHsv(hsv) => {
                 let width: &u32 = hsv.width();
                 let height: &u32 = hsv.height();
@@ -403,14 +232,15 @@ impl Image {
 
                 Ok(Image::ImageRgb(ImageData::new(*width, *height, rgb_pixels)))
             }
-            value => Err(Box::new(VisionXErrorKind::InvalidColorType(format!(
+            value => Err(VisionXErrorKind::InvalidColorType(format!(
                 "converting pixel value from {} to RGB colorspace",
                 value.to_str()
-            )))),
+            ))
+            .into()),
         }
     }
 
-    /// Convert an image to HSV colorspace. Supports only RGB, RGBA, RGB16, and RGBA16 colorspace
+    /// Convert an image to HSV colorspace. Supports only RGB, RGBA, RGB16, RGBA16, RGB32F and RGBA32F colorspace
     ///
     /// # Example
     ///
@@ -427,83 +257,12 @@ impl Image {
     /// ```
     pub fn hsv(&self) -> VisionXResult<Self> {
         match self {
-            Image::ImageRgb(rgb) => {
-                let width: &u32 = rgb.width();
-                let height: &u32 = rgb.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgb.pixels();
-
-                let hsv_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[f32; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u8; 3]| Self::rgb_to_hsv(px_vec));
-
-                Ok(Image::ImageHsv(ImageData::new(*width, *height, hsv_pixels)))
-            }
-            Image::ImageRgba(rgba) => {
-                let width: &u32 = rgba.width();
-                let height: &u32 = rgba.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u8; 4]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgba.pixels();
-
-                let hsv_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[f32; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u8; 4]| {
-                    let rgb = [px_vec[0], px_vec[1], px_vec[2]];
-                    Self::rgb_to_hsv(&rgb)
-                });
-
-                Ok(Image::ImageHsv(ImageData::new(*width, *height, hsv_pixels)))
-            }
-            Image::ImageRgb16(rgb16) => {
-                let width: &u32 = rgb16.width();
-                let height: &u32 = rgb16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgb16.pixels();
-
-                let hsv_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[f32; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u16; 3]| {
-                    let rgb: [u8; 3] = [
-                        Self::downcast_8bit(px_vec[0]),
-                        Self::downcast_8bit(px_vec[1]),
-                        Self::downcast_8bit(px_vec[2]),
-                    ];
-                    Self::rgb_to_hsv(&rgb)
-                });
-
-                Ok(Image::ImageHsv(ImageData::new(*width, *height, hsv_pixels)))
-            }
-            Image::ImageRgba16(rgba16) => {
-                let width: &u32 = rgba16.width();
-                let height: &u32 = rgba16.height();
-                let pixels: &ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[u16; 4]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = rgba16.pixels();
-
-                let hsv_pixels: ndarray::ArrayBase<
-                    ndarray::OwnedRepr<[f32; 3]>,
-                    ndarray::Dim<[usize; 2]>,
-                > = pixels.map(|px_vec: &[u16; 4]| {
-                    let rgb: [u8; 3] = [
-                        Self::downcast_8bit(px_vec[0]),
-                        Self::downcast_8bit(px_vec[1]),
-                        Self::downcast_8bit(px_vec[2]),
-                    ];
-                    Self::rgb_to_hsv(&rgb)
-                });
-
-                Ok(Image::ImageHsv(ImageData::new(*width, *height, hsv_pixels)))
-            }
+            Image::ImageRgb(rgb) => Ok(Image::ImageHsv(hsv_image(&rgb.view()))),
+            Image::ImageRgba(rgba) => Ok(Image::ImageHsv(hsv_image(&rgba.view()))),
+            Image::ImageRgb16(rgb16) => Ok(Image::ImageHsv(hsv_image(&rgb16.view()))),
+            Image::ImageRgba16(rgba16) => Ok(Image::ImageHsv(hsv_image(&rgba16.view()))),
+            Image::ImageRgb32F(rgb32f) => Ok(Image::ImageHsv(hsv_image(&rgb32f.view()))),
+            Image::ImageRgba32F(rgba32f) => Ok(Image::ImageHsv(hsv_image(&rgba32f.view()))),
             Image::ImageHsv(hsv) => {
                 let width: &u32 = hsv.width();
                 let height: &u32 = hsv.height();
@@ -518,19 +277,142 @@ impl Image {
                     pixels.clone(),
                 )))
             } // expensive operation, please avoid at any cost
-            value => Err(Box::new(VisionXErrorKind::InvalidColorType(format!(
+            value => Err(VisionXErrorKind::InvalidColorType(format!(
                 "converting pixel value from {} to HSV colorspace",
                 value.to_str()
-            )))),
+            ))
+            .into()),
         }
     }
 }
 
+/// Implementation to convert a borrowed image into Grayscale, RGB and HSV colorspace
+///
+/// Channels of the view are read by their count: 1 (grayscale), 2 (grayscale + alpha), 3 (RGB) or 4 (RGBA). HSV pixels can't be told apart from RGB pixels, use `ImageView::hsv_to_rgb()` for them
+impl<'a, T: PixelDepth, const N: usize> ImageView<'a, T, N> {
+    /// Convert the view to Grayscale colorspace, e.g. a region of interest or a tile, see `Image::grayscale()`
+    ///
+    /// Returns `Err` if the view doesn't have 1 to 4 channels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let pixels = Array2::from_elem((4, 4), [255u8, 0, 0]);
+    /// let rgb = ImageData::new(4, 4, pixels);
+    ///
+    /// let gray = rgb.roi((1, 1), (3, 3))?.grayscale()?;
+    /// assert_eq!((2, 2), (*gray.width(), *gray.height()));
+    /// assert_eq!(Some(&[76]), gray.get_pixel_at(0, 0));
+    /// # Ok(()) }
+    /// ```
+    pub fn grayscale(&self) -> VisionXResult<Grayscale> {
+        match N {
+            1..=4 => Ok(gray_image(self)),
+            _ => Err(invalid_channels(N, "grayscale")),
+        }
+    }
+
+    /// Convert the view to RGB colorspace (8bit), the alpha channel (if any) being dropped, see `Image::rgb()`
+    ///
+    /// Returns `Err` if the view doesn't have 3 or 4 channels
+    pub fn rgb(&self) -> VisionXResult<Rgb> {
+        match N {
+            3 | 4 => Ok(rgb_image(self)),
+            _ => Err(invalid_channels(N, "RGB")),
+        }
+    }
+
+    /// Convert the view to HSV colorspace, the alpha channel (if any) being ignored, see `Image::hsv()`
+    ///
+    /// Returns `Err` if the view doesn't have 3 or 4 channels
+    pub fn hsv(&self) -> VisionXResult<Hsv> {
+        match N {
+            3 | 4 => Ok(hsv_image(self)),
+            _ => Err(invalid_channels(N, "HSV")),
+        }
+    }
+}
+
+/// Implementation to convert a borrowed HSV image into RGB colorspace
+impl<'a> ImageView<'a, f32, 3> {
+    /// Convert a view of HSV pixels to RGB colorspace (8bit), see `Image::rgb()`
+    pub fn hsv_to_rgb(&self) -> Rgb {
+        let rgb_pixels = self
+            .pixels()
+            .map(|px_vec: &[f32; 3]| Image::hsv_to_rgb(px_vec));
+
+        ImageData::new(*self.width(), *self.height(), rgb_pixels)
+    }
+}
+
+/// Cast a grayscale(+alpha) or rgb(a) view to grayscale colorspace, any color depth being converted to 8bit. Expects 1 to 4 channels
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn gray_image<T: PixelDepth, const N: usize>(view: &ImageView<'_, T, N>) -> Grayscale {
+    let gray_pixels = view.pixels().map(|px_vec: &[T; N]| {
+        let px_vec = px_vec.map(|px: T| u8::from_normalized(px.to_normalized()));
+        if N < 3 {
+            [px_vec[0]]
+        } else {
+            Image::rgb_to_gray(&[px_vec[0], px_vec[1], px_vec[2]])
+        }
+    });
+
+    ImageData::new(*view.width(), *view.height(), gray_pixels)
+}
+
+/// Cast an rgb(a) view to rgb colorspace, any color depth being converted to 8bit. Expects 3 or 4 channels
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn rgb_image<T: PixelDepth, const N: usize>(view: &ImageView<'_, T, N>) -> Rgb {
+    let rgb_pixels = view
+        .pixels()
+        .map(|px_vec: &[T; N]| [0, 1, 2].map(|c| u8::from_normalized(px_vec[c].to_normalized())));
+
+    ImageData::new(*view.width(), *view.height(), rgb_pixels)
+}
+
+/// Cast an rgb(a) view to hsv colorspace. Accepts any color depth, the alpha channel (if any) is ignored. Expects 3 or 4 channels
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn hsv_image<T: PixelDepth, const N: usize>(view: &ImageView<'_, T, N>) -> Hsv {
+    let hsv_pixels = view.pixels().map(|px_vec: &[T; N]| {
+        let rgb: [f32; 3] = [
+            px_vec[0].to_normalized(),
+            px_vec[1].to_normalized(),
+            px_vec[2].to_normalized(),
+        ];
+        Image::rgb_to_hsv(&rgb)
+    });
+
+    ImageData::new(*view.width(), *view.height(), hsv_pixels)
+}
+
+/// Returns the error raised when a view doesn't have the channels required by a conversion
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn invalid_channels(channels: usize, colorspace: &str) -> VisionXError {
+    VisionXErrorKind::InvalidColorType(format!(
+        "converting pixel value with {channels} channel(s) to {colorspace} colorspace"
+    ))
+    .into()
+}
+
 #[cfg(test)]
 mod color_test {
-    use crate::core::Image;
+    use crate::core::{Image, ImageData};
     use crate::errors::VisionXResult;
     use crate::io;
+    use ndarray::Array2;
 
     // Test all types of grayscale conversion (8/16bit)
     // write image using `io::write()`
@@ -567,6 +449,57 @@ mod color_test {
         assert!(res.is_ok());
     }
 
+    // Views (e.g. regions of interest) are converted like owned images
+    #[test]
+    fn view_conversion() {
+        let pixels = Array2::from_shape_fn((6, 8), |(y, x)| {
+            [
+                (x * 30) as u16 * 257,
+                (y * 45 + 5) as u16 * 257,
+                9000,
+                65535,
+            ]
+        });
+        let rgba16 = ImageData::new(8, 6, pixels);
+        let img = Image::ImageRgba16(rgba16.clone());
+        let roi = rgba16.roi((2, 1), (6, 5)).unwrap();
+
+        let Image::ImageGrayscale(gray) = img.grayscale() else {
+            panic!("expected a grayscale image")
+        };
+        assert_eq!(
+            gray.crop((2, 1), (6, 5)).unwrap().pixels(),
+            roi.grayscale().unwrap().pixels()
+        );
+        let Ok(Image::ImageRgb(rgb)) = img.rgb() else {
+            panic!("expected an RGB image")
+        };
+        assert_eq!(
+            rgb.crop((2, 1), (6, 5)).unwrap().pixels(),
+            roi.rgb().unwrap().pixels()
+        );
+        let Ok(Image::ImageHsv(hsv)) = img.hsv() else {
+            panic!("expected an HSV image")
+        };
+        assert_eq!(
+            hsv.crop((2, 1), (6, 5)).unwrap().pixels(),
+            roi.hsv().unwrap().pixels()
+        );
+
+        let Ok(Image::ImageRgb(from_hsv)) = Image::ImageHsv(hsv.clone()).rgb() else {
+            panic!("expected an RGB image")
+        };
+        assert_eq!(from_hsv.pixels(), hsv.view().hsv_to_rgb().pixels());
+
+        let gray16 = ImageData::new(2, 2, Array2::from_elem((2, 2), [257u16]));
+        assert_eq!(
+            Some(&[1]),
+            gray16.view().grayscale().unwrap().get_pixel_at(1, 1)
+        );
+        assert!(gray16.view().rgb().is_err());
+        assert!(gray16.view().hsv().is_err());
+    }
+
     // Test rgb 16bit color depth to grayscale 8bit conversion
     // write image using `io::write()`
     #[test]
@@ -582,6 +515,27 @@ mod color_test {
         assert!(res.is_ok());
     }
 
+    // Test rgb to hsv conversion of a pixel whose green and blue channels differ
+    #[test]
+    fn hsv_conversion() {
+        let rgb_img: Image = Image::ImageRgb(ImageData::new(
+            1,
+            1,
+            Array2::from_elem((1, 1), [255u8, 128, 0]),
+        ));
+        let res: VisionXResult<Image> = rgb_img.hsv();
+        assert!(res.is_ok());
+
+        if let Image::ImageHsv(hsv) = res.unwrap() {
+            let pixel: &[f32; 3] = hsv.get_pixel_at(0, 0).unwrap();
+            assert!((pixel[0] - 128.0 / 255.0).abs() < 1e-6);
+            assert_eq!(pixel[1], 1.0);
+            assert_eq!(pixel[2], 1.0);
+        } else {
+            panic!("expected an hsv image");
+        }
+    }
+
     // Test grayscale 16bit color depth to grayscale 8bit conversion
     // write image using `io::write()`
     #[test]
@@ -0,0 +1,290 @@
+use crate::core::{ImageData, ImageView, Labels};
+
+/// `Connectivity` defines which neighbouring pixels belong to the same component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// Pixels sharing an edge (left, right, top and bottom neighbours)
+    Four,
+    /// Pixels sharing an edge or a corner (including diagonal neighbours)
+    #[default]
+    Eight,
+}
+
+/// `ComponentStats` holds the statistics of a connected component, returned by `ImageData::connected_components()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComponentStats {
+    label: u32,
+    area: u64,
+    bounding_box: (u32, u32, u32, u32),
+    centroid: (f64, f64),
+}
+
+/// Core implementation of struct `ComponentStats`
+impl ComponentStats {
+    /// Label of the component in the label image
+    pub fn label(&self) -> u32 {
+        self.label
+    }
+
+    /// Number of pixels of the component
+    pub fn area(&self) -> u64 {
+        self.area
+    }
+
+    /// Smallest rectangle holding the component as `(x, y, width, height)`
+    pub fn bounding_box(&self) -> (u32, u32, u32, u32) {
+        self.bounding_box
+    }
+
+    /// Mean position `(x, y)` of the pixels of the component
+    pub fn centroid(&self) -> (f64, f64) {
+        self.centroid
+    }
+}
+
+/// Implementation of connected-component labelling of a binary (grayscale) image
+impl ImageData<u8, 1> {
+    /// Labels the connected components of a binary image, every non-zero pixel being foreground. Returns a label image (`0` for background, `1..=n` for the components in the order they are first met, row by row) and the statistics of each component, the statistics of label `l` being at index `l - 1`
+    ///
+    /// Uses a two-pass algorithm with union-find, which runs in (almost) linear time and only allocates the label image and one entry per provisional label. Hence, it's suited to large masks produced by thresholding
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::Connectivity;
+    ///
+    /// # fn main() {
+    /// // two squares touching at a corner
+    /// let mask = ImageData::new(4, 4, Array2::from_shape_fn((4, 4), |(y, x)| [if (x < 2) == (y < 2) { 255 } else { 0 }]));
+    ///
+    /// let (labels, stats) = mask.connected_components(Connectivity::Four);
+    /// assert_eq!(2, stats.len());
+    /// assert_eq!(labels.get_pixel_at(3, 3), Some(&[2]));
+    /// assert_eq!((2, 2, 2, 2), stats[1].bounding_box());
+    ///
+    /// let (_, stats) = mask.connected_components(Connectivity::Eight);
+    /// assert_eq!(1, stats.len());
+    /// assert_eq!(8, stats[0].area());
+    /// # }
+    /// ```
+    pub fn connected_components(
+        &self,
+        connectivity: Connectivity,
+    ) -> (Labels, Vec<ComponentStats>) {
+        self.view().connected_components(connectivity)
+    }
+}
+
+/// Implementation of connected-component labelling of a borrowed binary (grayscale) image
+impl<'a> ImageView<'a, u8, 1> {
+    /// Labels the connected components of the view, see `ImageData::connected_components()`
+    pub fn connected_components(
+        &self,
+        connectivity: Connectivity,
+    ) -> (Labels, Vec<ComponentStats>) {
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+        let mut labels: Vec<u32> = vec![0; width * height];
+        // parent of every provisional label, index 0 is the background
+        let mut parents: Vec<u32> = vec![0];
+
+        // first pass: provisional labels, equivalences are merged in the union-find
+        for (y, row) in self.pixels().rows().into_iter().enumerate() {
+            for (x, px) in row.iter().enumerate() {
+                if px[0] == 0 {
+                    continue;
+                }
+
+                let index = y * width + x;
+                let mut neighbours = [0u32; 4];
+                if x > 0 {
+                    neighbours[0] = labels[index - 1];
+                }
+                if y > 0 {
+                    neighbours[1] = labels[index - width];
+                    if connectivity == Connectivity::Eight {
+                        if x > 0 {
+                            neighbours[2] = labels[index - width - 1];
+                        }
+                        if x + 1 < width {
+                            neighbours[3] = labels[index - width + 1];
+                        }
+                    }
+                }
+
+                let mut label = 0;
+                for &neighbour in neighbours.iter().filter(|&&neighbour| neighbour != 0) {
+                    if label == 0 {
+                        label = neighbour;
+                    } else {
+                        union(&mut parents, label, neighbour);
+                    }
+                }
+                if label == 0 {
+                    label = parents.len() as u32;
+                    parents.push(label);
+                }
+                labels[index] = label;
+            }
+        }
+
+        // second pass: provisional labels are replaced by the consecutive label of their root
+        let mut final_labels: Vec<u32> = vec![0; parents.len()];
+        let mut stats: Vec<ComponentStats> = Vec::new();
+        // sum of x and y, and bounds (x_min, y_min, x_max, y_max) of each component
+        let mut sums: Vec<(u64, u64)> = Vec::new();
+        let mut bounds: Vec<[usize; 4]> = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                if labels[index] == 0 {
+                    continue;
+                }
+
+                let root = find(&mut parents, labels[index]) as usize;
+                if final_labels[root] == 0 {
+                    stats.push(ComponentStats {
+                        label: stats.len() as u32 + 1,
+                        area: 0,
+                        bounding_box: (0, 0, 0, 0),
+                        centroid: (0.0, 0.0),
+                    });
+                    sums.push((0, 0));
+                    bounds.push([x, y, x, y]);
+                    final_labels[root] = stats.len() as u32;
+                }
+                let label = final_labels[root];
+                labels[index] = label;
+
+                let i = label as usize - 1;
+                stats[i].area += 1;
+                sums[i].0 += x as u64;
+                sums[i].1 += y as u64;
+                let bound = &mut bounds[i];
+                bound[0] = bound[0].min(x);
+                bound[1] = bound[1].min(y);
+                bound[2] = bound[2].max(x);
+                bound[3] = bound[3].max(y);
+            }
+        }
+
+        for ((stat, sum), bound) in stats.iter_mut().zip(sums).zip(bounds) {
+            let area = stat.area as f64;
+            stat.centroid = (sum.0 as f64 / area, sum.1 as f64 / area);
+            stat.bounding_box = (
+                bound[0] as u32,
+                bound[1] as u32,
+                (bound[2] - bound[0] + 1) as u32,
+                (bound[3] - bound[1] + 1) as u32,
+            );
+        }
+
+        let labels = ImageData::from_raw_vec(width as u32, height as u32, labels)
+            .expect("label buffer has the size of the image");
+        (labels, stats)
+    }
+}
+
+/// Finds the root of a label, halving the path on the way
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn find(parents: &mut [u32], mut label: u32) -> u32 {
+    while parents[label as usize] != label {
+        let grandparent = parents[parents[label as usize] as usize];
+        parents[label as usize] = grandparent;
+        label = grandparent;
+    }
+    label
+}
+
+/// Merges the sets of two labels, the smaller root becomes the root of both
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn union(parents: &mut [u32], a: u32, b: u32) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    if a < b {
+        parents[b as usize] = a;
+    } else if b < a {
+        parents[a as usize] = b;
+    }
+}
+
+#[cfg(test)]
+mod components_test {
+    use crate::core::ImageData;
+    use crate::imgproc::{test_utils::mask, Connectivity};
+    use ndarray::Array2;
+
+    // Shapes whose parts are merged late (U shape and comb) get a single label
+    #[test]
+    fn labels_and_stats() {
+        let img = mask(&[
+            "#.#..#...", //
+            "#.#...#..",
+            "###....#.",
+            ".........",
+            "##.#.#.##",
+            ".#######.",
+        ]);
+
+        let (labels, stats) = img.connected_components(Connectivity::Four);
+        // U shape, 3 pixels of the diagonal and comb
+        assert_eq!(5, stats.len());
+        assert_eq!(7, stats[0].area());
+        assert_eq!((0, 0, 3, 3), stats[0].bounding_box());
+        assert_eq!((1.0, 8.0 / 7.0), stats[0].centroid());
+        assert_eq!(labels.get_pixel_at(0, 0), labels.get_pixel_at(2, 0));
+
+        let comb = labels.get_pixel_at(0, 4).unwrap()[0] as usize;
+        assert_eq!(labels.get_pixel_at(6, 5), Some(&[comb as u32]));
+        assert_eq!(5, comb);
+        assert_eq!(13, stats[comb - 1].area());
+        assert_eq!((0, 4, 9, 2), stats[comb - 1].bounding_box());
+
+        let (labels, stats) = img.connected_components(Connectivity::Eight);
+        // U shape, diagonal and comb
+        assert_eq!(3, stats.len());
+        assert_eq!(3, stats[1].area());
+        assert_eq!((5, 0, 3, 3), stats[1].bounding_box());
+        assert_eq!(13, stats[2].area());
+        assert_eq!(labels.get_pixel_at(3, 3), Some(&[0]));
+        assert_eq!(labels.get_pixel_at(8, 4), Some(&[3]));
+
+        // labels of a view are relative to the view
+        let (labels, stats) = img
+            .roi((5, 0), (9, 3))
+            .unwrap()
+            .connected_components(Connectivity::Four);
+        assert_eq!(3, stats.len());
+        assert_eq!(labels.get_pixel_at(0, 0), Some(&[1]));
+    }
+
+    // Large masks with many provisional labels
+    #[test]
+    fn large_mask() {
+        let (width, height) = (1000, 800);
+        // comb: vertical teeth joined by the bottom row, plus isolated dots
+        let pixels = Array2::from_shape_fn((height, width), |(y, x)| {
+            let tooth = x % 2 == 0 && y < height - 10;
+            let base = y == height - 10;
+            let dot = y == height - 1 && x % 4 == 0;
+            [if tooth || base || dot { 1 } else { 0 }]
+        });
+        let img = ImageData::new(width as u32, height as u32, pixels);
+
+        let (labels, stats) = img.connected_components(Connectivity::Four);
+        assert_eq!(1 + 250, stats.len());
+        assert_eq!((500 * 790 + 1000) as u64, stats[0].area());
+        assert_eq!((0, 0, 1000, 791), stats[0].bounding_box());
+        assert!(stats[1..].iter().all(|stat| stat.area() == 1));
+        assert_eq!(labels.get_pixel_at(996, 799), Some(&[251]));
+
+        let empty = ImageData::new(3, 3, Array2::from_elem((3, 3), [0u8]));
+        let (labels, stats) = empty.connected_components(Connectivity::Eight);
+        assert!(stats.is_empty());
+        assert!(labels.as_raw_slice().iter().all(|&label| label == 0));
+    }
+}
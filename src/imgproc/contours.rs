@@ -0,0 +1,737 @@
+use super::Moments;
+use crate::core::{ImageData, ImageView};
+
+/// Offsets `(dy, dx)` of the 8 neighbours of a pixel, clockwise starting from the east neighbour
+///
+/// ## Note
+/// Constant is used internally (private scope)
+const NEIGHBOURS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+/// `Contour` holds the border of a connected component (outer border) or of a hole within it (hole border), returned by `ImageData::find_contours()`
+///
+/// Contours form a hierarchy: the parent of an outer border is the hole it lies in (if any) and the parent of a hole border is the outer border surrounding it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contour {
+    points: Vec<(u32, u32)>,
+    hole: bool,
+    parent: Option<usize>,
+}
+
+/// `RotatedRect` holds a rectangle rotated by an angle, returned by `Contour::min_area_rect()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotatedRect {
+    center: (f64, f64),
+    size: (f64, f64),
+    angle: f64,
+}
+
+/// Core implementation of struct `Contour`
+impl Contour {
+    /// Points `(x, y)` of the border in tracing order, the contour being closed (last point is connected to the first one)
+    pub fn points(&self) -> &[(u32, u32)] {
+        &self.points
+    }
+
+    /// Returns `true` for the border of a hole, `false` for the outer border of a component
+    pub fn is_hole(&self) -> bool {
+        self.hole
+    }
+
+    /// Index of the parent contour in the list returned by `ImageData::find_contours()`, `None` for outermost contours
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Area enclosed by the polygon joining the points of the contour (shoelace formula)
+    ///
+    /// ## Note
+    /// Points are the centres of the border pixels, hence the area is smaller than the number of pixels of the component (e.g. `4.0` for a 3x3 square)
+    pub fn area(&self) -> f64 {
+        let points = self.points_f64();
+        let sum: f64 = (0..points.len())
+            .map(|i| {
+                let (p, q) = (points[i], points[(i + 1) % points.len()]);
+                p.0 * q.1 - q.0 * p.1
+            })
+            .sum();
+        sum.abs() / 2.0
+    }
+
+    /// Length of the closed polygon joining the points of the contour
+    pub fn perimeter(&self) -> f64 {
+        let points = self.points_f64();
+        (0..points.len())
+            .map(|i| distance(points[i], points[(i + 1) % points.len()]))
+            .sum()
+    }
+
+    /// Approximates the contour with fewer points using the Douglas-Peucker algorithm. Accepts the maximum distance between the contour and its approximation as `f64`
+    ///
+    /// The approximation keeps the hierarchy (hole and parent) of the contour
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_shape_fn((8, 8), |(y, x)| [if (1..7).contains(&x) && (2..6).contains(&y) { 255 } else { 0 }]);
+    /// let mask = ImageData::new(8, 8, pixels);
+    ///
+    /// let contours = mask.find_contours();
+    /// let rect = contours[0].approximate(0.5);
+    /// assert_eq!(&[(1, 2), (1, 5), (6, 5), (6, 2)], rect.points());
+    /// # }
+    /// ```
+    pub fn approximate(&self, epsilon: f64) -> Contour {
+        let n = self.points.len();
+        if n < 3 {
+            return self.clone();
+        }
+
+        // the contour is split at the point farthest from the first one, both chains are then simplified
+        let points = self.points_f64();
+        let farthest = (1..n)
+            .max_by(|&i, &j| {
+                distance(points[0], points[i]).total_cmp(&distance(points[0], points[j]))
+            })
+            .unwrap_or(0);
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[farthest] = true;
+
+        // chains are given by the indices of their ends, `n` standing for the first point
+        let mut chains = vec![(0, farthest), (farthest, n)];
+        while let Some((start, end)) = chains.pop() {
+            let (a, b) = (points[start], points[end % n]);
+            let mut max = (0.0, start);
+            for (i, &p) in points.iter().enumerate().take(end).skip(start + 1) {
+                let dist = segment_distance(p, a, b);
+                if dist > max.0 {
+                    max = (dist, i);
+                }
+            }
+            if max.0 > epsilon {
+                keep[max.1] = true;
+                chains.push((start, max.1));
+                chains.push((max.1, end));
+            }
+        }
+
+        Contour {
+            points: (0..n)
+                .filter(|&i| keep[i])
+                .map(|i| self.points[i])
+                .collect(),
+            hole: self.hole,
+            parent: self.parent,
+        }
+    }
+
+    /// Computes the convex hull of the contour (Andrew's monotone chain algorithm), starting from its leftmost point and following the orientation of outer borders. Points lying on the edges of the hull are removed
+    ///
+    /// The hull keeps the hierarchy (hole and parent) of the contour
+    pub fn convex_hull(&self) -> Contour {
+        let mut points = self.points.clone();
+        points.sort_unstable();
+        points.dedup();
+
+        let points = if points.len() < 3 {
+            points
+        } else {
+            let cross = |o: (u32, u32), a: (u32, u32), b: (u32, u32)| {
+                let (ax, ay) = (a.0 as i64 - o.0 as i64, a.1 as i64 - o.1 as i64);
+                let (bx, by) = (b.0 as i64 - o.0 as i64, b.1 as i64 - o.1 as i64);
+                ax * by - ay * bx
+            };
+            let mut hull: Vec<(u32, u32)> = Vec::with_capacity(points.len() + 1);
+            // lower chain followed by the upper chain
+            for pass in 0..2 {
+                let lower = hull.len();
+                let iter: Box<dyn Iterator<Item = &(u32, u32)>> = if pass == 0 {
+                    Box::new(points.iter())
+                } else {
+                    Box::new(points.iter().rev())
+                };
+                for &p in iter {
+                    while hull.len() >= lower + 2
+                        && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0
+                    {
+                        hull.pop();
+                    }
+                    hull.push(p);
+                }
+                // last point is the first one of the other chain
+                hull.pop();
+            }
+            // hull follows the orientation of outer borders
+            hull[1..].reverse();
+            hull
+        };
+
+        Contour {
+            points,
+            hole: self.hole,
+            parent: self.parent,
+        }
+    }
+
+    /// Finds the rotated rectangle of minimum area enclosing the contour (rotating calipers over the convex hull)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// // diamond, i.e. a square rotated by 45 degrees
+    /// let pixels = Array2::from_shape_fn((9, 9), |(y, x)| [if x.abs_diff(4) + y.abs_diff(4) <= 3 { 255 } else { 0 }]);
+    /// let mask = ImageData::new(9, 9, pixels);
+    ///
+    /// let rect = mask.find_contours()[0].min_area_rect();
+    /// assert_eq!((4.0, 4.0), rect.center());
+    /// assert!((rect.angle() - 45.0).abs() < 1e-9);
+    /// assert!((rect.size().0 - 18f64.sqrt()).abs() < 1e-9);
+    /// # }
+    /// ```
+    pub fn min_area_rect(&self) -> RotatedRect {
+        let hull = self.convex_hull().points_f64();
+        if hull.len() < 2 {
+            let center = hull.first().copied().unwrap_or((0.0, 0.0));
+            return RotatedRect {
+                center,
+                size: (0.0, 0.0),
+                angle: 0.0,
+            };
+        }
+
+        let mut best: Option<(f64, RotatedRect)> = None;
+        for i in 0..hull.len() {
+            let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+            let len = distance(a, b);
+            // unit vectors along the edge and normal to it
+            let u = ((b.0 - a.0) / len, (b.1 - a.1) / len);
+            let v = (-u.1, u.0);
+
+            let (mut min_u, mut max_u, mut min_v, mut max_v) = (0.0f64, 0.0f64, 0.0f64, 0.0f64);
+            for p in &hull {
+                let d = (p.0 - a.0, p.1 - a.1);
+                let (pu, pv) = (d.0 * u.0 + d.1 * u.1, d.0 * v.0 + d.1 * v.1);
+                min_u = min_u.min(pu);
+                max_u = max_u.max(pu);
+                min_v = min_v.min(pv);
+                max_v = max_v.max(pv);
+            }
+
+            let area = (max_u - min_u) * (max_v - min_v);
+            if best.as_ref().is_none_or(|(best_area, _)| area < *best_area) {
+                let (cu, cv) = ((min_u + max_u) / 2.0, (min_v + max_v) / 2.0);
+                let rect = RotatedRect {
+                    center: (a.0 + cu * u.0 + cv * v.0, a.1 + cu * u.1 + cv * v.1),
+                    size: (max_u - min_u, max_v - min_v),
+                    angle: u.1.atan2(u.0).to_degrees(),
+                };
+                best = Some((area, rect));
+            }
+        }
+
+        let mut rect = best.map(|(_, rect)| rect).unwrap_or(RotatedRect {
+            center: hull[0],
+            size: (0.0, 0.0),
+            angle: 0.0,
+        });
+        // angle is brought within [0, 90), swapping the sides if needed
+        rect.angle = rect.angle.rem_euclid(180.0);
+        if rect.angle >= 90.0 {
+            rect.angle -= 90.0;
+            rect.size = (rect.size.1, rect.size.0);
+        }
+        rect
+    }
+
+    /// Finds the circle of minimum radius enclosing the contour (Welzl's incremental algorithm over the convex hull). Returns the center `(x, y)` and radius of the circle
+    pub fn min_enclosing_circle(&self) -> ((f64, f64), f64) {
+        let hull = self.convex_hull().points_f64();
+        let Some(&first) = hull.first() else {
+            return ((0.0, 0.0), 0.0);
+        };
+
+        let inside = |circle: ((f64, f64), f64), p: (f64, f64)| {
+            distance(circle.0, p) <= circle.1 + 1e-9 * (1.0 + circle.1)
+        };
+        let mut circle = (first, 0.0);
+        for i in 1..hull.len() {
+            if inside(circle, hull[i]) {
+                continue;
+            }
+            circle = (hull[i], 0.0);
+            for j in 0..i {
+                if inside(circle, hull[j]) {
+                    continue;
+                }
+                circle = diameter_circle(hull[i], hull[j]);
+                for k in 0..j {
+                    if !inside(circle, hull[k]) {
+                        circle = circumcircle(hull[i], hull[j], hull[k]);
+                    }
+                }
+            }
+        }
+        circle
+    }
+
+    /// Computes the moments of the polygon joining the points of the contour (Green's theorem), from which its centroid, orientation and Hu moments are derived
+    pub fn moments(&self) -> Moments {
+        // points are moved close to the origin to keep the sums accurate
+        let origin = self
+            .points
+            .first()
+            .map_or((0.0, 0.0), |&(x, y)| (x as f64, y as f64));
+        let points: Vec<(f64, f64)> = self
+            .points_f64()
+            .into_iter()
+            .map(|(x, y)| (x - origin.0, y - origin.1))
+            .collect();
+
+        // spatial moments of the polygon (Green's theorem)
+        let mut m = [0.0f64; 10];
+        for i in 0..points.len() {
+            let ((x0, y0), (x1, y1)) = (points[i], points[(i + 1) % points.len()]);
+            let a = x0 * y1 - x1 * y0;
+            m[0] += a / 2.0;
+            m[1] += a * (x0 + x1) / 6.0;
+            m[2] += a * (y0 + y1) / 6.0;
+            m[3] += a * (x0 * x0 + x0 * x1 + x1 * x1) / 12.0;
+            m[4] += a * (2.0 * x0 * y0 + x0 * y1 + x1 * y0 + 2.0 * x1 * y1) / 24.0;
+            m[5] += a * (y0 * y0 + y0 * y1 + y1 * y1) / 12.0;
+            m[6] += a * (x0 + x1) * (x0 * x0 + x1 * x1) / 20.0;
+            m[7] += a
+                * (x0 * x0 * (3.0 * y0 + y1)
+                    + 2.0 * x0 * x1 * (y0 + y1)
+                    + x1 * x1 * (y0 + 3.0 * y1))
+                / 60.0;
+            m[8] += a
+                * (y0 * y0 * (3.0 * x0 + x1)
+                    + 2.0 * y0 * y1 * (x0 + x1)
+                    + y1 * y1 * (x0 + 3.0 * x1))
+                / 60.0;
+            m[9] += a * (y0 + y1) * (y0 * y0 + y1 * y1) / 20.0;
+        }
+        // orientation of the contour gives the sign of every moment
+        if m[0] < 0.0 {
+            m.iter_mut().for_each(|moment| *moment = -*moment);
+        }
+        Moments::from_shifted(m, origin)
+    }
+
+    /// Computes the seven Hu moments of the polygon joining the points of the contour, which are invariant to translation, scale and rotation (the seventh one changes sign under reflection)
+    ///
+    /// Useful to compare shapes regardless of their position, size and orientation
+    pub fn hu_moments(&self) -> [f64; 7] {
+        self.moments().hu()
+    }
+
+    /// Converts the points of the contour into floating points
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn points_f64(&self) -> Vec<(f64, f64)> {
+        self.points
+            .iter()
+            .map(|&(x, y)| (x as f64, y as f64))
+            .collect()
+    }
+}
+
+/// Core implementation of struct `RotatedRect`
+impl RotatedRect {
+    /// Center `(x, y)` of the rectangle
+    pub fn center(&self) -> (f64, f64) {
+        self.center
+    }
+
+    /// Size `(width, height)` of the rectangle, the width being the side along `angle`
+    pub fn size(&self) -> (f64, f64) {
+        self.size
+    }
+
+    /// Angle in degrees within `[0, 90)` between the x-axis and the width side, clockwise as the y-axis points down
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+
+    /// Corners of the rectangle, in clockwise order
+    pub fn corners(&self) -> [(f64, f64); 4] {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let (w, h) = (self.size.0 / 2.0, self.size.1 / 2.0);
+        [(-w, -h), (w, -h), (w, h), (-w, h)].map(|(u, v)| {
+            (
+                self.center.0 + u * cos - v * sin,
+                self.center.1 + u * sin + v * cos,
+            )
+        })
+    }
+}
+
+/// Implementation of contour finding of a binary (grayscale) image
+impl ImageData<u8, 1> {
+    /// Finds the contours of a binary image, every non-zero pixel being foreground, using the border following algorithm of Suzuki and Abe. Components are 8-connected (holes are 4-connected)
+    ///
+    /// Returns the outer and hole borders in the order they are met, row by row. The hierarchy is given by `Contour::parent()`, which refers to the index of another contour in the list
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// // 5x5 square with a hole in its centre
+    /// let pixels = Array2::from_shape_fn((7, 7), |(y, x)| {
+    ///     let inside = (1..6).contains(&x) && (1..6).contains(&y);
+    ///     [if inside && (x, y) != (3, 3) { 255 } else { 0 }]
+    /// });
+    /// let mask = ImageData::new(7, 7, pixels);
+    ///
+    /// let contours = mask.find_contours();
+    /// assert_eq!(2, contours.len());
+    /// assert!(!contours[0].is_hole());
+    /// assert_eq!(16.0, contours[0].area());
+    /// assert!(contours[1].is_hole());
+    /// assert_eq!(Some(0), contours[1].parent());
+    /// # }
+    /// ```
+    pub fn find_contours(&self) -> Vec<Contour> {
+        self.view().find_contours()
+    }
+}
+
+/// Implementation of contour finding of a borrowed binary (grayscale) image
+impl<'a> ImageView<'a, u8, 1> {
+    /// Finds the contours of the view, see `ImageData::find_contours()`
+    pub fn find_contours(&self) -> Vec<Contour> {
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+        // image is padded with a background frame, so that neighbours are always within the buffer
+        let stride = width + 2;
+        let mut f: Vec<i32> = vec![0; stride * (height + 2)];
+        for (y, row) in self.pixels().rows().into_iter().enumerate() {
+            for (x, px) in row.iter().enumerate() {
+                f[(y + 1) * stride + x + 1] = (px[0] != 0) as i32;
+            }
+        }
+
+        let mut contours: Vec<Contour> = Vec::new();
+        // border number of the last traced border, 1 being the frame
+        let mut nbd = 1;
+        for y in 1..=height {
+            // border number of the last border met on the row
+            let mut lnbd = 1;
+            for x in 1..=width {
+                let p = y * stride + x;
+                let (hole, from) = if f[p] == 1 && f[p - 1] == 0 {
+                    (false, p - 1)
+                } else if f[p] >= 1 && f[p + 1] == 0 {
+                    if f[p] > 1 {
+                        lnbd = f[p];
+                    }
+                    (true, p + 1)
+                } else {
+                    if f[p] != 0 && f[p] != 1 {
+                        lnbd = f[p].abs();
+                    }
+                    continue;
+                };
+
+                // parent is the last border met if it's of the other type, else its parent
+                let parent = match lnbd {
+                    1 => None,
+                    _ => {
+                        let last = (lnbd - 2) as usize;
+                        if contours[last].hole == hole {
+                            contours[last].parent
+                        } else {
+                            Some(last)
+                        }
+                    }
+                };
+
+                nbd += 1;
+                let points = trace_border(&mut f, stride, p, from, nbd)
+                    .into_iter()
+                    .map(|q| ((q % stride - 1) as u32, (q / stride - 1) as u32))
+                    .collect();
+                contours.push(Contour {
+                    points,
+                    hole,
+                    parent,
+                });
+
+                if f[p] != 1 {
+                    lnbd = f[p].abs();
+                }
+            }
+        }
+        contours
+    }
+}
+
+/// Follows a border starting at `start`, `from` being the background neighbour from which it was met, and marks its pixels with the border number. Returns the (padded) indices of the border pixels
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn trace_border(f: &mut [i32], stride: usize, start: usize, from: usize, nbd: i32) -> Vec<usize> {
+    let neighbour = |p: usize, dir: usize| {
+        let (dy, dx) = NEIGHBOURS[dir];
+        (p as isize + dy * stride as isize + dx) as usize
+    };
+    let direction = |p: usize, q: usize| (0..8).find(|&dir| neighbour(p, dir) == q).unwrap_or(0);
+
+    // first foreground neighbour, clockwise from the background neighbour
+    let dir = direction(start, from);
+    let Some(first) = (0..8)
+        .map(|k| neighbour(start, (dir + k) % 8))
+        .find(|&q| f[q] != 0)
+    else {
+        // isolated pixel
+        f[start] = -nbd;
+        return vec![start];
+    };
+
+    let mut points = Vec::new();
+    let (mut previous, mut current) = (first, start);
+    loop {
+        points.push(current);
+
+        // next foreground neighbour, counterclockwise from the previous pixel
+        let dir = direction(current, previous);
+        let mut east_examined = false;
+        let mut next = previous;
+        for k in 1..=8 {
+            let d = (dir + 8 - k) % 8;
+            let q = neighbour(current, d);
+            if f[q] != 0 {
+                next = q;
+                break;
+            }
+            if d == 0 {
+                east_examined = true;
+            }
+        }
+
+        if east_examined {
+            f[current] = -nbd;
+        } else if f[current] == 1 {
+            f[current] = nbd;
+        }
+
+        if next == start && current == first {
+            break;
+        }
+        (previous, current) = (current, next);
+    }
+    points
+}
+
+/// Computes the euclidean distance between two points
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+/// Computes the distance between a point and a segment
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = dx * dx + dy * dy;
+    if len == 0.0 {
+        return distance(p, a);
+    }
+    let t = (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len).clamp(0.0, 1.0);
+    distance(p, (a.0 + t * dx, a.1 + t * dy))
+}
+
+/// Computes the circle whose diameter is the segment between two points
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn diameter_circle(a: (f64, f64), b: (f64, f64)) -> ((f64, f64), f64) {
+    (((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0), distance(a, b) / 2.0)
+}
+
+/// Computes the circle passing through three points, or the smallest circle holding them if they are collinear
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn circumcircle(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> ((f64, f64), f64) {
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d.abs() < 1e-12 {
+        return [
+            diameter_circle(a, b),
+            diameter_circle(a, c),
+            diameter_circle(b, c),
+        ]
+        .into_iter()
+        .max_by(|p, q| p.1.total_cmp(&q.1))
+        .unwrap_or((a, 0.0));
+    }
+
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let center = ((cy * b2 - by * c2) / d, (bx * c2 - cx * b2) / d);
+    ((a.0 + center.0, a.1 + center.1), center.0.hypot(center.1))
+}
+
+#[cfg(test)]
+mod contours_test {
+    use crate::core::ImageData;
+    use crate::imgproc::test_utils::mask;
+    use ndarray::Array2;
+
+    // Outer and hole borders are traced with their hierarchy
+    #[test]
+    fn hierarchy() {
+        let img = mask(&[
+            "#########.#", //
+            "#.......#..",
+            "#.#####.#..",
+            "#.#...#.#.#",
+            "#.#.#.#.#..",
+            "#.#...#.#..",
+            "#.#####.#..",
+            "#.......#..",
+            "#########..",
+        ]);
+
+        let contours = img.find_contours();
+        let summary: Vec<(bool, Option<usize>)> = contours
+            .iter()
+            .map(|contour| (contour.is_hole(), contour.parent()))
+            .collect();
+        assert_eq!(
+            vec![
+                (false, None),    // outer square
+                (false, None),    // top right dot
+                (true, Some(0)),  // hole of the outer square
+                (false, Some(2)), // inner square
+                (true, Some(3)),  // hole of the inner square
+                (false, None),    // right dot
+                (false, Some(4)), // centre dot
+            ],
+            summary
+        );
+
+        assert_eq!(32, contours[0].points().len());
+        assert_eq!((0, 0), contours[0].points()[0]);
+        assert_eq!(&[(10, 0)], contours[1].points());
+        assert_eq!(&[(4, 4)], contours[6].points());
+        assert_eq!(64.0, contours[0].area());
+        assert_eq!(32.0, contours[0].perimeter());
+        assert_eq!(16.0, contours[3].area());
+        // hole border skips the corners of the inner square, which don't touch the hole
+        assert_eq!(12, contours[4].points().len());
+        assert_eq!(14.0, contours[4].area());
+        assert_eq!(0.0, contours[6].area());
+
+        // contours of a view are relative to the view
+        let contours = img.roi((2, 2), (7, 7)).unwrap().find_contours();
+        assert_eq!(3, contours.len());
+        assert_eq!((2, 2), contours[2].points()[0]);
+    }
+
+    // Polygon approximation and convex hull
+    #[test]
+    fn approximation_and_hull() {
+        let img = mask(&[
+            "..........", //
+            ".###......",
+            ".###......",
+            ".#######..",
+            ".########.",
+            "..........",
+        ]);
+        let contour = &img.find_contours()[0];
+
+        let approx = contour.approximate(0.5);
+        assert!(approx.points().len() < contour.points().len());
+        assert!(approx.points().iter().all(|p| contour.points().contains(p)));
+        assert_eq!(contour.points()[0], approx.points()[0]);
+        assert_eq!(
+            &[(1, 1), (1, 4), (8, 4), (7, 3), (4, 3), (3, 2), (3, 1)],
+            contour.approximate(0.0).points()
+        );
+
+        let hull = contour.convex_hull();
+        assert_eq!(&[(1, 1), (1, 4), (8, 4), (7, 3), (3, 1)], hull.points());
+        assert!(hull.area() >= contour.area());
+        assert_eq!(hull.convex_hull().points(), hull.points());
+    }
+
+    // Rotated rectangle, enclosing circle and Hu moments
+    #[test]
+    fn shape_descriptors() {
+        let square = mask(&[
+            ".....", //
+            ".###.", ".###.", ".###.", ".....",
+        ]);
+        let contour = &square.find_contours()[0];
+
+        let rect = contour.min_area_rect();
+        assert_eq!((2.0, 2.0), rect.center());
+        assert_eq!((2.0, 2.0), rect.size());
+        assert_eq!(0.0, rect.angle());
+        let mut corners = rect.corners().map(|(x, y)| (x.round(), y.round()));
+        corners.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!([(1.0, 1.0), (1.0, 3.0), (3.0, 1.0), (3.0, 3.0)], corners);
+
+        let (center, radius) = contour.min_enclosing_circle();
+        assert!((center.0 - 2.0).abs() < 1e-9 && (center.1 - 2.0).abs() < 1e-9);
+        assert!((radius - 2f64.sqrt()).abs() < 1e-9);
+
+        // Hu moments don't depend on the position, scale and orientation of the shape
+        let shape = |scale: isize, shift: isize, transpose: bool| {
+            let pixels = Array2::from_shape_fn((128, 128), |(y, x)| {
+                let (x, y) = if transpose { (y, x) } else { (x, y) };
+                let (x, y) = (
+                    (x as isize - shift).div_euclid(scale),
+                    (y as isize - shift).div_euclid(scale),
+                );
+                [
+                    if (0..6).contains(&x) && (0..3).contains(&y)
+                        || (0..2).contains(&x) && (0..9).contains(&y)
+                    {
+                        255
+                    } else {
+                        0
+                    },
+                ]
+            });
+            ImageData::new(128, 128, pixels).find_contours()[0].hu_moments()
+        };
+        let reference = shape(4, 0, false);
+        for hu in [shape(4, 20, false), shape(4, 7, true)] {
+            for (a, b) in reference.iter().zip(hu).take(6) {
+                assert!((a - b).abs() <= 1e-9 * a.abs().max(1e-9), "{a} vs {b}");
+            }
+        }
+        // border pixels are slightly off the ideal shape, so scaling only gives close moments
+        let scaled = shape(8, 0, false);
+        assert!((reference[0] - scaled[0]).abs() < 0.05 * reference[0]);
+        assert!((reference[1] - scaled[1]).abs() < 0.1 * reference[1]);
+    }
+}
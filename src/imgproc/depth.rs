@@ -0,0 +1,87 @@
+use crate::core::{ImageData, ImageView, PixelDepth};
+
+/// Implementation to convert the color depth of an image
+impl<T: PixelDepth, const N: usize> ImageData<T, N> {
+    /// Create an image with every channel converted to another color depth. Supports `u8`, `u16` and `f32` (see `PixelDepth`)
+    ///
+    /// Values are scaled through the normalised range `[0.0, 1.0]`, hence, 8bit to 16bit conversion multiplies each value by 257 and 16bit to 8bit conversion rounds to the nearest value. Float values outside `[0.0, 1.0]` are clamped when converted to an integer depth
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::core::{Image, ImageData};
+    /// use vision_x::io;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let path = "images/jpg/lenna.jpg";
+    /// let img: Image = io::read(path)?;
+    /// if let Image::ImageRgb(rgb) = img {
+    ///     let rgb32f: ImageData<f32, 3> = rgb.convert_depth::<f32>();
+    ///     let rgb16: ImageData<u16, 3> = rgb32f.convert_depth::<u16>();
+    ///     assert_eq!(rgb16.get_pixel_at(0, 0).unwrap()[0], rgb.get_pixel_at(0, 0).unwrap()[0] as u16 * 257);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn convert_depth<U: PixelDepth>(&self) -> ImageData<U, N> {
+        self.view().convert_depth()
+    }
+}
+
+/// Implementation to convert the color depth of a borrowed image
+impl<'a, T: PixelDepth, const N: usize> ImageView<'a, T, N> {
+    /// Create an image from the view with every channel converted to another color depth, see `ImageData::convert_depth()`
+    pub fn convert_depth<U: PixelDepth>(&self) -> ImageData<U, N> {
+        let pixels = self
+            .pixels()
+            .map(|px_vec: &[T; N]| px_vec.map(|px: T| U::from_normalized(px.to_normalized())));
+
+        ImageData::new(*self.width(), *self.height(), pixels)
+    }
+}
+
+#[cfg(test)]
+mod depth_test {
+    use crate::core::{ImageData, PixelDepth};
+    use ndarray::Array2;
+
+    // Every 8bit value is converted to 16bit and back without any loss
+    #[test]
+    fn u8_to_u16_roundtrip() {
+        let mut pixels = Array2::from_elem((1, 256), [0u8; 1]);
+        for x in 0..256 {
+            pixels[(0, x)] = [x as u8];
+        }
+
+        let raw_img: ImageData<u8, 1> = ImageData::new(256, 1, pixels);
+        let img16: ImageData<u16, 1> = raw_img.convert_depth();
+        for x in 0..256 {
+            assert_eq!(img16.get_pixel_at(x, 0), Some(&[x as u16 * 257]));
+        }
+
+        let img8: ImageData<u8, 1> = img16.convert_depth();
+        assert_eq!(img8.pixels(), raw_img.pixels());
+    }
+
+    // 16bit values are rounded to the nearest 8bit value
+    #[test]
+    fn u16_to_u8_rounding() {
+        assert_eq!(u8::from_normalized(65535u16.to_normalized()), 255);
+        assert_eq!(u8::from_normalized(128u16.to_normalized()), 0);
+        assert_eq!(u8::from_normalized(129u16.to_normalized()), 1);
+        assert_eq!(u8::from_normalized(32896u16.to_normalized()), 128);
+    }
+
+    // Float values are normalised and clamped while converting to an integer depth
+    #[test]
+    fn f32_conversion() {
+        let pixels = Array2::from_elem((2, 2), [-0.5f32, 0.5, 1.5]);
+        let raw_img: ImageData<f32, 3> = ImageData::new(2, 2, pixels);
+
+        let img8: ImageData<u8, 3> = raw_img.convert_depth();
+        assert_eq!(img8.get_pixel_at(1, 1), Some(&[0, 128, 255]));
+
+        let img32f: ImageData<f32, 3> = img8.convert_depth();
+        assert_eq!(img32f.get_pixel_at(1, 1), Some(&[0.0, 128.0 / 255.0, 1.0]));
+    }
+}
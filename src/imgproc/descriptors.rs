@@ -0,0 +1,464 @@
+use super::{
+    features::{CornerScore, KeyPoint},
+    kernel::{smooth, XorShift},
+};
+use crate::{
+    core::{ImageData, ImageView},
+    errors::{VisionXErrorKind, VisionXResult},
+};
+use ndarray::Array2;
+
+/// `BinaryDescriptor` holds the 256 bits descriptor of a keypoint computed by `ImageData::orb()`, compared with the Hamming distance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BinaryDescriptor {
+    bits: [u8; 32],
+}
+
+/// `FeatureMatch` holds a match between a query descriptor and a train descriptor, returned by `match_descriptors()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureMatch {
+    query: usize,
+    train: usize,
+    distance: u32,
+}
+
+/// Side of the square patch described around a keypoint (at the scale of its pyramid level)
+///
+/// ## Note
+/// Constant is used internally (private scope)
+const PATCH_SIZE: usize = 31;
+
+/// Keypoints closer than this distance to the border of a pyramid level are discarded, so that the rotated pattern stays within the level
+///
+/// ## Note
+/// Constant is used internally (private scope)
+const BORDER: usize = 22;
+
+/// Core implementation of struct `BinaryDescriptor`
+impl BinaryDescriptor {
+    /// Creates a descriptor from its bits, the first bit being the least significant bit of the first byte
+    pub fn new(bits: [u8; 32]) -> Self {
+        BinaryDescriptor { bits }
+    }
+
+    /// Bits of the descriptor
+    pub fn bits(&self) -> &[u8; 32] {
+        &self.bits
+    }
+
+    /// Hamming distance to another descriptor, i.e. the number of bits which differ
+    pub fn distance(&self, other: &BinaryDescriptor) -> u32 {
+        self.bits
+            .iter()
+            .zip(&other.bits)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+}
+
+/// Core implementation of struct `FeatureMatch`
+impl FeatureMatch {
+    /// Index of the descriptor within the query descriptors
+    pub fn query(&self) -> usize {
+        self.query
+    }
+
+    /// Index of the descriptor within the train descriptors
+    pub fn train(&self) -> usize {
+        self.train
+    }
+
+    /// Hamming distance between both descriptors
+    pub fn distance(&self) -> u32 {
+        self.distance
+    }
+}
+
+/// Implementation of binary feature extraction of a grayscale image
+impl ImageData<u8, 1> {
+    /// Finds keypoints and computes their descriptors with ORB (oriented FAST and rotated BRIEF)
+    ///
+    /// Keypoints are found with FAST-9 (`ImageData::fast()`) on every level of an image pyramid, each level being `scale_factor` times smaller than the previous one. They are ranked by their Harris response and the `max_features` keypoints (`0` keeps them all) are shared among the levels in proportion to their area. Each keypoint is oriented by the intensity centroid of its patch, then described by 256 intensity comparisons within the smoothed patch, rotated by the orientation of the keypoint
+    ///
+    /// Positions and scales of the keypoints are expressed in pixels of the image, keypoints being returned level by level with the strongest first. Keypoints closer than 22 pixels (of their level) to the border are discarded
+    ///
+    /// Returns `Err` if `scale_factor` isn't greater than `1.0` or `levels` is `0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::match_descriptors;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // blocks of pseudo random intensities
+    /// let value = |x: usize, y: usize| [(((x / 5 * 73_856_093) ^ (y / 5 * 19_349_663)) % 241) as u8];
+    /// let img = ImageData::new(100, 100, Array2::from_shape_fn((100, 100), |(y, x)| value(x, y)));
+    /// let shifted = ImageData::new(100, 100, Array2::from_shape_fn((100, 100), |(y, x)| value(x + 10, y + 5)));
+    ///
+    /// let (keypoints, descriptors) = img.orb(200, 1.2, 3, 20)?;
+    /// let (shifted_keypoints, shifted_descriptors) = shifted.orb(200, 1.2, 3, 20)?;
+    /// assert_eq!(keypoints.len(), descriptors.len());
+    ///
+    /// let matches = match_descriptors(&descriptors, &shifted_descriptors, None, true)?;
+    /// let exact = matches.iter().filter(|m| {
+    ///     let (x, y) = keypoints[m.query()].position();
+    ///     let (sx, sy) = shifted_keypoints[m.train()].position();
+    ///     (x - sx - 10.0).abs() < 1.0 && (y - sy - 5.0).abs() < 1.0
+    /// });
+    /// assert!(exact.count() > matches.len() / 2);
+    /// # Ok(()) }
+    /// ```
+    pub fn orb(
+        &self,
+        max_features: usize,
+        scale_factor: f64,
+        levels: u32,
+        fast_threshold: u8,
+    ) -> VisionXResult<(Vec<KeyPoint>, Vec<BinaryDescriptor>)> {
+        self.view()
+            .orb(max_features, scale_factor, levels, fast_threshold)
+    }
+}
+
+/// Implementation of binary feature extraction of a borrowed grayscale image
+impl<'a> ImageView<'a, u8, 1> {
+    /// Finds keypoints and computes their descriptors with ORB on the view, see `ImageData::orb()`
+    pub fn orb(
+        &self,
+        max_features: usize,
+        scale_factor: f64,
+        levels: u32,
+        fast_threshold: u8,
+    ) -> VisionXResult<(Vec<KeyPoint>, Vec<BinaryDescriptor>)> {
+        if !(scale_factor.is_finite() && scale_factor > 1.0) || levels == 0 {
+            let err = format!(
+                "extracting ORB features with a scale factor of {scale_factor} over {levels} levels"
+            );
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        }
+
+        // features are shared among the levels in proportion to their area
+        let factor = 1.0 / (scale_factor * scale_factor);
+        let first_share = (1.0 - factor) / (1.0 - factor.powi(levels as i32));
+        let pattern = pattern();
+
+        let mut keypoints = Vec::new();
+        let mut descriptors = Vec::new();
+        let mut level = ImageData::new(*self.width(), *self.height(), self.pixels().to_owned());
+        for l in 0..levels {
+            let (width, height) = (*level.width() as usize, *level.height() as usize);
+            if width <= 2 * BORDER || height <= 2 * BORDER {
+                break;
+            }
+            let scale = scale_factor.powi(l as i32);
+
+            let harris = level.corner_response(7, CornerScore::Harris { k: 0.04 })?;
+            let mut candidates: Vec<(usize, usize, f64)> = level
+                .fast(fast_threshold, true)
+                .into_iter()
+                .map(|keypoint| {
+                    let (x, y) = keypoint.position();
+                    (x as usize, y as usize)
+                })
+                .filter(|&(x, y)| {
+                    (BORDER..width - BORDER).contains(&x) && (BORDER..height - BORDER).contains(&y)
+                })
+                .map(|(x, y)| (x, y, harris.pixels()[(y, x)][0] as f64))
+                .collect();
+            candidates.sort_by(|a, b| b.2.total_cmp(&a.2));
+            if max_features > 0 {
+                let share = max_features as f64 * first_share * factor.powi(l as i32);
+                candidates.truncate(share.round() as usize);
+            }
+
+            let source: Vec<f64> = level.pixels().iter().map(|px| px[0] as f64).collect();
+            let smoothed = smooth(&source, *level.width() as usize, *level.height() as usize);
+            for (x, y, response) in candidates {
+                let angle = orientation(&level, x, y);
+                let (sin, cos) = angle.sin_cos();
+                let mut bits = [0u8; 32];
+                for (i, &((x1, y1), (x2, y2))) in pattern.iter().enumerate() {
+                    let sample = |px: f64, py: f64| {
+                        let sx = (x as f64 + (cos * px - sin * py).round()) as usize;
+                        let sy = (y as f64 + (sin * px + cos * py).round()) as usize;
+                        smoothed[sy * width + sx]
+                    };
+                    if sample(x1, y1) < sample(x2, y2) {
+                        bits[i / 8] |= 1 << (i % 8);
+                    }
+                }
+                keypoints.push(KeyPoint::new(
+                    (x as f64 * scale, y as f64 * scale),
+                    response,
+                    PATCH_SIZE as f64 * scale,
+                    Some(angle),
+                ));
+                descriptors.push(BinaryDescriptor::new(bits));
+            }
+
+            if l + 1 < levels {
+                level = downscale(&level, smoothed, scale_factor);
+            }
+        }
+        Ok((keypoints, descriptors))
+    }
+}
+
+/// Matches every query descriptor with its nearest train descriptor (brute force, Hamming distance). Matches are returned in the order of the query descriptors
+///
+/// With a `ratio`, a match is kept only if its distance is lower than `ratio` times the distance of the second nearest train descriptor (Lowe's ratio test), which discards ambiguous matches. With `cross_check`, a match is kept only if the query descriptor is also the nearest descriptor of its train descriptor
+///
+/// Returns `Err` if `ratio` isn't within `(0.0, 1.0]`
+///
+/// # Example
+///
+/// ```
+/// use vision_x::imgproc::{match_descriptors, BinaryDescriptor};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let query = [BinaryDescriptor::new([0b1010; 32])];
+/// let train = [BinaryDescriptor::new([0; 32]), BinaryDescriptor::new([0b1000; 32])];
+///
+/// let matches = match_descriptors(&query, &train, Some(0.8), true)?;
+/// assert_eq!(1, matches.len());
+/// assert_eq!((0, 1, 32), (matches[0].query(), matches[0].train(), matches[0].distance()));
+/// # Ok(()) }
+/// ```
+pub fn match_descriptors(
+    query: &[BinaryDescriptor],
+    train: &[BinaryDescriptor],
+    ratio: Option<f64>,
+    cross_check: bool,
+) -> VisionXResult<Vec<FeatureMatch>> {
+    if let Some(ratio) = ratio.filter(|ratio| !(*ratio > 0.0 && *ratio <= 1.0)) {
+        let err = format!("matching descriptors with a ratio of {ratio}");
+        return Err(VisionXErrorKind::InvalidParameter(err).into());
+    }
+
+    let mut matches = Vec::new();
+    for (q, descriptor) in query.iter().enumerate() {
+        let Some((train_index, distance, second)) = nearest(descriptor, train) else {
+            continue;
+        };
+        if let (Some(ratio), Some(second)) = (ratio, second) {
+            if distance as f64 >= ratio * second as f64 {
+                continue;
+            }
+        }
+        if cross_check {
+            let back = nearest(&train[train_index], query).map(|(index, _, _)| index);
+            if back != Some(q) {
+                continue;
+            }
+        }
+        matches.push(FeatureMatch {
+            query: q,
+            train: train_index,
+            distance,
+        });
+    }
+    Ok(matches)
+}
+
+/// Returns the index and distance of the nearest descriptor (the first one for ties) along with the distance of the second nearest descriptor, `None` if there is no descriptor
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn nearest(
+    descriptor: &BinaryDescriptor,
+    descriptors: &[BinaryDescriptor],
+) -> Option<(usize, u32, Option<u32>)> {
+    let mut best: Option<(usize, u32, Option<u32>)> = None;
+    for (i, other) in descriptors.iter().enumerate() {
+        let distance = descriptor.distance(other);
+        best = match best {
+            None => Some((i, distance, None)),
+            Some((_, nearest, second)) if distance < nearest => Some((
+                i,
+                distance,
+                Some(second.map_or(nearest, |s| s.min(nearest))),
+            )),
+            Some((index, nearest, second)) => Some((
+                index,
+                nearest,
+                Some(second.map_or(distance, |s| s.min(distance))),
+            )),
+        };
+    }
+    best
+}
+
+/// Returns the 256 pairs of points `((x1, y1), (x2, y2))` compared by the descriptor, relative to the keypoint. Points are drawn from a deterministic, roughly Gaussian distribution within the patch
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn pattern() -> [((f64, f64), (f64, f64)); 256] {
+    // fixed seed, so that descriptors don't change between runs
+    let mut random = XorShift::new(0x9E37_79B9_7F4A_7C15);
+    let mut coordinate = || {
+        // sum of three uniform values within [-5, 5], i.e. within the patch
+        (0..3).map(|_| random.below(11) as f64 - 5.0).sum::<f64>()
+    };
+    std::array::from_fn(|_| {
+        let (x1, y1, x2, y2) = (coordinate(), coordinate(), coordinate(), coordinate());
+        ((x1, y1), (x2, y2))
+    })
+}
+
+/// Returns the orientation in radians of the keypoint `(x, y)`, i.e. the angle of the vector from the keypoint to the intensity centroid of the disk of radius `PATCH_SIZE / 2` around it
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn orientation(img: &ImageData<u8, 1>, x: usize, y: usize) -> f64 {
+    let radius = (PATCH_SIZE / 2) as isize;
+    let pixels = img.pixels();
+    let (mut m10, mut m01) = (0.0, 0.0);
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy > radius * radius {
+                continue;
+            }
+            let value = pixels[((y as isize + dy) as usize, (x as isize + dx) as usize)][0] as f64;
+            m10 += dx as f64 * value;
+            m01 += dy as f64 * value;
+        }
+    }
+    m01.atan2(m10)
+}
+
+/// Returns the next level of the pyramid, sampled bilinearly from the smoothed level
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn downscale(level: &ImageData<u8, 1>, smoothed: Vec<f64>, scale_factor: f64) -> ImageData<u8, 1> {
+    let (width, height) = (*level.width() as usize, *level.height() as usize);
+    let (new_width, new_height) = (
+        (width as f64 / scale_factor).round() as usize,
+        (height as f64 / scale_factor).round() as usize,
+    );
+    let pixels = Array2::from_shape_fn((new_height, new_width), |(y, x)| {
+        // pixel centres are aligned
+        let sx = ((x as f64 + 0.5) * scale_factor - 0.5).clamp(0.0, (width - 1) as f64);
+        let sy = ((y as f64 + 0.5) * scale_factor - 0.5).clamp(0.0, (height - 1) as f64);
+        let (x0, y0) = (sx.floor() as usize, sy.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(width - 1), (y0 + 1).min(height - 1));
+        let (fx, fy) = (sx - x0 as f64, sy - y0 as f64);
+        let top = smoothed[y0 * width + x0] * (1.0 - fx) + smoothed[y0 * width + x1] * fx;
+        let bottom = smoothed[y1 * width + x0] * (1.0 - fx) + smoothed[y1 * width + x1] * fx;
+        [(top * (1.0 - fy) + bottom * fy).round() as u8]
+    });
+    ImageData::new(new_width as u32, new_height as u32, pixels)
+}
+
+#[cfg(test)]
+mod descriptors_test {
+    use super::{match_descriptors, BinaryDescriptor};
+    use crate::{
+        core::ImageData,
+        errors::{VisionXError, VisionXErrorKind},
+    };
+    use ndarray::Array2;
+
+    fn texture(x: usize, y: usize) -> [u8; 1] {
+        // blocks of pseudo random intensities
+        let hash = (x / 6 * 73_856_093) ^ (y / 6 * 19_349_663);
+        [(hash % 241) as u8]
+    }
+
+    // Features are found on every level and survive a rotation of the image
+    #[test]
+    fn orb() {
+        let size = 120;
+        let img = ImageData::new(
+            size as u32,
+            size as u32,
+            Array2::from_shape_fn((size, size), |(y, x)| texture(x, y)),
+        );
+        // rotation by 90 degrees clockwise: (x, y) -> (size - 1 - y, x)
+        let rotated = ImageData::new(
+            size as u32,
+            size as u32,
+            Array2::from_shape_fn((size, size), |(y, x)| texture(y, size - 1 - x)),
+        );
+
+        let (keypoints, descriptors) = img.orb(300, 1.3, 3, 20).unwrap();
+        assert_eq!(keypoints.len(), descriptors.len());
+        assert!(keypoints.len() > 50 && keypoints.len() <= 300);
+        assert!(keypoints.iter().any(|keypoint| keypoint.scale() > 31.0));
+        assert!(keypoints.iter().all(|keypoint| keypoint.angle().is_some()));
+
+        let (rotated_keypoints, rotated_descriptors) = rotated.orb(300, 1.3, 3, 20).unwrap();
+        let matches =
+            match_descriptors(&descriptors, &rotated_descriptors, Some(0.9), true).unwrap();
+        assert!(matches.len() > 10);
+        let correct = matches
+            .iter()
+            .filter(|m| {
+                let (x, y) = keypoints[m.query()].position();
+                let (rx, ry) = rotated_keypoints[m.train()].position();
+                (rx - (size - 1) as f64 + y).abs() < 3.0 && (ry - x).abs() < 3.0
+            })
+            .count();
+        assert!(correct * 10 > matches.len() * 8);
+
+        for (scale_factor, levels) in [(1.0, 3), (1.2, 0)] {
+            assert!(matches!(
+                img.orb(100, scale_factor, levels, 20),
+                Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+            ));
+        }
+    }
+
+    // Ratio test and cross check discard ambiguous matches
+    #[test]
+    fn matching() {
+        let descriptor = |bytes: [u8; 4]| {
+            let mut bits = [0u8; 32];
+            bits[..4].copy_from_slice(&bytes);
+            BinaryDescriptor::new(bits)
+        };
+        let query = [
+            descriptor([0xff, 0, 0, 0]),
+            descriptor([0, 0xff, 0, 0]),
+            descriptor([0, 0xff, 0x01, 0]),
+        ];
+        let train = [
+            descriptor([0xff, 0x01, 0, 0]),
+            descriptor([0, 0xff, 0x03, 0]),
+            descriptor([0xff, 0x03, 0, 0]),
+        ];
+        assert_eq!(16, query[0].distance(&query[1]));
+
+        let matches = match_descriptors(&query, &train, None, false).unwrap();
+        let pairs: Vec<_> = matches
+            .iter()
+            .map(|m| (m.query(), m.train(), m.distance()))
+            .collect();
+        assert_eq!(vec![(0, 0, 1), (1, 1, 2), (2, 1, 1)], pairs);
+
+        // train 2 is almost as close to query 0 as train 0
+        let matches = match_descriptors(&query, &train, Some(0.6), false).unwrap();
+        let pairs: Vec<_> = matches.iter().map(|m| (m.query(), m.train())).collect();
+        assert_eq!(vec![(0, 0), (1, 1), (2, 1)], pairs);
+        let matches = match_descriptors(&query, &train, Some(0.4), false).unwrap();
+        let pairs: Vec<_> = matches.iter().map(|m| (m.query(), m.train())).collect();
+        assert_eq!(vec![(1, 1), (2, 1)], pairs);
+
+        // train 1 is closer to query 2 than to query 1
+        let matches = match_descriptors(&query, &train, None, true).unwrap();
+        let pairs: Vec<_> = matches.iter().map(|m| (m.query(), m.train())).collect();
+        assert_eq!(vec![(0, 0), (2, 1)], pairs);
+
+        assert!(match_descriptors(&query, &[], None, true)
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            match_descriptors(&query, &train, Some(1.5), false),
+            Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+        ));
+    }
+}
@@ -0,0 +1,540 @@
+use super::kernel::{clamped_index, local_maxima, sobel};
+use crate::{
+    core::{Grayscale32F, ImageData, ImageView},
+    errors::{VisionXErrorKind, VisionXResult},
+};
+use ndarray::Array2;
+
+/// `KeyPoint` holds a point of interest found by a detector (e.g. `ImageData::good_features_to_track()` or `ImageData::fast()`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyPoint {
+    position: (f64, f64),
+    response: f64,
+    scale: f64,
+    angle: Option<f64>,
+}
+
+/// `CornerScore` defines how the structure tensor of a neighbourhood is scored by `ImageData::corner_response()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CornerScore {
+    /// Harris response `det(M) - k * trace(M)^2`, `k` being usually within `[0.04, 0.06]`
+    Harris {
+        /// Sensitivity of the detector, lower values find more corners
+        k: f64,
+    },
+    /// Smallest eigenvalue of the structure tensor (Shi-Tomasi), which is large only if the intensity varies along both directions
+    MinEigenvalue,
+}
+
+/// Offsets `(x, y)` of the Bresenham circle of radius 3 used by FAST, clockwise from the top
+///
+/// ## Note
+/// Constant is used internally (private scope)
+const CIRCLE: [(isize, isize); 16] = [
+    (0, -3),
+    (1, -3),
+    (2, -2),
+    (3, -1),
+    (3, 0),
+    (3, 1),
+    (2, 2),
+    (1, 3),
+    (0, 3),
+    (-1, 3),
+    (-2, 2),
+    (-3, 1),
+    (-3, 0),
+    (-3, -1),
+    (-2, -2),
+    (-1, -3),
+];
+
+/// Core implementation of struct `KeyPoint`
+impl KeyPoint {
+    /// Creates a keypoint at `(x, y)` with the response of the detector, the diameter of its meaningful neighbourhood and its orientation in radians (if any)
+    pub fn new(position: (f64, f64), response: f64, scale: f64, angle: Option<f64>) -> Self {
+        KeyPoint {
+            position,
+            response,
+            scale,
+            angle,
+        }
+    }
+
+    /// Position `(x, y)` of the keypoint
+    pub fn position(&self) -> (f64, f64) {
+        self.position
+    }
+
+    /// Response of the detector, stronger keypoints having larger responses
+    pub fn response(&self) -> f64 {
+        self.response
+    }
+
+    /// Diameter of the meaningful neighbourhood of the keypoint, in pixels of the image
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Orientation of the keypoint in radians, `None` if the detector doesn't compute one
+    pub fn angle(&self) -> Option<f64> {
+        self.angle
+    }
+}
+
+/// Implementation of corner and keypoint detection of a grayscale image
+impl ImageData<u8, 1> {
+    /// Computes the corner response of every pixel from the structure tensor of its `block_size` x `block_size` neighbourhood, i.e. the mean of the products of the Sobel gradients (intensities being normalised to `[0.0, 1.0]`). Pixels outside of the image replicate the border
+    ///
+    /// Returns `Err` if `block_size` isn't odd or is larger than the image
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::CornerScore;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // white square from (10, 10) to (29, 29)
+    /// let pixels = Array2::from_shape_fn((40, 40), |(y, x)| [if (10..30).contains(&x) && (10..30).contains(&y) { 255u8 } else { 0 }]);
+    /// let img = ImageData::new(40, 40, pixels);
+    ///
+    /// let response = img.corner_response(3, CornerScore::Harris { k: 0.04 })?;
+    /// let corner = response.get_pixel_at(10, 10).unwrap()[0];
+    /// let edge = response.get_pixel_at(20, 10).unwrap()[0];
+    /// let flat = response.get_pixel_at(20, 20).unwrap()[0];
+    /// assert!(corner > 0.0 && edge < 0.0 && flat == 0.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn corner_response(
+        &self,
+        block_size: u32,
+        score: CornerScore,
+    ) -> VisionXResult<Grayscale32F> {
+        self.view().corner_response(block_size, score)
+    }
+
+    /// Finds the strongest corners of the image (good features to track), sorted by decreasing response
+    ///
+    /// Corners are the local maxima (3x3) of `ImageData::corner_response()` whose response is at least `quality_level` times the strongest response. Weaker corners closer than `min_distance` to a stronger corner are then discarded and at most `max_corners` corners are kept (`0` keeps them all). The scale of the keypoints is `block_size`
+    ///
+    /// Returns `Err` if `block_size` isn't odd or is larger than the image, `quality_level` isn't within `(0.0, 1.0]` or `min_distance` is negative or not finite
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::CornerScore;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let pixels = Array2::from_shape_fn((40, 40), |(y, x)| [if (10..30).contains(&x) && (10..30).contains(&y) { 255u8 } else { 0 }]);
+    /// let img = ImageData::new(40, 40, pixels);
+    ///
+    /// let corners = img.good_features_to_track(10, 0.1, 5.0, 3, CornerScore::MinEigenvalue)?;
+    /// assert_eq!(4, corners.len());
+    /// for corner in corners {
+    ///     let (x, y) = corner.position();
+    ///     assert!((x - 10.0).abs() <= 1.0 || (x - 29.0).abs() <= 1.0);
+    ///     assert!((y - 10.0).abs() <= 1.0 || (y - 29.0).abs() <= 1.0);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn good_features_to_track(
+        &self,
+        max_corners: usize,
+        quality_level: f64,
+        min_distance: f64,
+        block_size: u32,
+        score: CornerScore,
+    ) -> VisionXResult<Vec<KeyPoint>> {
+        self.view().good_features_to_track(
+            max_corners,
+            quality_level,
+            min_distance,
+            block_size,
+            score,
+        )
+    }
+
+    /// Finds keypoints with the FAST-9 detector, sorted by decreasing response. A pixel is a keypoint if at least 9 contiguous pixels of the circle of radius 3 around it are all brighter than the pixel plus `threshold`, or all darker than the pixel minus `threshold`
+    ///
+    /// The response is the sum of the absolute differences beyond `threshold` between the pixel and the brighter (or darker) pixels of the circle. Pixels closer than 3 pixels to the border are skipped. If `nonmax_suppression` is set, keypoints which aren't the maxima (3x3) of the response are discarded. The scale of the keypoints is `7.0`, the diameter of the circle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_shape_fn((40, 40), |(y, x)| [if (10..30).contains(&x) && (10..30).contains(&y) { 200u8 } else { 20 }]);
+    /// let img = ImageData::new(40, 40, pixels);
+    ///
+    /// let keypoints = img.fast(40, true);
+    /// assert_eq!(4, keypoints.len());
+    /// assert!(keypoints.iter().any(|keypoint| keypoint.position() == (10.0, 10.0)));
+    /// # }
+    /// ```
+    pub fn fast(&self, threshold: u8, nonmax_suppression: bool) -> Vec<KeyPoint> {
+        self.view().fast(threshold, nonmax_suppression)
+    }
+}
+
+/// Implementation of corner and keypoint detection of a borrowed grayscale image
+impl<'a> ImageView<'a, u8, 1> {
+    /// Computes the corner response of every pixel of the view, see `ImageData::corner_response()`
+    pub fn corner_response(
+        &self,
+        block_size: u32,
+        score: CornerScore,
+    ) -> VisionXResult<Grayscale32F> {
+        let response = corner_responses(self, block_size, score)?;
+        let width = *self.width() as usize;
+        let pixels = Array2::from_shape_fn(self.pixels().dim(), |(y, x)| {
+            [response[y * width + x] as f32]
+        });
+        Ok(ImageData::new(*self.width(), *self.height(), pixels))
+    }
+
+    /// Finds the strongest corners of the view, see `ImageData::good_features_to_track()`
+    pub fn good_features_to_track(
+        &self,
+        max_corners: usize,
+        quality_level: f64,
+        min_distance: f64,
+        block_size: u32,
+        score: CornerScore,
+    ) -> VisionXResult<Vec<KeyPoint>> {
+        let valid_distance = min_distance.is_finite() && min_distance >= 0.0;
+        if !(quality_level > 0.0 && quality_level <= 1.0 && valid_distance) {
+            let err = format!(
+                "tracking features with a quality level of {quality_level} and a minimum distance of {min_distance}"
+            );
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        }
+        let response = corner_responses(self, block_size, score)?;
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+        let strongest = response.iter().cloned().fold(0.0, f64::max);
+        if strongest <= 0.0 {
+            return Ok(Vec::new());
+        }
+
+        let threshold = quality_level * strongest;
+        let mut candidates = local_maxima(&response, width, height, |value| value > threshold);
+        candidates.sort_by(|a, b| response[*b].total_cmp(&response[*a]));
+
+        // grid of the kept corners, each cell being `min_distance` wide so only the 3x3 neighbouring cells are searched
+        let cell = min_distance.max(1.0);
+        let (grid_width, grid_height) = (
+            (width as f64 / cell).ceil() as usize,
+            (height as f64 / cell).ceil() as usize,
+        );
+        let mut grid: Vec<Vec<(f64, f64)>> = vec![Vec::new(); grid_width * grid_height];
+        let mut corners = Vec::new();
+        for index in candidates {
+            if max_corners > 0 && corners.len() == max_corners {
+                break;
+            }
+            let (x, y) = ((index % width) as f64, (index / width) as f64);
+            let (cx, cy) = ((x / cell) as usize, (y / cell) as usize);
+            if min_distance > 0.0 {
+                let close = (cy.saturating_sub(1)..(cy + 2).min(grid_height))
+                    .flat_map(|gy| {
+                        (cx.saturating_sub(1)..(cx + 2).min(grid_width)).map(move |gx| (gx, gy))
+                    })
+                    .flat_map(|(gx, gy)| grid[gy * grid_width + gx].iter())
+                    .any(|&(px, py)| (px - x).hypot(py - y) < min_distance);
+                if close {
+                    continue;
+                }
+                grid[cy * grid_width + cx].push((x, y));
+            }
+            corners.push(KeyPoint::new(
+                (x, y),
+                response[index],
+                block_size as f64,
+                None,
+            ));
+        }
+        Ok(corners)
+    }
+
+    /// Finds keypoints of the view with the FAST-9 detector, see `ImageData::fast()`
+    pub fn fast(&self, threshold: u8, nonmax_suppression: bool) -> Vec<KeyPoint> {
+        let pixels = self.pixels();
+        let (height, width) = pixels.dim();
+        if width < 7 || height < 7 {
+            return Vec::new();
+        }
+
+        let mut scores = vec![0.0; width * height];
+        for y in 3..height - 3 {
+            for x in 3..width - 3 {
+                let center = pixels[(y, x)][0] as i32;
+                let circle: [i32; 16] = std::array::from_fn(|i| {
+                    let (dx, dy) = CIRCLE[i];
+                    pixels[((y as isize + dy) as usize, (x as isize + dx) as usize)][0] as i32
+                });
+                scores[y * width + x] = fast_score(center, &circle, threshold as i32);
+            }
+        }
+
+        let mut keypoints: Vec<KeyPoint> = if nonmax_suppression {
+            local_maxima(&scores, width, height, |score| score > 0.0)
+        } else {
+            (0..width * height).filter(|&i| scores[i] > 0.0).collect()
+        }
+        .into_iter()
+        .map(|i| {
+            let position = ((i % width) as f64, (i / width) as f64);
+            KeyPoint::new(position, scores[i], 7.0, None)
+        })
+        .collect();
+        keypoints.sort_by(|a, b| b.response.total_cmp(&a.response));
+        keypoints
+    }
+}
+
+/// Computes the corner response of every pixel, row by row
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn corner_responses(
+    img: &ImageView<'_, u8, 1>,
+    block_size: u32,
+    score: CornerScore,
+) -> VisionXResult<Vec<f64>> {
+    let pixels = img.pixels();
+    let (height, width) = pixels.dim();
+    // a block of a single pixel is valid even for an empty image
+    if block_size % 2 == 0 || block_size as usize > width.max(height).max(1) {
+        let err = format!(
+            "computing corner responses over blocks of size {block_size} for image size ({width}, {height})"
+        );
+        return Err(VisionXErrorKind::InvalidParameter(err).into());
+    }
+    let clamp = |x: isize, y: isize| clamped_index(width, height, x, y);
+
+    // products of the Sobel gradients
+    let source: Vec<f64> = pixels.iter().map(|px| px[0] as f64 / 255.0).collect();
+    let (gx, gy) = sobel(&source, width, height);
+    let products: Vec<[f64; 3]> = gx
+        .iter()
+        .zip(&gy)
+        .map(|(gx, gy)| {
+            let (gx, gy) = (gx / 8.0, gy / 8.0);
+            [gx * gx, gx * gy, gy * gy]
+        })
+        .collect();
+
+    // separable box filter of the products, rows then columns
+    let radius = (block_size / 2) as isize;
+    let count = block_size as f64 * block_size as f64;
+    let sum = |values: &[[f64; 3]], index: &dyn Fn(isize) -> usize| {
+        (-radius..=radius).fold([0.0; 3], |acc, k| {
+            let value = values[index(k)];
+            [acc[0] + value[0], acc[1] + value[1], acc[2] + value[2]]
+        })
+    };
+    let mut rows = vec![[0.0; 3]; width * height];
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            rows[clamp(x, y)] = sum(&products, &|k| clamp(x + k, y));
+        }
+    }
+    let mut response = vec![0.0; width * height];
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            let [a, b, c] = sum(&rows, &|k| clamp(x, y + k)).map(|value| value / count);
+            response[clamp(x, y)] = match score {
+                CornerScore::Harris { k } => a * c - b * b - k * (a + c) * (a + c),
+                CornerScore::MinEigenvalue => (a + c) / 2.0 - ((a - c) / 2.0).hypot(b),
+            };
+        }
+    }
+    Ok(response)
+}
+
+/// Returns the FAST-9 score of a pixel, `0.0` if it isn't a keypoint
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn fast_score(center: i32, circle: &[i32; 16], threshold: i32) -> f64 {
+    // an arc of 9 pixels covers at least 2 of the 4 compass points
+    let brighter = |value: i32| value > center + threshold;
+    let darker = |value: i32| value < center - threshold;
+    let compass = [circle[0], circle[4], circle[8], circle[12]];
+    if compass.iter().filter(|&&v| brighter(v)).count() < 2
+        && compass.iter().filter(|&&v| darker(v)).count() < 2
+    {
+        return 0.0;
+    }
+
+    let mut best = 0.0;
+    for (is_set, difference) in [
+        (&brighter as &dyn Fn(i32) -> bool, 1),
+        (&darker as &dyn Fn(i32) -> bool, -1),
+    ] {
+        let mask = circle.iter().enumerate().fold(0u32, |mask, (i, &value)| {
+            mask | ((is_set(value) as u32) << i)
+        });
+        // contiguous run over the circle, which wraps around
+        let doubled = mask | (mask << 16);
+        let mut run = doubled;
+        for _ in 1..9 {
+            run &= run >> 1;
+        }
+        if run != 0 {
+            let score: i32 = circle
+                .iter()
+                .filter(|&&value| is_set(value))
+                .map(|&value| difference * (value - center) - threshold)
+                .sum();
+            best = f64::max(best, score as f64);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod features_test {
+    use super::CornerScore;
+    use crate::{
+        core::ImageData,
+        errors::{VisionXError, VisionXErrorKind},
+    };
+    use ndarray::Array2;
+
+    fn squares() -> ImageData<u8, 1> {
+        // bright square from (10, 10) to (29, 29) and dark square from (40, 15) to (51, 26)
+        let pixels = Array2::from_shape_fn((40, 64), |(y, x)| {
+            if (10..30).contains(&x) && (10..30).contains(&y) {
+                [220]
+            } else if (40..52).contains(&x) && (15..27).contains(&y) {
+                [10]
+            } else {
+                [100]
+            }
+        });
+        ImageData::new(64, 40, pixels)
+    }
+
+    // Harris and Shi-Tomasi find the corners of both squares
+    #[test]
+    fn corners() {
+        let img = squares();
+        let expected = [
+            (10.0, 10.0),
+            (29.0, 10.0),
+            (10.0, 29.0),
+            (29.0, 29.0),
+            (40.0, 15.0),
+            (51.0, 15.0),
+            (40.0, 26.0),
+            (51.0, 26.0),
+        ];
+        for score in [CornerScore::Harris { k: 0.04 }, CornerScore::MinEigenvalue] {
+            let corners = img.good_features_to_track(0, 0.05, 4.0, 3, score).unwrap();
+            assert_eq!(8, corners.len());
+            for pair in corners.windows(2) {
+                assert!(pair[0].response() >= pair[1].response());
+            }
+            for (ex, ey) in expected {
+                assert!(corners.iter().any(|corner| {
+                    let (x, y) = corner.position();
+                    (x - ex).abs() <= 1.0 && (y - ey).abs() <= 1.0
+                }));
+            }
+            assert!(corners
+                .iter()
+                .all(|corner| corner.scale() == 3.0 && corner.angle().is_none()));
+
+            // the bright square has the strongest contrast
+            let strongest = img.good_features_to_track(4, 0.05, 4.0, 3, score).unwrap();
+            assert_eq!(4, strongest.len());
+            assert!(strongest.iter().all(|corner| corner.position().0 < 32.0));
+
+            // corners of the dark square are closer than the minimum distance, unlike the corners of the bright square
+            let merged = img.good_features_to_track(0, 0.05, 13.0, 5, score).unwrap();
+            assert!((5..8).contains(&merged.len()));
+            assert_eq!(
+                4,
+                merged
+                    .iter()
+                    .filter(|corner| corner.position().0 < 32.0)
+                    .count()
+            );
+            for (i, a) in merged.iter().enumerate() {
+                for b in &merged[i + 1..] {
+                    let ((ax, ay), (bx, by)) = (a.position(), b.position());
+                    assert!((ax - bx).hypot(ay - by) >= 13.0);
+                }
+            }
+        }
+
+        // blocks as large as the image are valid, larger ones aren't
+        assert!(img.corner_response(63, CornerScore::MinEigenvalue).is_ok());
+        assert!(matches!(
+            img.corner_response(u32::MAX, CornerScore::MinEigenvalue),
+            Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+        ));
+
+        // flat images have no corner
+        let flat = ImageData::new(16, 16, Array2::from_elem((16, 16), [50u8]));
+        assert!(flat
+            .good_features_to_track(0, 0.1, 1.0, 3, CornerScore::MinEigenvalue)
+            .unwrap()
+            .is_empty());
+
+        for (quality_level, min_distance, block_size) in [
+            (0.0, 1.0, 3),
+            (0.1, -1.0, 3),
+            (0.1, f64::INFINITY, 3),
+            (0.1, f64::NAN, 3),
+            (0.1, 1.0, 4),
+            (0.1, 1.0, 65),
+            (0.1, 1.0, 65537),
+        ] {
+            assert!(matches!(
+                img.good_features_to_track(
+                    0,
+                    quality_level,
+                    min_distance,
+                    block_size,
+                    CornerScore::MinEigenvalue
+                ),
+                Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+            ));
+        }
+    }
+
+    // FAST finds the corners, not the edges, with and without non-maximum suppression
+    #[test]
+    fn fast() {
+        let img = squares();
+        let keypoints = img.fast(30, true);
+        assert_eq!(8, keypoints.len());
+        for pair in keypoints.windows(2) {
+            assert!(pair[0].response() >= pair[1].response());
+        }
+        for (ex, ey) in [(10.0, 10.0), (29.0, 29.0), (40.0, 15.0), (51.0, 26.0)] {
+            assert!(keypoints.iter().any(|keypoint| {
+                let (x, y) = keypoint.position();
+                (x - ex).abs() <= 1.0 && (y - ey).abs() <= 1.0
+            }));
+        }
+        assert!(keypoints.iter().all(|keypoint| keypoint.scale() == 7.0));
+
+        let all = img.fast(30, false);
+        assert!(all.len() > keypoints.len());
+        // contrast is below the threshold
+        assert!(img.fast(150, true).is_empty());
+        assert!(ImageData::new(6, 6, Array2::from_elem((6, 6), [0u8]))
+            .fast(10, true)
+            .is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
+
+/// `Complex` holds a complex number used by the fast Fourier transform
+///
+/// ## Note
+/// Struct is used within the crate (frequency domain processing and template matching)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Complex {
+    pub(crate) re: f64,
+    pub(crate) im: f64,
+}
+
+/// Core implementation of struct `Complex`
+impl Complex {
+    /// Creates a complex number from its real and imaginary parts
+    pub(crate) fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    /// Returns the complex conjugate
+    pub(crate) fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+/// Implements `Add` for `Complex`
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+/// Implements `Sub` for `Complex`
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+/// Implements `Mul` for `Complex`
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// Computes the (inverse) discrete Fourier transform of a sequence in place, the inverse transform being scaled by `1 / len`. Power of two lengths use the radix-2 algorithm, any other length is handled with Bluestein's algorithm
+///
+/// ## Note
+/// Function is used within the crate
+pub(crate) fn fft(data: &mut [Complex], inverse: bool) {
+    if data.len() <= 1 {
+        return;
+    }
+    if data.len().is_power_of_two() {
+        radix2(data, inverse);
+    } else {
+        bluestein(data, inverse);
+    }
+    if inverse {
+        let scale = 1.0 / data.len() as f64;
+        for value in data.iter_mut() {
+            *value = Complex::new(value.re * scale, value.im * scale);
+        }
+    }
+}
+
+/// Computes the unscaled (inverse) discrete Fourier transform of a sequence whose length is a power of two, in place with the iterative radix-2 algorithm
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn radix2(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+
+    // bit reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    // twiddle factors of the last stage, earlier stages use every `n / len`-th factor
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let twiddles: Vec<Complex> = (0..n / 2)
+        .map(|k| {
+            let (sin, cos) = (sign * 2.0 * PI * k as f64 / n as f64).sin_cos();
+            Complex::new(cos, sin)
+        })
+        .collect();
+    let mut len = 2;
+    while len <= n {
+        let stride = n / len;
+        for chunk in data.chunks_mut(len) {
+            let (low, high) = chunk.split_at_mut(len / 2);
+            for (k, (a, b)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+                let t = *b * twiddles[k * stride];
+                *b = *a - t;
+                *a = *a + t;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Computes the unscaled (inverse) discrete Fourier transform of a sequence of any length with Bluestein's algorithm, i.e. as a convolution with a chirp computed by power of two transforms
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn bluestein(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+    // chirp exp(sign * i * pi * k^2 / n), k^2 is reduced modulo 2n to keep the angle accurate
+    let chirp: Vec<Complex> = (0..n)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n);
+            let (sin, cos) = (sign * PI * k2 as f64 / n as f64).sin_cos();
+            Complex::new(cos, sin)
+        })
+        .collect();
+
+    let mut a = vec![Complex::default(); m];
+    for (k, value) in data.iter().enumerate() {
+        a[k] = *value * chirp[k];
+    }
+    let mut b = vec![Complex::default(); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x = *x * *y;
+    }
+    radix2(&mut a, true);
+
+    let scale = 1.0 / m as f64;
+    for (k, value) in data.iter_mut().enumerate() {
+        *value = a[k] * chirp[k];
+        *value = Complex::new(value.re * scale, value.im * scale);
+    }
+}
+
+/// Computes the (inverse) 2D discrete Fourier transform of a row-major buffer in place, rows then columns
+///
+/// ## Note
+/// Function is used within the crate
+pub(crate) fn fft_2d(data: &mut [Complex], width: usize, height: usize, inverse: bool) {
+    // empty images have nothing to transform
+    if width == 0 || height == 0 {
+        return;
+    }
+    for row in data.chunks_mut(width) {
+        fft(row, inverse);
+    }
+
+    let mut column = vec![Complex::default(); height];
+    for x in 0..width {
+        for (y, value) in column.iter_mut().enumerate() {
+            *value = data[y * width + x];
+        }
+        fft(&mut column, inverse);
+        for (y, value) in column.iter().enumerate() {
+            data[y * width + x] = *value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod fft_test {
+    use super::{fft, fft_2d, Complex};
+    use std::f64::consts::PI;
+
+    // Transform matches the definition of the DFT and is inverted
+    #[test]
+    fn transform_and_inverse() {
+        let input: Vec<Complex> = (0..16)
+            .map(|i| Complex::new((i * 7 % 5) as f64, (i % 3) as f64 - 1.0))
+            .collect();
+
+        let mut data = input.clone();
+        fft(&mut data, false);
+        for (k, value) in data.iter().enumerate() {
+            let expected = input
+                .iter()
+                .enumerate()
+                .fold(Complex::default(), |acc, (n, x)| {
+                    let (sin, cos) = (-2.0 * PI * (k * n) as f64 / 16.0).sin_cos();
+                    acc + *x * Complex::new(cos, sin)
+                });
+            assert!((value.re - expected.re).abs() < 1e-9 && (value.im - expected.im).abs() < 1e-9);
+        }
+
+        fft(&mut data, true);
+        for (a, b) in data.iter().zip(&input) {
+            assert!((a.re - b.re).abs() < 1e-12 && (a.im - b.im).abs() < 1e-12);
+        }
+
+        // lengths which aren't a power of two
+        for n in [1, 3, 12, 15] {
+            let input: Vec<Complex> = (0..n)
+                .map(|i| Complex::new((i * 5 % 7) as f64, (i % 4) as f64 - 2.0))
+                .collect();
+            let mut data = input.clone();
+            fft(&mut data, false);
+            for (k, value) in data.iter().enumerate() {
+                let expected = input
+                    .iter()
+                    .enumerate()
+                    .fold(Complex::default(), |acc, (i, x)| {
+                        let (sin, cos) = (-2.0 * PI * (k * i) as f64 / n as f64).sin_cos();
+                        acc + *x * Complex::new(cos, sin)
+                    });
+                assert!(
+                    (value.re - expected.re).abs() < 1e-9 && (value.im - expected.im).abs() < 1e-9
+                );
+            }
+            fft(&mut data, true);
+            for (a, b) in data.iter().zip(&input) {
+                assert!((a.re - b.re).abs() < 1e-9 && (a.im - b.im).abs() < 1e-9);
+            }
+        }
+
+        // 2D transform of an impulse is flat
+        let mut data = vec![Complex::default(); 6 * 4];
+        data[0] = Complex::new(1.0, 0.0);
+        fft_2d(&mut data, 6, 4, false);
+        assert!(data
+            .iter()
+            .all(|value| (value.re - 1.0).abs() < 1e-12 && value.im.abs() < 1e-12));
+        fft_2d(&mut [], 0, 5, false);
+        fft_2d(&mut [], 5, 0, true);
+    }
+}
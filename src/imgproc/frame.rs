@@ -1,8 +1,8 @@
 use ndarray::Array2;
 
 use crate::{
-    core::ImageData,
-    errors::{VisionXErrorKind, VisionXResult},
+    core::{ImageData, ImageView, ImageViewMut},
+    errors::{VisionXError, VisionXResult},
 };
 
 /// Implementation for frame/image size manipulation
@@ -32,28 +32,13 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
     /// # Ok(()) }
     /// ```
     pub fn resize(&self, width: u32, height: u32) -> Self {
-        let mut resized_pixels: ndarray::ArrayBase<
-            ndarray::OwnedRepr<[T; N]>,
-            ndarray::Dim<[usize; 2]>,
-        > = Array2::from_elem((height as usize, width as usize), [T::default(); N]);
-
-        for y in 0..height {
-            for x in 0..width {
-                let old_x = (x * self.width()) / width;
-                let old_y = (y * self.height()) / height;
-
-                let pixel = self.get_pixel_at(old_x as usize, old_y as usize);
-                if pixel.is_some() {
-                    resized_pixels[(y as usize, x as usize)] = *pixel.unwrap();
-                }
-            }
-        }
-
-        ImageData::new(width, height, resized_pixels)
+        self.view().resize(width, height)
     }
 
     /// Consider only a portion of the image (region of interest). Accepts two points which will be used in cropping the original image with type: `(u32, u32)` respectively
     ///
+    /// The pixels within the region are copied into a new image, use `ImageData::roi()` to avoid the copy
+    ///
     /// Returns `Err` if points are out of boundary
     ///
     /// # Example
@@ -74,26 +59,156 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
     /// # Ok(()) }
     /// ```
     pub fn crop(&self, point1: (u32, u32), point2: (u32, u32)) -> VisionXResult<Self> {
+        Ok(self.roi(point1, point2)?.to_owned())
+    }
+
+    /// Borrow only a portion of the image (region of interest) without copying. Accepts two points (top-left and bottom-right, exclusive) with type: `(u32, u32)` respectively
+    ///
+    /// Returns `Err` if points are out of boundary
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::core::Image;
+    /// use vision_x::io;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let path = "images/jpg/lenna.jpg";
+    /// let img: Image = io::read(path)?;
+    /// if let Image::ImageRgb(rgb) = img {
+    ///     let tile = rgb.roi((100, 100), (200, 200))?;
+    ///     let resized_tile = tile.resize(50, 50);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn roi(
+        &self,
+        point1: (u32, u32),
+        point2: (u32, u32),
+    ) -> VisionXResult<ImageView<'_, T, N>> {
+        self.view().roi(point1, point2)
+    }
+
+    /// Mutably borrow only a portion of the image (region of interest) without copying. Accepts two points (top-left and bottom-right, exclusive) with type: `(u32, u32)` respectively
+    ///
+    /// Any change made through the returned view is written back into the image. Returns `Err` if points are out of boundary
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::core::Image;
+    /// use vision_x::io;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let path = "images/jpg/lenna.jpg";
+    /// let img: Image = io::read(path)?;
+    /// if let Image::ImageRgb(mut rgb) = img {
+    ///     // blacken a region of the image
+    ///     rgb.roi_mut((100, 100), (200, 200))?.fill([0; 3]);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn roi_mut(
+        &mut self,
+        point1: (u32, u32),
+        point2: (u32, u32),
+    ) -> VisionXResult<ImageViewMut<'_, T, N>> {
+        let dim = (*self.width(), *self.height());
+        ImageView::<T, N>::check_roi(dim, point1, point2)?;
+
+        let pixels = self.view_mut().into_pixels().slice_move(ndarray::s![
+            point1.1 as usize..point2.1 as usize,
+            point1.0 as usize..point2.0 as usize
+        ]);
+        Ok(ImageViewMut::new(pixels))
+    }
+}
+
+/// Implementation for frame/image size manipulation of a mutably borrowed image
+impl<'a, T: Default + Copy, const N: usize> ImageViewMut<'a, T, N> {
+    /// Mutably borrow only a portion of the view (region of interest) without copying. Points are relative to the top-left corner of the view, see `ImageData::roi_mut()`
+    ///
+    /// Returns `Err` if points are out of boundary
+    pub fn roi_mut(
+        &mut self,
+        point1: (u32, u32),
+        point2: (u32, u32),
+    ) -> VisionXResult<ImageViewMut<'_, T, N>> {
         let dim = (*self.width(), *self.height());
-        if point1.0 < point2.0 && point1.1 < point2.1 && point2.0 <= dim.0 && point2.1 <= dim.1 {
-            let cropped_pixels: ndarray::ArrayBase<
-                ndarray::OwnedRepr<[T; N]>,
-                ndarray::Dim<[usize; 2]>,
-            > = self
-                .pixels()
-                .slice(ndarray::s![
-                    point1.1 as usize..point2.1 as usize,
-                    point1.0 as usize..point2.0 as usize
-                ])
-                .to_owned();
-
-            let new_width = point2.0 - point1.0;
-            let new_height = point2.1 - point1.1;
-
-            Ok(ImageData::new(new_width, new_height, cropped_pixels))
+        ImageView::<T, N>::check_roi(dim, point1, point2)?;
+
+        let pixels = self.pixels_mut().slice_mut(ndarray::s![
+            point1.1 as usize..point2.1 as usize,
+            point1.0 as usize..point2.0 as usize
+        ]);
+        Ok(ImageViewMut::new(pixels))
+    }
+}
+
+/// Implementation for frame/image size manipulation of a borrowed image
+impl<'a, T: Default + Copy, const N: usize> ImageView<'a, T, N> {
+    /// Create a image resized to specified dimension from the view. Accepts width and height as `u32` respectively
+    ///
+    /// Uses **nearest-neighbor interpolation**, see `ImageData::resize()`
+    pub fn resize(&self, width: u32, height: u32) -> ImageData<T, N> {
+        let mut resized_pixels: ndarray::ArrayBase<
+            ndarray::OwnedRepr<[T; N]>,
+            ndarray::Dim<[usize; 2]>,
+        > = Array2::from_elem((height as usize, width as usize), [T::default(); N]);
+
+        for y in 0..height {
+            for x in 0..width {
+                let old_x = (x as u64 * *self.width() as u64) / width as u64;
+                let old_y = (y as u64 * *self.height() as u64) / height as u64;
+
+                let pixel = self.get_pixel_at(old_x as usize, old_y as usize);
+                if let Some(pixel) = pixel {
+                    resized_pixels[(y as usize, x as usize)] = *pixel;
+                }
+            }
+        }
+
+        ImageData::new(width, height, resized_pixels)
+    }
+
+    /// Borrow only a portion of the view (region of interest) without copying. Points are relative to the top-left corner of the view, see `ImageData::roi()`
+    ///
+    /// Returns `Err` if points are out of boundary
+    pub fn roi(
+        &self,
+        point1: (u32, u32),
+        point2: (u32, u32),
+    ) -> VisionXResult<ImageView<'a, T, N>> {
+        let dim = (*self.width(), *self.height());
+        Self::check_roi(dim, point1, point2)?;
+
+        let pixels = self.pixels().slice_move(ndarray::s![
+            point1.1 as usize..point2.1 as usize,
+            point1.0 as usize..point2.0 as usize
+        ]);
+        Ok(ImageView::new(pixels))
+    }
+
+    /// Checks if a region of interest lies within an image of the given dimension
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn check_roi(dim: (u32, u32), point1: (u32, u32), point2: (u32, u32)) -> VisionXResult<()> {
+        if point2.0 > dim.0 || point2.1 > dim.1 {
+            Err(VisionXError::OutOfBounds {
+                point: point2,
+                size: dim,
+            })
+        } else if point1.0 < point2.0 && point1.1 < point2.1 {
+            Ok(())
         } else {
-            let err = format!("cropping as given size fails condition for values: Point1({}, {}) and Point2({}, {}) for image size ({}, {})", point1.0, point1.1, point2.0, point2.1, dim.0, dim.1);
-            Err(Box::new(VisionXErrorKind::IndexOutofBound(err)))
+            // point1 must lie within the region ending at point2
+            Err(VisionXError::OutOfBounds {
+                point: point1,
+                size: point2,
+            })
         }
     }
 }
@@ -101,7 +216,7 @@ impl<T: Default + Copy, const N: usize> ImageData<T, N> {
 #[cfg(test)]
 mod frame_test {
     use crate::core::{Image, ImageData};
-    use crate::errors::VisionXResult;
+    use crate::errors::{VisionXError, VisionXResult};
     use crate::io;
 
     // Resize rgb8bit image
@@ -148,4 +263,44 @@ mod frame_test {
             }
         };
     }
+
+    // Region of interest shares the pixels with the image
+    // changes through a mutable region are written back
+    #[test]
+    fn roi() {
+        let pixels = ndarray::Array2::from_elem((64, 64), [0u8; 3]);
+        let mut raw_img: ImageData<u8, 3> = ImageData::new(64, 64, pixels);
+
+        assert!(raw_img.roi((10, 10), (65, 20)).is_err());
+        assert!(matches!(
+            raw_img.roi_mut((10, 10), (10, 20)),
+            Err(VisionXError::OutOfBounds {
+                point: (10, 10),
+                size: (10, 20)
+            })
+        ));
+
+        {
+            let mut tile = raw_img.roi_mut((8, 16), (24, 48)).unwrap();
+            assert_eq!((*tile.width(), *tile.height()), (16, 32));
+            assert!(tile.set_pixel_at(16, 0, [1; 3]).is_err());
+            assert!(tile.set_pixel_at(15, 31, [200; 3]).is_ok());
+            tile.map_inplace(|px| px[0] += 1);
+        }
+        assert_eq!(raw_img.get_pixel_at(23, 47), Some(&[201, 200, 200]));
+        assert_eq!(raw_img.get_pixel_at(8, 16), Some(&[1, 0, 0]));
+        assert_eq!(raw_img.get_pixel_at(7, 16), Some(&[0, 0, 0]));
+
+        let tile = raw_img.roi((8, 16), (24, 48)).unwrap();
+        let inner = tile.roi((15, 31), (16, 32)).unwrap();
+        assert_eq!(inner.get_pixel_at(0, 0), Some(&[201, 200, 200]));
+        assert_eq!(
+            inner.pixels().as_ptr(),
+            &raw_img.pixels()[(47, 23)] as *const [u8; 3]
+        );
+
+        let cropped = raw_img.crop((8, 16), (24, 48)).unwrap();
+        assert_eq!(cropped.pixels(), tile.pixels());
+        assert_eq!(tile.resize(8, 16).get_pixel_at(7, 15), Some(&[1, 0, 0]));
+    }
 }
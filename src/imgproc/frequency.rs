@@ -0,0 +1,585 @@
+use super::fft::{fft_2d, Complex};
+use crate::{
+    core::{Grayscale32F, ImageData, ImageView},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+use ndarray::Array2;
+use std::f64::consts::PI;
+
+/// `Spectrum` holds the 2D discrete Fourier transform of a single channel image, returned by `ImageData::fft()`
+///
+/// The spectrum has the size of the image and the zero frequency (DC) component lies at `(0, 0)`. Magnitude and phase spectra are returned centred (DC at `(width / 2, height / 2)`) so they can be displayed as it is
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrum {
+    width: u32,
+    height: u32,
+    data: Vec<Complex>,
+}
+
+/// `FrequencyFilter` defines the transfer function applied by `Spectrum::filter()`
+///
+/// Cutoff frequencies are normalised, i.e. expressed in cycles per pixel within `(0.0, 0.5]` (`0.5` being the Nyquist frequency along an axis), so the same filter behaves the same whatever the size of the image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrequencyFilter {
+    /// Keeps the frequencies up to `cutoff` and removes the others. Sharp, but rings around edges
+    IdealLowPass {
+        /// Cutoff frequency
+        cutoff: f64,
+    },
+    /// Removes the frequencies up to `cutoff` and keeps the others
+    IdealHighPass {
+        /// Cutoff frequency
+        cutoff: f64,
+    },
+    /// Butterworth low-pass filter `1 / (1 + (d / cutoff)^(2 * order))`, smoother than the ideal filter as the order decreases
+    ButterworthLowPass {
+        /// Cutoff frequency, attenuated by half
+        cutoff: f64,
+        /// Order of the filter, at least `1`
+        order: u32,
+    },
+    /// Butterworth high-pass filter, complement of the low-pass filter
+    ButterworthHighPass {
+        /// Cutoff frequency, attenuated by half
+        cutoff: f64,
+        /// Order of the filter, at least `1`
+        order: u32,
+    },
+    /// Gaussian low-pass filter `exp(-d^2 / (2 * cutoff^2))`, which doesn't ring
+    GaussianLowPass {
+        /// Standard deviation of the Gaussian
+        cutoff: f64,
+    },
+    /// Gaussian high-pass filter, complement of the low-pass filter
+    GaussianHighPass {
+        /// Standard deviation of the Gaussian
+        cutoff: f64,
+    },
+}
+
+/// Core implementation of enum `FrequencyFilter`
+impl FrequencyFilter {
+    /// Returns the gain of the filter at the normalised frequency `distance`
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn gain(&self, distance: f64) -> f64 {
+        match *self {
+            FrequencyFilter::IdealLowPass { cutoff } => (distance <= cutoff) as u8 as f64,
+            FrequencyFilter::IdealHighPass { cutoff } => (distance > cutoff) as u8 as f64,
+            FrequencyFilter::ButterworthLowPass { cutoff, order } => {
+                1.0 / (1.0 + (distance / cutoff).powi(2 * order as i32))
+            }
+            FrequencyFilter::ButterworthHighPass { cutoff, order } => {
+                1.0 - 1.0 / (1.0 + (distance / cutoff).powi(2 * order as i32))
+            }
+            FrequencyFilter::GaussianLowPass { cutoff } => {
+                (-distance * distance / (2.0 * cutoff * cutoff)).exp()
+            }
+            FrequencyFilter::GaussianHighPass { cutoff } => {
+                1.0 - (-distance * distance / (2.0 * cutoff * cutoff)).exp()
+            }
+        }
+    }
+
+    /// Returns `Err` if the cutoff frequency isn't positive or the order of a Butterworth filter is `0`
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn validate(&self) -> VisionXResult<()> {
+        let (cutoff, order) = match *self {
+            FrequencyFilter::ButterworthLowPass { cutoff, order }
+            | FrequencyFilter::ButterworthHighPass { cutoff, order } => (cutoff, order),
+            FrequencyFilter::IdealLowPass { cutoff }
+            | FrequencyFilter::IdealHighPass { cutoff }
+            | FrequencyFilter::GaussianLowPass { cutoff }
+            | FrequencyFilter::GaussianHighPass { cutoff } => (cutoff, 1),
+        };
+        if !(cutoff.is_finite() && cutoff > 0.0) || order == 0 {
+            let err = format!("filtering with a cutoff of {cutoff} and an order of {order}");
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        }
+        Ok(())
+    }
+}
+
+/// Core implementation of struct `Spectrum`
+impl Spectrum {
+    /// Width of the spectrum (and of the transformed image)
+    pub fn width(&self) -> &u32 {
+        &self.width
+    }
+
+    /// Height of the spectrum (and of the transformed image)
+    pub fn height(&self) -> &u32 {
+        &self.height
+    }
+
+    /// Returns the complex coefficient `(re, im)` of the frequency `(u, v)`, `None` if it lies outside of the spectrum
+    pub fn get(&self, u: u32, v: u32) -> Option<(f64, f64)> {
+        if u >= self.width || v >= self.height {
+            return None;
+        }
+        let value = self.data[(v * self.width + u) as usize];
+        Some((value.re, value.im))
+    }
+
+    /// Computes the inverse transform and returns its real part, i.e. the image back in the spatial domain
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_shape_fn((12, 20), |(y, x)| [((x * 3 + y * 7) % 11) as f32]);
+    /// let img = ImageData::new(20, 12, pixels);
+    ///
+    /// let restored = img.fft().inverse();
+    /// for (a, b) in img.as_raw_slice().iter().zip(restored.as_raw_slice()) {
+    ///     assert!((a - b).abs() < 1e-4);
+    /// }
+    /// # }
+    /// ```
+    pub fn inverse(&self) -> Grayscale32F {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut data = self.data.clone();
+        fft_2d(&mut data, width, height, true);
+        let pixels =
+            Array2::from_shape_fn((height, width), |(y, x)| [data[y * width + x].re as f32]);
+        ImageData::new(self.width, self.height, pixels)
+    }
+
+    /// Returns the magnitude spectrum `|F(u, v)|`, centred
+    pub fn magnitude(&self) -> Grayscale32F {
+        self.centred(|value| value.re.hypot(value.im))
+    }
+
+    /// Returns the log-magnitude spectrum `ln(1 + |F(u, v)|)`, centred. Compresses the dynamic range for display
+    pub fn log_magnitude(&self) -> Grayscale32F {
+        self.centred(|value| value.re.hypot(value.im).ln_1p())
+    }
+
+    /// Returns the phase spectrum in radians within `[-PI, PI]`, centred
+    pub fn phase(&self) -> Grayscale32F {
+        self.centred(|value| value.im.atan2(value.re))
+    }
+
+    /// Multiplies the spectrum by the transfer function of a filter, which only depends on the distance to the zero frequency
+    ///
+    /// Returns `Err` if the cutoff frequency isn't positive or the order of a Butterworth filter is `0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::FrequencyFilter;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // stripes of period 2 on top of a constant
+    /// let pixels = Array2::from_shape_fn((16, 16), |(_, x)| [5.0 + (x % 2) as f32]);
+    /// let img = ImageData::new(16, 16, pixels);
+    ///
+    /// let low = img.fft().filter(FrequencyFilter::IdealLowPass { cutoff: 0.25 })?.inverse();
+    /// assert!(low.as_raw_slice().iter().all(|value| (value - 5.5).abs() < 1e-4));
+    /// # Ok(()) }
+    /// ```
+    pub fn filter(&self, filter: FrequencyFilter) -> VisionXResult<Spectrum> {
+        filter.validate()?;
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut data = self.data.clone();
+        for (v, row) in data.chunks_mut(width.max(1)).enumerate() {
+            let fv = signed_frequency(v, height);
+            for (u, value) in row.iter_mut().enumerate() {
+                let gain = filter.gain(signed_frequency(u, width).hypot(fv));
+                *value = Complex::new(value.re * gain, value.im * gain);
+            }
+        }
+        Ok(Spectrum {
+            width: self.width,
+            height: self.height,
+            data,
+        })
+    }
+
+    /// Maps every coefficient to a value and swaps the quadrants so that the zero frequency lies at the centre
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn centred(&self, f: impl Fn(Complex) -> f64) -> Grayscale32F {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let pixels = Array2::from_shape_fn((height, width), |(y, x)| {
+            let u = (x + width - width / 2) % width;
+            let v = (y + height - height / 2) % height;
+            [f(self.data[v * width + u]) as f32]
+        });
+        ImageData::new(self.width, self.height, pixels)
+    }
+}
+
+/// Implementation of frequency domain processing of a single channel image
+impl ImageData<f32, 1> {
+    /// Computes the 2D discrete Fourier transform of the image. Any size is supported, sizes which are powers of two being the fastest
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_elem((6, 10), [0.5f32]);
+    /// let img = ImageData::new(10, 6, pixels);
+    ///
+    /// let spectrum = img.fft();
+    /// // only the zero frequency (sum of the pixels) remains for a constant image
+    /// let (re, im) = spectrum.get(0, 0).unwrap();
+    /// assert!((re - 30.0).abs() < 1e-9 && im.abs() < 1e-9);
+    /// assert_eq!(30.0, spectrum.magnitude().get_pixel_at(5, 3).unwrap()[0].round());
+    /// # }
+    /// ```
+    pub fn fft(&self) -> Spectrum {
+        self.view().fft()
+    }
+
+    /// Filters the image in the frequency domain, i.e. transforms it, applies the filter and transforms it back, see `Spectrum::filter()`
+    ///
+    /// Returns `Err` if the cutoff frequency isn't positive or the order of a Butterworth filter is `0`
+    pub fn frequency_filter(&self, filter: FrequencyFilter) -> VisionXResult<Grayscale32F> {
+        Ok(self.fft().filter(filter)?.inverse())
+    }
+
+    /// Estimates the translation `(dx, dy)` from the image to another image of the same size with phase correlation, i.e. `other(x, y) ≈ self(x - dx, y - dy)`. The shift is refined to sub-pixel accuracy and lies within half of the size of the image along each axis
+    ///
+    /// Also returns the response, i.e. the height of the correlation peak within `[0.0, 1.0]`, which drops as the images differ. Setting `window` applies a Hann window to both images beforehand, which removes the artefacts caused by their borders when the content isn't periodic (e.g. consecutive scans)
+    ///
+    /// Returns `Err` if the images have different sizes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let pattern = |x: usize, y: usize| [((x * x * 7 + y * y * 3 + x * y) % 97) as f32];
+    /// let img = ImageData::new(32, 24, Array2::from_shape_fn((24, 32), |(y, x)| pattern(x, y)));
+    /// // circular shift by (5, -3)
+    /// let shifted = ImageData::new(32, 24, Array2::from_shape_fn((24, 32), |(y, x)| pattern((x + 27) % 32, (y + 3) % 24)));
+    ///
+    /// let ((dx, dy), response) = img.phase_correlate(&shifted, false)?;
+    /// assert!((dx - 5.0).abs() < 1e-3 && (dy + 3.0).abs() < 1e-3);
+    /// assert!(response > 0.99);
+    /// # Ok(()) }
+    /// ```
+    pub fn phase_correlate(
+        &self,
+        other: &ImageData<f32, 1>,
+        window: bool,
+    ) -> VisionXResult<((f64, f64), f64)> {
+        self.view().phase_correlate(&other.view(), window)
+    }
+}
+
+/// Implementation of frequency domain processing of a borrowed single channel image
+impl<'a> ImageView<'a, f32, 1> {
+    /// Computes the 2D discrete Fourier transform of the view, see `ImageData::fft()`
+    pub fn fft(&self) -> Spectrum {
+        let data = self
+            .pixels()
+            .iter()
+            .map(|px| Complex::new(px[0] as f64, 0.0))
+            .collect();
+        transform(data, *self.width(), *self.height())
+    }
+
+    /// Estimates the translation from the view to another view with phase correlation, see `ImageData::phase_correlate()`
+    pub fn phase_correlate(
+        &self,
+        other: &ImageView<'_, f32, 1>,
+        window: bool,
+    ) -> VisionXResult<((f64, f64), f64)> {
+        if other.pixels().dim() != self.pixels().dim() {
+            return Err(VisionXError::SizeMismatch {
+                expected: (*self.width(), *self.height()),
+                actual: (*other.width(), *other.height()),
+            });
+        }
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+        if width == 0 || height == 0 {
+            return Ok(((0.0, 0.0), 0.0));
+        }
+
+        let hann = |i: usize, n: usize| {
+            if window && n > 1 {
+                0.5 - 0.5 * (2.0 * PI * i as f64 / (n - 1) as f64).cos()
+            } else {
+                1.0
+            }
+        };
+        let weighted = |view: &ImageView<'_, f32, 1>| {
+            view.pixels()
+                .indexed_iter()
+                .map(|((y, x), px)| {
+                    Complex::new(px[0] as f64 * hann(x, width) * hann(y, height), 0.0)
+                })
+                .collect()
+        };
+        let source = transform(weighted(self), *self.width(), *self.height());
+        let target = transform(weighted(other), *self.width(), *self.height());
+
+        // normalised cross-power spectrum, its inverse peaks at the shift
+        let mut cross: Vec<Complex> = target
+            .data
+            .iter()
+            .zip(&source.data)
+            .map(|(t, s)| {
+                let product = *t * s.conj();
+                let norm = product.re.hypot(product.im);
+                if norm > 1e-12 {
+                    Complex::new(product.re / norm, product.im / norm)
+                } else {
+                    Complex::default()
+                }
+            })
+            .collect();
+        fft_2d(&mut cross, width, height, true);
+
+        let (peak, _) = cross
+            .iter()
+            .enumerate()
+            .fold((0, f64::MIN), |(best, max), (i, value)| {
+                if value.re > max {
+                    (i, value.re)
+                } else {
+                    (best, max)
+                }
+            });
+        let (px, py) = (peak % width, peak / width);
+
+        // sub-pixel refinement with the centroid of the 3x3 neighbourhood (wrapping around)
+        let (mut sum, mut sum_x, mut sum_y) = (0.0, 0.0, 0.0);
+        for dy in -1i64..=1 {
+            for dx in -1i64..=1 {
+                let x = (px as i64 + dx).rem_euclid(width as i64) as usize;
+                let y = (py as i64 + dy).rem_euclid(height as i64) as usize;
+                let value = cross[y * width + x].re.max(0.0);
+                sum += value;
+                sum_x += value * dx as f64;
+                sum_y += value * dy as f64;
+            }
+        }
+        let (mut dx, mut dy) = (px as f64, py as f64);
+        if sum > 0.0 {
+            dx += sum_x / sum;
+            dy += sum_y / sum;
+        }
+        // shifts beyond half of the size wrap around to negative shifts
+        if dx > width as f64 / 2.0 {
+            dx -= width as f64;
+        }
+        if dy > height as f64 / 2.0 {
+            dy -= height as f64;
+        }
+        Ok(((dx, dy), cross[peak].re.clamp(0.0, 1.0)))
+    }
+}
+
+/// Transforms a row-major buffer into its spectrum
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn transform(mut data: Vec<Complex>, width: u32, height: u32) -> Spectrum {
+    fft_2d(&mut data, width as usize, height as usize, false);
+    Spectrum {
+        width,
+        height,
+        data,
+    }
+}
+
+/// Returns the normalised frequency (cycles per pixel) of the index `i` of a transform of length `n`, indices past `n / 2` being negative frequencies
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn signed_frequency(i: usize, n: usize) -> f64 {
+    let i = if i > n / 2 {
+        i as f64 - n as f64
+    } else {
+        i as f64
+    };
+    i / n as f64
+}
+
+#[cfg(test)]
+mod frequency_test {
+    use super::FrequencyFilter;
+    use crate::{
+        core::ImageData,
+        errors::{VisionXError, VisionXErrorKind},
+    };
+    use ndarray::Array2;
+
+    fn pattern(width: usize, height: usize, dx: usize, dy: usize) -> ImageData<f32, 1> {
+        let pixels = Array2::from_shape_fn((height, width), |(y, x)| {
+            let (x, y) = ((x + width - dx) % width, (y + height - dy) % height);
+            [((x * x * 7 + y * y * 3 + x * y) % 97) as f32 / 97.0]
+        });
+        ImageData::new(width as u32, height as u32, pixels)
+    }
+
+    // Spectrum of a sinusoid, inverse transform and centred spectra
+    #[test]
+    fn spectrum() {
+        let (width, height) = (24, 10);
+        let pixels = Array2::from_shape_fn((height, width), |(y, x)| {
+            [(2.0
+                * std::f32::consts::PI
+                * (3.0 * x as f32 / width as f32 + y as f32 / height as f32))
+                .cos()]
+        });
+        let img = ImageData::new(width as u32, height as u32, pixels);
+
+        let spectrum = img.fft();
+        assert_eq!((24, 10), (*spectrum.width(), *spectrum.height()));
+        assert_eq!(None, spectrum.get(24, 0));
+        // cos splits into the frequencies (3, 1) and (-3, -1)
+        let half = (width * height) as f64 / 2.0;
+        for (u, v) in [(3, 1), (21, 9)] {
+            let (re, im) = spectrum.get(u, v).unwrap();
+            assert!((re - half).abs() < 1e-3 && im.abs() < 1e-3);
+        }
+        let magnitude = spectrum.magnitude();
+        for ((y, x), px) in magnitude.pixels().indexed_iter() {
+            let expected = if (x, y) == (15, 6) || (x, y) == (9, 4) {
+                half
+            } else {
+                0.0
+            };
+            assert!((px[0] as f64 - expected).abs() < 1e-3);
+        }
+        let log_magnitude = spectrum.log_magnitude();
+        assert!((log_magnitude.get_pixel_at(15, 6).unwrap()[0] as f64 - half.ln_1p()).abs() < 1e-4);
+        assert!(spectrum.phase().get_pixel_at(15, 6).unwrap()[0].abs() < 1e-4);
+
+        let img = pattern(13, 7, 0, 0);
+        let restored = img.fft().inverse();
+        for (a, b) in img.as_raw_slice().iter().zip(restored.as_raw_slice()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    // Low and high pass filters are complementary and parameters are validated
+    #[test]
+    fn filters() {
+        let img = pattern(32, 20, 0, 0);
+        let mean = img.as_raw_slice().iter().sum::<f32>() / img.as_raw_slice().len() as f32;
+        let variance = |img: &ImageData<f32, 1>| {
+            let m = img.as_raw_slice().iter().sum::<f32>() / img.as_raw_slice().len() as f32;
+            img.as_raw_slice()
+                .iter()
+                .map(|v| (v - m) * (v - m))
+                .sum::<f32>()
+        };
+
+        for (low, high) in [
+            (
+                FrequencyFilter::IdealLowPass { cutoff: 0.1 },
+                FrequencyFilter::IdealHighPass { cutoff: 0.1 },
+            ),
+            (
+                FrequencyFilter::ButterworthLowPass {
+                    cutoff: 0.1,
+                    order: 2,
+                },
+                FrequencyFilter::ButterworthHighPass {
+                    cutoff: 0.1,
+                    order: 2,
+                },
+            ),
+            (
+                FrequencyFilter::GaussianLowPass { cutoff: 0.1 },
+                FrequencyFilter::GaussianHighPass { cutoff: 0.1 },
+            ),
+        ] {
+            let smooth = img.frequency_filter(low).unwrap();
+            let detail = img.frequency_filter(high).unwrap();
+            // the mean is kept by low pass filters and removed by high pass filters
+            let smooth_mean = smooth.as_raw_slice().iter().sum::<f32>() / 640.0;
+            let detail_mean = detail.as_raw_slice().iter().sum::<f32>() / 640.0;
+            assert!((smooth_mean - mean).abs() < 1e-4 && detail_mean.abs() < 1e-4);
+            assert!(variance(&smooth) < variance(&img) / 2.0);
+            for ((a, b), c) in smooth
+                .as_raw_slice()
+                .iter()
+                .zip(detail.as_raw_slice())
+                .zip(img.as_raw_slice())
+            {
+                assert!((a + b - c).abs() < 1e-4);
+            }
+        }
+
+        for filter in [
+            FrequencyFilter::IdealLowPass { cutoff: 0.0 },
+            FrequencyFilter::GaussianHighPass { cutoff: f64::NAN },
+            FrequencyFilter::ButterworthLowPass {
+                cutoff: 0.2,
+                order: 0,
+            },
+        ] {
+            assert!(matches!(
+                img.frequency_filter(filter),
+                Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+            ));
+        }
+    }
+
+    // Empty images are transformed and filtered into empty images
+    #[test]
+    fn empty() {
+        for (width, height) in [(0, 5), (5, 0)] {
+            let img = ImageData::new(
+                width,
+                height,
+                Array2::from_elem((height as usize, width as usize), [0f32]),
+            );
+            let spectrum = img.fft();
+            assert_eq!((width, height), (*spectrum.width(), *spectrum.height()));
+            assert!(spectrum.magnitude().as_raw_slice().is_empty());
+            let restored = spectrum.inverse();
+            assert_eq!((width, height), (*restored.width(), *restored.height()));
+            let filtered = img
+                .frequency_filter(FrequencyFilter::GaussianLowPass { cutoff: 0.1 })
+                .unwrap();
+            assert!(filtered.as_raw_slice().is_empty());
+        }
+    }
+
+    // Translations are recovered, with and without a window
+    #[test]
+    fn phase_correlation() {
+        let img = pattern(40, 30, 0, 0);
+        let shifted = pattern(40, 30, 7, 26);
+        let ((dx, dy), response) = img.phase_correlate(&shifted, false).unwrap();
+        assert!((dx - 7.0).abs() < 1e-3 && (dy + 4.0).abs() < 1e-3);
+        assert!(response > 0.99);
+
+        // translated crops of a larger image aren't periodic
+        let scene = pattern(96, 96, 0, 0);
+        let a = scene.crop((10, 20), (74, 84)).unwrap();
+        let b = scene.crop((14, 17), (78, 81)).unwrap();
+        let ((dx, dy), response) = a.phase_correlate(&b, true).unwrap();
+        assert!((dx + 4.0).abs() < 0.25 && (dy - 3.0).abs() < 0.25);
+        assert!(response > 0.1);
+
+        let other = pattern(40, 31, 0, 0);
+        assert!(matches!(
+            img.phase_correlate(&other, false),
+            Err(VisionXError::SizeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,678 @@
+use super::kernel::XorShift;
+use crate::{
+    core::{ImageData, ImageView, PixelDepth},
+    errors::{VisionXErrorKind, VisionXResult},
+};
+use ndarray::Array2;
+
+/// `Transform` holds a planar transform as a 3x3 matrix acting on homogeneous coordinates `(x, y, 1)`, returned by `estimate_transform()` and applied to images by `ImageData::warp()`
+///
+/// Similarity and affine transforms have `[0, 0, 1]` as their last row, homographies are normalised so that their bottom-right coefficient is `1` (when it isn't `0`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    matrix: [[f64; 3]; 3],
+}
+
+/// `TransformModel` defines the family of transforms fitted by `estimate_transform()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformModel {
+    /// Rotation, uniform scaling and translation (4 degrees of freedom, at least 2 correspondences)
+    Similarity,
+    /// Linear map and translation, which keeps parallel lines parallel (6 degrees of freedom, at least 3 correspondences)
+    Affine,
+    /// Projective transform between two views of a plane (8 degrees of freedom, at least 4 correspondences)
+    Homography,
+}
+
+/// `RobustMethod` defines how `estimate_transform()` separates inliers from outliers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RobustMethod {
+    /// Random sample consensus, keeps the model with the most correspondences whose reprojection error is below `threshold` (in pixels)
+    Ransac {
+        /// Largest reprojection error of an inlier
+        threshold: f64,
+    },
+    /// Least median of squares, keeps the model with the lowest median of the squared reprojection errors. Doesn't need a threshold, but requires at least half of the correspondences to be inliers
+    LeastMedianOfSquares,
+}
+
+/// Core implementation of struct `Transform`
+impl Transform {
+    /// Creates a transform from its 3x3 matrix (row-major)
+    pub fn new(matrix: [[f64; 3]; 3]) -> Self {
+        Transform { matrix }
+    }
+
+    /// Creates the identity transform
+    pub fn identity() -> Self {
+        Transform::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// Creates a similarity transform, which scales by `scale` and rotates by `angle` (radians, clockwise as the y-axis points down) around the origin, then translates by `translation`
+    pub fn similarity(scale: f64, angle: f64, translation: (f64, f64)) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Transform::new([
+            [scale * cos, -scale * sin, translation.0],
+            [scale * sin, scale * cos, translation.1],
+            [0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Matrix of the transform (row-major)
+    pub fn matrix(&self) -> &[[f64; 3]; 3] {
+        &self.matrix
+    }
+
+    /// Maps a point `(x, y)` through the transform, `None` if it's mapped to infinity (homographies only)
+    pub fn apply(&self, point: (f64, f64)) -> Option<(f64, f64)> {
+        let m = &self.matrix;
+        let (x, y) = point;
+        let w = m[2][0] * x + m[2][1] * y + m[2][2];
+        if w.abs() < f64::EPSILON {
+            return None;
+        }
+        Some((
+            (m[0][0] * x + m[0][1] * y + m[0][2]) / w,
+            (m[1][0] * x + m[1][1] * y + m[1][2]) / w,
+        ))
+    }
+
+    /// Returns the inverse transform, `None` if the transform isn't invertible
+    pub fn inverse(&self) -> Option<Transform> {
+        let m = &self.matrix;
+        let cofactor = |r1: usize, r2: usize, c1: usize, c2: usize| {
+            m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+        };
+        let adjugate = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let determinant = (0..3).map(|i| m[0][i] * adjugate[i][0]).sum::<f64>();
+        let scale = m.iter().flatten().fold(0.0, |max: f64, v| max.max(v.abs()));
+        if determinant.abs() <= 1e-12 * scale.powi(3) {
+            return None;
+        }
+        Some(Transform::new(adjugate.map(|row| row.map(|v| v / determinant))).normalized())
+    }
+
+    /// Returns the transform `self` applied after `other`
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn then(&self, other: &Transform) -> Transform {
+        let (a, b) = (&other.matrix, &self.matrix);
+        let matrix = std::array::from_fn(|r| {
+            std::array::from_fn(|c| (0..3).map(|k| b[r][k] * a[k][c]).sum())
+        });
+        Transform::new(matrix).normalized()
+    }
+
+    /// Scales the matrix so that its bottom-right coefficient is `1`, unless it's `0`
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn normalized(self) -> Transform {
+        let w = self.matrix[2][2];
+        if w.abs() < f64::EPSILON || w == 1.0 {
+            return self;
+        }
+        Transform::new(self.matrix.map(|row| row.map(|v| v / w)))
+    }
+}
+
+/// Implementation of geometric warping of an image
+impl<T: PixelDepth, const N: usize> ImageData<T, N> {
+    /// Warps the image through a transform into an image of size `(width, height)`, i.e. each pixel `(x, y)` of the result is sampled at the position of the image mapped to `(x, y)` by the transform. Samples are interpolated bilinearly and pixels mapped from outside of the image are set to the default value (black)
+    ///
+    /// Works with every model returned by `estimate_transform()`, so aligning an image onto another is: match features, estimate the transform, then warp
+    ///
+    /// Returns `Err` if the transform isn't invertible
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::Transform;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let pixels = Array2::from_shape_fn((20, 30), |(y, x)| [(x * 8 + y) as u8]);
+    /// let img = ImageData::new(30, 20, pixels);
+    ///
+    /// let shifted = img.warp(&Transform::similarity(1.0, 0.0, (4.0, 2.0)), 30, 20)?;
+    /// assert_eq!(img.get_pixel_at(10, 5), shifted.get_pixel_at(14, 7));
+    /// assert_eq!(Some(&[0]), shifted.get_pixel_at(1, 1));
+    /// # Ok(()) }
+    /// ```
+    pub fn warp(&self, transform: &Transform, width: u32, height: u32) -> VisionXResult<Self> {
+        self.view().warp(transform, width, height)
+    }
+}
+
+/// Implementation of geometric warping of a borrowed image
+impl<'a, T: PixelDepth, const N: usize> ImageView<'a, T, N> {
+    /// Warps the view through a transform, positions being relative to the view, see `ImageData::warp()`
+    pub fn warp(
+        &self,
+        transform: &Transform,
+        width: u32,
+        height: u32,
+    ) -> VisionXResult<ImageData<T, N>> {
+        let Some(inverse) = transform.inverse() else {
+            let err = format!(
+                "warping with the singular transform {:?}",
+                transform.matrix()
+            );
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        };
+
+        let source = self.pixels();
+        let (src_height, src_width) = source.dim();
+        let pixels = Array2::from_shape_fn((height as usize, width as usize), |(y, x)| {
+            let Some((sx, sy)) = inverse.apply((x as f64, y as f64)) else {
+                return [T::default(); N];
+            };
+            // samples within half a pixel of the border replicate it
+            if !(sx >= -0.5
+                && sx < src_width as f64 - 0.5
+                && sy >= -0.5
+                && sy < src_height as f64 - 0.5)
+            {
+                return [T::default(); N];
+            }
+            let (sx, sy) = (
+                sx.clamp(0.0, (src_width - 1) as f64),
+                sy.clamp(0.0, (src_height - 1) as f64),
+            );
+            let (x0, y0) = (sx.floor() as usize, sy.floor() as usize);
+            let (x1, y1) = ((x0 + 1).min(src_width - 1), (y0 + 1).min(src_height - 1));
+            let (fx, fy) = ((sx - x0 as f64) as f32, (sy - y0 as f64) as f32);
+            std::array::from_fn(|c| {
+                let at = |x: usize, y: usize| source[(y, x)][c].to_normalized();
+                let top = at(x0, y0) * (1.0 - fx) + at(x1, y0) * fx;
+                let bottom = at(x0, y1) * (1.0 - fx) + at(x1, y1) * fx;
+                T::from_normalized(top * (1.0 - fy) + bottom * fy)
+            })
+        });
+        Ok(ImageData::new(width, height, pixels))
+    }
+}
+
+/// Estimates the transform mapping the `source` points onto the `destination` points (e.g. the positions of matched keypoints), robustly to wrong correspondences. Returns the model along with the inlier mask of the correspondences
+///
+/// Minimal samples of correspondences are drawn (deterministically) and scored by `method`, at most `max_iterations` times. RANSAC stops early once the best model has been found with the probability `confidence` (which LMedS ignores). The model is then refined by least squares on its inliers. Points are normalised beforehand, so pixel coordinates can be used as it is
+///
+/// Returns `Err` if `source` and `destination` have different lengths or fewer correspondences than the model requires, if a parameter is outside of its valid range or if no model can be fitted (e.g. collinear points)
+///
+/// # Example
+///
+/// ```
+/// use vision_x::imgproc::{estimate_transform, RobustMethod, Transform, TransformModel};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let expected = Transform::similarity(1.5, 0.3, (12.0, -4.0));
+/// let source: Vec<(f64, f64)> = (0..20).map(|i| ((i * 7 % 40) as f64, (i * 13 % 30) as f64)).collect();
+/// let mut destination: Vec<(f64, f64)> = source.iter().map(|&p| expected.apply(p).unwrap()).collect();
+/// // wrong correspondence
+/// destination[3] = (100.0, 100.0);
+///
+/// let method = RobustMethod::Ransac { threshold: 1.0 };
+/// let (transform, inliers) = estimate_transform(&source, &destination, TransformModel::Similarity, method, 500, 0.99)?;
+/// assert!(!inliers[3] && inliers.iter().filter(|&&inlier| inlier).count() == 19);
+/// let (x, y) = transform.apply((10.0, 10.0)).unwrap();
+/// let (ex, ey) = expected.apply((10.0, 10.0)).unwrap();
+/// assert!((x - ex).abs() < 1e-6 && (y - ey).abs() < 1e-6);
+/// # Ok(()) }
+/// ```
+pub fn estimate_transform(
+    source: &[(f64, f64)],
+    destination: &[(f64, f64)],
+    model: TransformModel,
+    method: RobustMethod,
+    max_iterations: usize,
+    confidence: f64,
+) -> VisionXResult<(Transform, Vec<bool>)> {
+    let sample_size = match model {
+        TransformModel::Similarity => 2,
+        TransformModel::Affine => 3,
+        TransformModel::Homography => 4,
+    };
+    let count = source.len();
+    if destination.len() != count || count < sample_size {
+        let err = format!(
+            "estimating a {model:?} transform from {count} source points and {} destination points",
+            destination.len()
+        );
+        return Err(VisionXErrorKind::InvalidSize(err).into());
+    }
+    let threshold = match method {
+        RobustMethod::Ransac { threshold } => Some(threshold),
+        RobustMethod::LeastMedianOfSquares => None,
+    };
+    // the confidence only stops RANSAC early
+    if threshold.is_some_and(|threshold| {
+        threshold.is_nan() || threshold <= 0.0 || !(confidence > 0.0 && confidence < 1.0)
+    }) || max_iterations == 0
+    {
+        let err = format!(
+            "estimating a transform with {method:?}, a confidence of {confidence} and {max_iterations} iterations"
+        );
+        return Err(VisionXErrorKind::InvalidParameter(err).into());
+    }
+
+    // fixed seed, so that the estimation doesn't change between runs
+    let mut random = XorShift::new(0x2545_F491_4F6C_DD1D);
+
+    // best model so far: (transform, score), the score being the number of outliers for RANSAC and the median of the squared errors for LMedS
+    let mut best: Option<(Transform, f64)> = None;
+    let mut iterations = max_iterations;
+    let mut iteration = 0;
+    let mut errors = vec![0.0; count];
+    while iteration < iterations {
+        iteration += 1;
+        let mut sample: Vec<usize> = Vec::with_capacity(sample_size);
+        while sample.len() < sample_size {
+            let index = random.below(count);
+            if !sample.contains(&index) {
+                sample.push(index);
+            }
+        }
+        let src: Vec<(f64, f64)> = sample.iter().map(|&i| source[i]).collect();
+        let dst: Vec<(f64, f64)> = sample.iter().map(|&i| destination[i]).collect();
+        if model == TransformModel::Homography && (has_collinear(&src) || has_collinear(&dst)) {
+            continue;
+        }
+        let Some(transform) = fit(&src, &dst, model) else {
+            continue;
+        };
+
+        squared_errors(&transform, source, destination, &mut errors);
+        let score = match threshold {
+            Some(threshold) => errors
+                .iter()
+                .filter(|&&e| e > threshold * threshold)
+                .count() as f64,
+            None => median(&errors),
+        };
+        if best
+            .as_ref()
+            .is_some_and(|(_, best_score)| score >= *best_score)
+        {
+            continue;
+        }
+        best = Some((transform, score));
+
+        // number of iterations needed to draw an outlier free sample with the given confidence
+        if threshold.is_some() {
+            let inlier_ratio = 1.0 - score / count as f64;
+            let outlier_free = inlier_ratio.powi(sample_size as i32);
+            iterations = if outlier_free >= 1.0 {
+                iteration
+            } else if outlier_free > 0.0 {
+                let needed = ((1.0 - confidence).ln() / (1.0 - outlier_free).ln()).ceil();
+                iterations.min(needed.max(iteration as f64) as usize)
+            } else {
+                iterations
+            };
+        }
+    }
+
+    let Some((transform, score)) = best else {
+        let err =
+            format!("estimating a {model:?} transform from degenerate (e.g. collinear) points");
+        return Err(VisionXErrorKind::InvalidData(err).into());
+    };
+    let threshold = match threshold {
+        Some(threshold) => threshold,
+        None => {
+            // robust standard deviation of the errors (Rousseeuw)
+            let correction = 1.0 + 5.0 / (count - sample_size).max(1) as f64;
+            (2.5 * 1.4826 * correction * score.sqrt()).max(1e-6)
+        }
+    };
+
+    // refine the model with all of its inliers
+    squared_errors(&transform, source, destination, &mut errors);
+    let mut inliers: Vec<bool> = errors.iter().map(|&e| e <= threshold * threshold).collect();
+    let src: Vec<(f64, f64)> = (0..count)
+        .filter(|&i| inliers[i])
+        .map(|i| source[i])
+        .collect();
+    let dst: Vec<(f64, f64)> = (0..count)
+        .filter(|&i| inliers[i])
+        .map(|i| destination[i])
+        .collect();
+    let transform = match fit(&src, &dst, model) {
+        Some(refined) if src.len() > sample_size => {
+            squared_errors(&refined, source, destination, &mut errors);
+            inliers = errors.iter().map(|&e| e <= threshold * threshold).collect();
+            refined
+        }
+        _ => transform,
+    };
+    Ok((transform, inliers))
+}
+
+/// Fits a transform to correspondences by least squares, on normalised points. Returns `None` if the system is singular
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn fit(
+    source: &[(f64, f64)],
+    destination: &[(f64, f64)],
+    model: TransformModel,
+) -> Option<Transform> {
+    let src_normalization = normalization(source)?;
+    let dst_normalization = normalization(destination)?;
+    let unknowns = match model {
+        TransformModel::Similarity => 4,
+        TransformModel::Affine => 6,
+        TransformModel::Homography => 8,
+    };
+
+    // normal equations of the linear system, two rows per correspondence
+    let mut normal = vec![vec![0.0; unknowns]; unknowns];
+    let mut rhs = vec![0.0; unknowns];
+    for (&s, &d) in source.iter().zip(destination) {
+        let (x, y) = src_normalization.apply(s)?;
+        let (u, v) = dst_normalization.apply(d)?;
+        let rows: [(Vec<f64>, f64); 2] = match model {
+            TransformModel::Similarity => [(vec![x, -y, 1.0, 0.0], u), (vec![y, x, 0.0, 1.0], v)],
+            TransformModel::Affine => [
+                (vec![x, y, 1.0, 0.0, 0.0, 0.0], u),
+                (vec![0.0, 0.0, 0.0, x, y, 1.0], v),
+            ],
+            TransformModel::Homography => [
+                (vec![x, y, 1.0, 0.0, 0.0, 0.0, -x * u, -y * u], u),
+                (vec![0.0, 0.0, 0.0, x, y, 1.0, -x * v, -y * v], v),
+            ],
+        };
+        for (row, value) in rows {
+            for i in 0..unknowns {
+                rhs[i] += row[i] * value;
+                for j in 0..unknowns {
+                    normal[i][j] += row[i] * row[j];
+                }
+            }
+        }
+    }
+
+    let p = solve(normal, rhs)?;
+    let normalized = Transform::new(match model {
+        TransformModel::Similarity => [[p[0], -p[1], p[2]], [p[1], p[0], p[3]], [0.0, 0.0, 1.0]],
+        TransformModel::Affine => [[p[0], p[1], p[2]], [p[3], p[4], p[5]], [0.0, 0.0, 1.0]],
+        TransformModel::Homography => [[p[0], p[1], p[2]], [p[3], p[4], p[5]], [p[6], p[7], 1.0]],
+    });
+    Some(
+        dst_normalization
+            .inverse()?
+            .then(&normalized.then(&src_normalization)),
+    )
+}
+
+/// Returns the similarity moving the centroid of the points to the origin and scaling their mean distance to it to `sqrt(2)`, `None` if all points are identical
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn normalization(points: &[(f64, f64)]) -> Option<Transform> {
+    let count = points.len() as f64;
+    let (sum_x, sum_y) = points
+        .iter()
+        .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+    let (cx, cy) = (sum_x / count, sum_y / count);
+    let mean_distance = points
+        .iter()
+        .map(|&(x, y)| (x - cx).hypot(y - cy))
+        .sum::<f64>()
+        / count;
+    if mean_distance.is_nan() || mean_distance <= 1e-12 {
+        return None;
+    }
+    let scale = std::f64::consts::SQRT_2 / mean_distance;
+    Some(Transform::similarity(
+        scale,
+        0.0,
+        (-scale * cx, -scale * cy),
+    ))
+}
+
+/// Solves a linear system with Gaussian elimination and partial pivoting, `None` if it's singular
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    let scale = a.iter().flatten().fold(0.0, |max: f64, v| max.max(v.abs()));
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() <= 1e-10 * scale {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (value, pivot) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Returns `true` if three of the points are (nearly) collinear, which makes a homography degenerate
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn has_collinear(points: &[(f64, f64)]) -> bool {
+    let scale = points
+        .iter()
+        .flat_map(|a| points.iter().map(move |b| (a.0 - b.0).hypot(a.1 - b.1)))
+        .fold(0.0, f64::max);
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            for k in j + 1..points.len() {
+                let (a, b, c) = (points[i], points[j], points[k]);
+                let cross = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+                if cross.abs() <= 1e-6 * scale * scale {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Computes the squared reprojection error of every correspondence, infinite for points mapped to infinity
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn squared_errors(
+    transform: &Transform,
+    source: &[(f64, f64)],
+    destination: &[(f64, f64)],
+    errors: &mut [f64],
+) {
+    for ((error, &s), &(u, v)) in errors.iter_mut().zip(source).zip(destination) {
+        *error = match transform.apply(s) {
+            Some((x, y)) => (x - u) * (x - u) + (y - v) * (y - v),
+            None => f64::INFINITY,
+        };
+    }
+}
+
+/// Returns the median of the values (the upper one for an even count)
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    let middle = sorted.len() / 2;
+    let (_, median, _) = sorted.select_nth_unstable_by(middle, f64::total_cmp);
+    *median
+}
+
+#[cfg(test)]
+mod geometry_test {
+    use super::{estimate_transform, RobustMethod, Transform, TransformModel};
+    use crate::{
+        core::ImageData,
+        errors::{VisionXError, VisionXErrorKind},
+    };
+    use ndarray::Array2;
+
+    fn assert_close(a: &Transform, b: &Transform) {
+        for (x, y) in [(0.0, 0.0), (50.0, 10.0), (20.0, 70.0), (80.0, 80.0)] {
+            let (ax, ay) = a.apply((x, y)).unwrap();
+            let (bx, by) = b.apply((x, y)).unwrap();
+            assert!((ax - bx).abs() < 1e-2 && (ay - by).abs() < 1e-2);
+        }
+    }
+
+    // Every model is recovered by both methods and outliers are masked
+    #[test]
+    fn estimation() {
+        let source: Vec<(f64, f64)> = (0..60)
+            .map(|i| ((i * 37 % 97) as f64, (i * 61 % 89) as f64))
+            .collect();
+        let outliers = |i: usize| i % 4 == 1;
+        let models = [
+            (
+                TransformModel::Similarity,
+                Transform::similarity(0.8, -0.6, (30.0, 5.0)),
+            ),
+            (
+                TransformModel::Affine,
+                Transform::new([[1.1, 0.2, -7.0], [-0.3, 0.9, 12.0], [0.0, 0.0, 1.0]]),
+            ),
+            (
+                TransformModel::Homography,
+                Transform::new([[0.9, 0.1, 15.0], [-0.05, 1.2, -3.0], [0.001, -0.002, 1.0]]),
+            ),
+        ];
+        for (model, expected) in models {
+            let destination: Vec<(f64, f64)> = source
+                .iter()
+                .enumerate()
+                .map(|(i, &p)| {
+                    let (x, y) = expected.apply(p).unwrap();
+                    if outliers(i) {
+                        (x + 20.0 + i as f64, y - 30.0)
+                    } else {
+                        // small deterministic noise
+                        (
+                            x + ((i % 3) as f64 - 1.0) * 0.01,
+                            y + ((i % 5) as f64 - 2.0) * 0.01,
+                        )
+                    }
+                })
+                .collect();
+
+            for method in [
+                RobustMethod::Ransac { threshold: 1.0 },
+                RobustMethod::LeastMedianOfSquares,
+            ] {
+                let (transform, inliers) =
+                    estimate_transform(&source, &destination, model, method, 2000, 0.999).unwrap();
+                assert_close(&expected, &transform);
+                for (i, inlier) in inliers.iter().enumerate() {
+                    assert_eq!(!outliers(i), *inlier);
+                }
+            }
+        }
+    }
+
+    // Sizes, parameters and degenerate points are rejected
+    #[test]
+    fn invalid_inputs() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)];
+        let ransac = RobustMethod::Ransac { threshold: 1.0 };
+        let homography = TransformModel::Homography;
+        assert!(matches!(
+            estimate_transform(&points, &points[..3], homography, ransac, 100, 0.99),
+            Err(VisionXError::Kind(VisionXErrorKind::InvalidSize(_)))
+        ));
+        assert!(matches!(
+            estimate_transform(&points[..3], &points[..3], homography, ransac, 100, 0.99),
+            Err(VisionXError::Kind(VisionXErrorKind::InvalidSize(_)))
+        ));
+        for (method, iterations, confidence) in [
+            (RobustMethod::Ransac { threshold: 0.0 }, 100, 0.99),
+            (ransac, 0, 0.99),
+            (ransac, 100, 1.0),
+        ] {
+            assert!(matches!(
+                estimate_transform(&points, &points, homography, method, iterations, confidence),
+                Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+            ));
+        }
+        let collinear = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)];
+        assert!(matches!(
+            estimate_transform(&collinear, &collinear, homography, ransac, 100, 0.99),
+            Err(VisionXError::Kind(VisionXErrorKind::InvalidData(_)))
+        ));
+
+        // LMedS doesn't use the confidence
+        let lmeds = RobustMethod::LeastMedianOfSquares;
+        assert!(estimate_transform(&points, &points, homography, lmeds, 100, 1.0).is_ok());
+
+        let identity = estimate_transform(&points, &points, homography, ransac, 100, 0.99).unwrap();
+        assert_close(&Transform::identity(), &identity.0);
+        assert!(identity.1.iter().all(|&inlier| inlier));
+    }
+
+    // Warping follows the transform and its inverse undoes it
+    #[test]
+    fn warp() {
+        let pixels = Array2::from_shape_fn((24, 32), |(y, x)| [(x * 7) as u8, (y * 9) as u8, 100]);
+        let img = ImageData::new(32, 24, pixels);
+
+        // rotation by 90 degrees, translated back into a 24x32 image: (x, y) -> (23 - y, x)
+        let rotation = Transform::similarity(1.0, std::f64::consts::FRAC_PI_2, (23.0, 0.0));
+        let expected = Transform::new([[0.0, -1.0, 23.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_close(&expected, &rotation);
+        let rotated = img.warp(&rotation, 24, 32).unwrap();
+        for ((y, x), px) in img.pixels().indexed_iter() {
+            assert_eq!(px, rotated.get_pixel_at(23 - y, x).unwrap());
+        }
+
+        // sub-pixel translation is interpolated
+        let shifted = img
+            .warp(&Transform::similarity(1.0, 0.0, (0.75, 0.0)), 32, 24)
+            .unwrap();
+        assert_eq!(&[23, 45, 100], shifted.get_pixel_at(4, 5).unwrap());
+        assert_eq!(&[0, 0, 0], shifted.get_pixel_at(0, 5).unwrap());
+
+        let back = rotated.warp(&rotation.inverse().unwrap(), 32, 24).unwrap();
+        assert_eq!(img.as_raw_slice(), back.as_raw_slice());
+
+        let singular = Transform::new([[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert!(singular.inverse().is_none());
+        assert!(matches!(
+            img.warp(&singular, 32, 24),
+            Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+        ));
+    }
+}
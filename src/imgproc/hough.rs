@@ -0,0 +1,729 @@
+use super::kernel::{local_maxima, neighbours, smooth, sobel, XorShift};
+use crate::{
+    core::{ImageData, ImageView},
+    errors::{VisionXErrorKind, VisionXResult},
+};
+use std::f64::consts::PI;
+
+/// Maximum number of cells `(theta, rho)` of the accumulator of the line transforms (256 MiB of votes)
+///
+/// ## Note
+/// Constant is used internally (private scope)
+const MAX_LINE_CELLS: f64 = (1u64 << 26) as f64;
+
+/// `Line` holds a line found by `ImageData::hough_lines()` in its normal form `x * cos(theta) + y * sin(theta) = rho`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    rho: f64,
+    theta: f64,
+    votes: u32,
+}
+
+/// `LineSegment` holds a line segment found by `ImageData::hough_lines_p()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineSegment {
+    start: (u32, u32),
+    end: (u32, u32),
+    votes: u32,
+}
+
+/// `Circle` holds a circle found by `ImageData::hough_circles()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    center: (f64, f64),
+    radius: f64,
+    votes: u32,
+}
+
+/// Core implementation of struct `Line`
+impl Line {
+    /// Signed distance between the origin (top-left corner) and the line
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+
+    /// Angle in radians within `[0, PI)` between the x-axis and the normal of the line (`0` for vertical lines, `PI / 2` for horizontal lines)
+    pub fn theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Number of edge pixels lying on the line
+    pub fn votes(&self) -> u32 {
+        self.votes
+    }
+}
+
+/// Core implementation of struct `LineSegment`
+impl LineSegment {
+    /// First end `(x, y)` of the segment
+    pub fn start(&self) -> (u32, u32) {
+        self.start
+    }
+
+    /// Last end `(x, y)` of the segment
+    pub fn end(&self) -> (u32, u32) {
+        self.end
+    }
+
+    /// Length of the segment
+    pub fn length(&self) -> f64 {
+        let (dx, dy) = (
+            self.end.0 as f64 - self.start.0 as f64,
+            self.end.1 as f64 - self.start.1 as f64,
+        );
+        dx.hypot(dy)
+    }
+
+    /// Number of votes of the line holding the segment when it was found
+    pub fn votes(&self) -> u32 {
+        self.votes
+    }
+}
+
+/// Core implementation of struct `Circle`
+impl Circle {
+    /// Center `(x, y)` of the circle
+    pub fn center(&self) -> (f64, f64) {
+        self.center
+    }
+
+    /// Radius of the circle
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Number of edge pixels whose gradient points at the center of the circle (within a pixel)
+    pub fn votes(&self) -> u32 {
+        self.votes
+    }
+}
+
+/// Implementation of Hough transforms of a grayscale image
+impl ImageData<u8, 1> {
+    /// Finds the lines of an edge image (e.g. a thresholded gradient), every non-zero pixel being an edge pixel, using the standard Hough transform. Accepts the resolution of the accumulator for the distance (in pixels) and the angle (in radians), and the minimum number of votes of a line
+    ///
+    /// Returns the lines sorted by decreasing number of votes
+    ///
+    /// Returns `Err` if a resolution isn't strictly positive or if it's so fine that the accumulator would have more than 2^26 cells
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // horizontal line at y = 10
+    /// let pixels = Array2::from_shape_fn((20, 40), |(y, x)| [if y == 10 && x > 5 { 255 } else { 0 }]);
+    /// let edges = ImageData::new(40, 20, pixels);
+    ///
+    /// let lines = edges.hough_lines(1.0, std::f64::consts::PI / 180.0, 20)?;
+    /// assert_eq!(10.0, lines[0].rho());
+    /// assert!((lines[0].theta() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    /// # Ok(()) }
+    /// ```
+    pub fn hough_lines(
+        &self,
+        rho_step: f64,
+        theta_step: f64,
+        threshold: u32,
+    ) -> VisionXResult<Vec<Line>> {
+        self.view().hough_lines(rho_step, theta_step, threshold)
+    }
+
+    /// Finds the line segments of an edge image, every non-zero pixel being an edge pixel, using the progressive probabilistic Hough transform. Accepts the resolution of the accumulator for the distance (in pixels) and the angle (in radians), the minimum number of votes of a line, the minimum length of a segment and the maximum gap between two pixels of a segment
+    ///
+    /// Edge pixels are visited in a pseudo-random order, which is the same on every call. Pixels of a segment are removed as soon as it's found, so that they don't vote for other lines
+    ///
+    /// Returns `Err` if a resolution isn't strictly positive or if it's so fine that the accumulator would have more than 2^26 cells
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // vertical segment from (12, 4) to (12, 30)
+    /// let pixels = Array2::from_shape_fn((40, 40), |(y, x)| [if x == 12 && (4..=30).contains(&y) { 255 } else { 0 }]);
+    /// let edges = ImageData::new(40, 40, pixels);
+    ///
+    /// let segments = edges.hough_lines_p(1.0, std::f64::consts::PI / 180.0, 10, 10.0, 2.0)?;
+    /// assert_eq!(1, segments.len());
+    /// assert_eq!(26.0, segments[0].length());
+    /// # Ok(()) }
+    /// ```
+    pub fn hough_lines_p(
+        &self,
+        rho_step: f64,
+        theta_step: f64,
+        threshold: u32,
+        min_length: f64,
+        max_gap: f64,
+    ) -> VisionXResult<Vec<LineSegment>> {
+        self.view()
+            .hough_lines_p(rho_step, theta_step, threshold, min_length, max_gap)
+    }
+
+    /// Finds the circles of a grayscale image using the Hough gradient method. Edge pixels are the pixels whose gradient magnitude (Sobel) is a local maximum above `edge_threshold`, each of them votes for the centers lying along its gradient between `min_radius` and `max_radius`. Centers with at least `vote_threshold` votes, at least `min_distance` away from a center with more votes, are kept and their radius is estimated from the edge pixels around them
+    ///
+    /// Returns the circles sorted by decreasing number of votes
+    ///
+    /// Radii larger than the diagonal of the image can't fit in it, `max_radius` is clamped to the diagonal
+    ///
+    /// Returns `Err` if the radii aren't ordered or if `max_radius` is `0`
+    ///
+    /// ## Note
+    /// The intensity image is expected (not an edge image), as the direction of the gradient is required
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // bright disk of radius 15 centered at (40, 30)
+    /// let pixels = Array2::from_shape_fn((60, 80), |(y, x)| {
+    ///     let inside = (x as f64 - 40.0).hypot(y as f64 - 30.0) <= 15.0;
+    ///     [if inside { 200 } else { 20 }]
+    /// });
+    /// let img = ImageData::new(80, 60, pixels);
+    ///
+    /// let circles = img.hough_circles(20.0, 100.0, 80, 5, 30)?;
+    /// assert_eq!(1, circles.len());
+    /// let (x, y) = circles[0].center();
+    /// assert!((x - 40.0).abs() <= 1.0 && (y - 30.0).abs() <= 1.0);
+    /// assert!((circles[0].radius() - 15.0).abs() <= 1.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn hough_circles(
+        &self,
+        min_distance: f64,
+        edge_threshold: f64,
+        vote_threshold: u32,
+        min_radius: u32,
+        max_radius: u32,
+    ) -> VisionXResult<Vec<Circle>> {
+        self.view().hough_circles(
+            min_distance,
+            edge_threshold,
+            vote_threshold,
+            min_radius,
+            max_radius,
+        )
+    }
+}
+
+/// Implementation of Hough transforms of a borrowed grayscale image
+impl<'a> ImageView<'a, u8, 1> {
+    /// Finds the lines of the view, see `ImageData::hough_lines()`
+    pub fn hough_lines(
+        &self,
+        rho_step: f64,
+        theta_step: f64,
+        threshold: u32,
+    ) -> VisionXResult<Vec<Line>> {
+        let space = HoughSpace::new(self, rho_step, theta_step)?;
+        let mut accumulator = vec![0u32; space.thetas.len() * space.rhos];
+        for ((y, x), px) in self.pixels().indexed_iter() {
+            if px[0] != 0 {
+                for (t, index) in space.cells(x, y).enumerate() {
+                    accumulator[t * space.rhos + index] += 1;
+                }
+            }
+        }
+
+        let lines = accumulator_maxima(&accumulator, space.rhos, space.thetas.len(), threshold)
+            .into_iter()
+            .map(|(votes, r, t)| Line {
+                rho: (r as f64 - space.offset as f64) * rho_step,
+                theta: space.thetas[t].0,
+                votes,
+            })
+            .collect();
+        Ok(lines)
+    }
+
+    /// Finds the line segments of the view, see `ImageData::hough_lines_p()`
+    pub fn hough_lines_p(
+        &self,
+        rho_step: f64,
+        theta_step: f64,
+        threshold: u32,
+        min_length: f64,
+        max_gap: f64,
+    ) -> VisionXResult<Vec<LineSegment>> {
+        let space = HoughSpace::new(self, rho_step, theta_step)?;
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+
+        // state of every pixel: 0 for background or removed pixels, else edge pixels which have voted or not
+        const PENDING: u8 = 1;
+        const VOTED: u8 = 2;
+        let mut state = vec![0u8; width * height];
+        let mut points = Vec::new();
+        for ((y, x), px) in self.pixels().indexed_iter() {
+            if px[0] != 0 {
+                state[y * width + x] = PENDING;
+                points.push((x, y));
+            }
+        }
+        // points are shuffled with a fixed seed, so that results are reproducible
+        let mut random = XorShift::new(0x9E37_79B9_7F4A_7C15);
+        for i in (1..points.len()).rev() {
+            points.swap(i, random.below(i + 1));
+        }
+
+        let mut accumulator = vec![0i32; space.thetas.len() * space.rhos];
+        let mut segments = Vec::new();
+        for (x0, y0) in points {
+            if state[y0 * width + x0] != PENDING {
+                continue;
+            }
+            state[y0 * width + x0] = VOTED;
+
+            let mut best = (0, 0);
+            for (t, index) in space.cells(x0, y0).enumerate() {
+                let cell = &mut accumulator[t * space.rhos + index];
+                *cell += 1;
+                if *cell > best.0 {
+                    best = (*cell, t);
+                }
+            }
+            if best.0 < threshold.max(1) as i32 {
+                continue;
+            }
+
+            // pixels of the line are walked from the point in both directions, one pixel at a time along the main axis
+            let (_, cos, sin) = space.thetas[best.1];
+            let (dx, dy) = (-sin, cos);
+            let step = if dx.abs() > dy.abs() {
+                (dx.signum(), dy / dx.abs())
+            } else {
+                (dx / dy.abs(), dy.signum())
+            };
+            let pixel_at = |sign: f64, n: usize| {
+                let x = (x0 as f64 + sign * n as f64 * step.0).round();
+                let y = (y0 as f64 + sign * n as f64 * step.1).round();
+                (x >= 0.0 && y >= 0.0 && x < width as f64 && y < height as f64)
+                    .then_some((x as usize, y as usize))
+            };
+
+            // number of steps to the last edge pixel in each direction
+            let mut lengths = [0usize; 2];
+            for (k, sign) in [1.0, -1.0].into_iter().enumerate() {
+                let mut gap = 0;
+                for n in 1.. {
+                    let Some((x, y)) = pixel_at(sign, n) else {
+                        break;
+                    };
+                    if state[y * width + x] != 0 {
+                        gap = 0;
+                        lengths[k] = n;
+                    } else {
+                        gap += 1;
+                        if gap as f64 > max_gap {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            let ends = [
+                pixel_at(1.0, lengths[0]).unwrap_or((x0, y0)),
+                pixel_at(-1.0, lengths[1]).unwrap_or((x0, y0)),
+            ];
+            let length =
+                (ends[0].0 as f64 - ends[1].0 as f64).hypot(ends[0].1 as f64 - ends[1].1 as f64);
+            let found = length >= min_length;
+
+            // pixels of the segment are removed, their votes are withdrawn if the segment is kept
+            for (k, sign) in [1.0, -1.0].into_iter().enumerate() {
+                for n in 0..=lengths[k] {
+                    let Some((x, y)) = pixel_at(sign, n) else {
+                        break;
+                    };
+                    let pixel = &mut state[y * width + x];
+                    if found && *pixel == VOTED {
+                        for (t, index) in space.cells(x, y).enumerate() {
+                            accumulator[t * space.rhos + index] -= 1;
+                        }
+                    }
+                    *pixel = 0;
+                }
+            }
+
+            if found {
+                segments.push(LineSegment {
+                    start: (ends[1].0 as u32, ends[1].1 as u32),
+                    end: (ends[0].0 as u32, ends[0].1 as u32),
+                    votes: best.0 as u32,
+                });
+            }
+        }
+        Ok(segments)
+    }
+
+    /// Finds the circles of the view, see `ImageData::hough_circles()`
+    pub fn hough_circles(
+        &self,
+        min_distance: f64,
+        edge_threshold: f64,
+        vote_threshold: u32,
+        min_radius: u32,
+        max_radius: u32,
+    ) -> VisionXResult<Vec<Circle>> {
+        if max_radius == 0 || min_radius > max_radius {
+            let err = format!("finding circles with radii between {min_radius} and {max_radius}");
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        }
+
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+        // votes and radius histogram are bounded by the image size
+        let diagonal = (width as f64).hypot(height as f64).ceil() as u32;
+        let max_radius = max_radius.min(diagonal);
+        if min_radius > max_radius {
+            return Ok(Vec::new());
+        }
+        let (gx, gy) = gradients(self);
+        let magnitude: Vec<f64> = gx.iter().zip(&gy).map(|(x, y)| x.hypot(*y)).collect();
+
+        // edge pixels: local maxima of the magnitude along the gradient (4 quantised directions)
+        let mut edges = Vec::new();
+        for y in 1..height.saturating_sub(1) {
+            for x in 1..width.saturating_sub(1) {
+                let index = y * width + x;
+                let mag = magnitude[index];
+                if mag < edge_threshold || mag == 0.0 {
+                    continue;
+                }
+                let angle = gy[index].atan2(gx[index]).rem_euclid(PI);
+                let offset: isize = match ((angle + PI / 8.0) / (PI / 4.0)) as usize % 4 {
+                    0 => 1,
+                    1 => width as isize + 1,
+                    2 => width as isize,
+                    _ => width as isize - 1,
+                };
+                let (before, after) = (
+                    magnitude[(index as isize - offset) as usize],
+                    magnitude[(index as isize + offset) as usize],
+                );
+                if mag >= before && mag > after {
+                    edges.push((x, y, gx[index] / mag, gy[index] / mag));
+                }
+            }
+        }
+
+        // every edge pixel votes for the centers along its gradient, in both directions
+        let mut accumulator = vec![0u32; width * height];
+        for &(x, y, ux, uy) in &edges {
+            for sign in [1.0, -1.0] {
+                for r in min_radius.max(1)..=max_radius {
+                    let cx = (x as f64 + sign * r as f64 * ux).round();
+                    let cy = (y as f64 + sign * r as f64 * uy).round();
+                    if cx >= 0.0 && cy >= 0.0 && cx < width as f64 && cy < height as f64 {
+                        accumulator[cy as usize * width + cx as usize] += 1;
+                    }
+                }
+            }
+        }
+
+        // votes of a center are summed over its neighbours, as rounding spreads them around the center
+        let mut neighbourhood = vec![0u32; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                neighbourhood[y * width + x] = neighbours(x, y, width, height)
+                    .map(|(nx, ny)| accumulator[ny * width + nx])
+                    .sum();
+            }
+        }
+
+        let mut circles: Vec<Circle> = Vec::new();
+        for (votes, x, y) in accumulator_maxima(&neighbourhood, width, height, vote_threshold) {
+            // center is refined with the mean position of the votes
+            let (mut sx, mut sy) = (0.0, 0.0);
+            for (nx, ny) in neighbours(x, y, width, height) {
+                let weight = accumulator[ny * width + nx] as f64;
+                sx += weight * nx as f64;
+                sy += weight * ny as f64;
+            }
+            let center = (sx / votes as f64, sy / votes as f64);
+            let close = circles.iter().any(|circle| {
+                (circle.center.0 - center.0).hypot(circle.center.1 - center.1) < min_distance
+            });
+            if close {
+                continue;
+            }
+
+            // radius supported by the largest share of its circumference
+            let distances: Vec<f64> = edges
+                .iter()
+                .map(|&(ex, ey, _, _)| (ex as f64 - center.0).hypot(ey as f64 - center.1))
+                .collect();
+            let mut counts = vec![0u32; max_radius as usize + 1];
+            for &d in &distances {
+                let bin = d.round() as usize;
+                if bin >= min_radius.max(1) as usize && bin <= max_radius as usize {
+                    counts[bin] += 1;
+                }
+            }
+            let Some(radius) = (1..counts.len())
+                .filter(|&r| counts[r] > 0)
+                .max_by(|&a, &b| {
+                    (counts[a] as f64 / a as f64).total_cmp(&(counts[b] as f64 / b as f64))
+                })
+            else {
+                continue;
+            };
+            let near: Vec<f64> = distances
+                .into_iter()
+                .filter(|d| (d - radius as f64).abs() <= 1.0)
+                .collect();
+            let radius = near.iter().sum::<f64>() / near.len() as f64;
+
+            circles.push(Circle {
+                center,
+                radius,
+                votes,
+            });
+        }
+        Ok(circles)
+    }
+}
+
+/// `HoughSpace` holds the discretised parameter space `(theta, rho)` of the Hough line transforms
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct HoughSpace {
+    // angle along with its cosine and sine
+    thetas: Vec<(f64, f64, f64)>,
+    offset: usize,
+    rhos: usize,
+    rho_step: f64,
+}
+
+/// Core implementation of struct `HoughSpace`
+impl HoughSpace {
+    /// Creates the parameter space of an image for a resolution of the distance and the angle
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn new(img: &ImageView<'_, u8, 1>, rho_step: f64, theta_step: f64) -> VisionXResult<Self> {
+        if !(rho_step > 0.0 && theta_step > 0.0) {
+            let err = format!("finding lines with resolution ({rho_step}, {theta_step})");
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        }
+
+        // size of the accumulator is checked before allocating it
+        let max_rho = (*img.width() as f64).hypot(*img.height() as f64);
+        let (count, rhos) = (
+            (PI / theta_step).round().max(1.0),
+            (max_rho / rho_step).ceil(),
+        );
+        if count * (2.0 * rhos + 1.0) > MAX_LINE_CELLS {
+            let err = format!(
+                "finding lines with resolution ({rho_step}, {theta_step}), accumulator exceeds {MAX_LINE_CELLS} cells"
+            );
+            return Err(VisionXErrorKind::InvalidParameter(err).into());
+        }
+
+        let thetas = (0..count as usize)
+            .map(|t| {
+                let theta = t as f64 * theta_step;
+                let (sin, cos) = theta.sin_cos();
+                (theta, cos, sin)
+            })
+            .collect();
+        let offset = rhos as usize;
+        Ok(HoughSpace {
+            thetas,
+            offset,
+            rhos: 2 * offset + 1,
+            rho_step,
+        })
+    }
+
+    /// Returns the index of the distance cell of a point for every angle
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn cells(&self, x: usize, y: usize) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = (x as f64, y as f64);
+        self.thetas.iter().map(move |&(_, cos, sin)| {
+            (((x * cos + y * sin) / self.rho_step).round() as isize + self.offset as isize) as usize
+        })
+    }
+}
+
+/// Finds the local maxima (over the 8 neighbours) of an accumulator with at least `threshold` votes, see `kernel::local_maxima()`. Returns the votes and position `(x, y)` of the maxima sorted by decreasing number of votes
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn accumulator_maxima(
+    accumulator: &[u32],
+    width: usize,
+    height: usize,
+    threshold: u32,
+) -> Vec<(u32, usize, usize)> {
+    let mut maxima: Vec<(u32, usize, usize)> = local_maxima(accumulator, width, height, |votes| {
+        votes >= threshold.max(1)
+    })
+    .into_iter()
+    .map(|index| (accumulator[index], index % width, index / width))
+    .collect();
+    maxima.sort_by_key(|&(votes, _, _)| std::cmp::Reverse(votes));
+    maxima
+}
+
+/// Computes the horizontal and vertical gradients of an image with the Sobel operator, after smoothing it with a 5x5 binomial (Gaussian) kernel to steady the direction of the gradient. Pixels outside of the image are replicated from its border
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn gradients(img: &ImageView<'_, u8, 1>) -> (Vec<f64>, Vec<f64>) {
+    let pixels = img.pixels();
+    let (height, width) = pixels.dim();
+    let source: Vec<f64> = pixels.iter().map(|px| px[0] as f64).collect();
+    sobel(&smooth(&source, width, height), width, height)
+}
+
+#[cfg(test)]
+mod hough_test {
+    use crate::core::ImageData;
+    use crate::errors::{VisionXError, VisionXErrorKind};
+    use ndarray::Array2;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    // Builds an edge image from a predicate on the pixel position
+    fn edges(width: usize, height: usize, f: impl Fn(usize, usize) -> bool) -> ImageData<u8, 1> {
+        let pixels =
+            Array2::from_shape_fn((height, width), |(y, x)| [if f(x, y) { 255 } else { 0 }]);
+        ImageData::new(width as u32, height as u32, pixels)
+    }
+
+    // Standard transform finds horizontal, vertical and diagonal lines
+    #[test]
+    fn lines() {
+        let img = edges(80, 60, |x, y| {
+            (y == 10 && x >= 5) || (x == 30 && y >= 20) || (x == y && x < 50)
+        });
+        let lines = img.hough_lines(1.0, PI / 180.0, 35).unwrap();
+        assert_eq!(3, lines.len());
+
+        let find = |theta: f64| {
+            lines
+                .iter()
+                .find(|line| (line.theta() - theta).abs() < 1e-9)
+                .copied()
+        };
+        let horizontal = find(FRAC_PI_2).unwrap();
+        assert_eq!((10.0, 75), (horizontal.rho(), horizontal.votes()));
+        let vertical = find(0.0).unwrap();
+        // vertical line crosses the horizontal one
+        assert_eq!((30.0, 41), (vertical.rho(), vertical.votes()));
+        // x - y = 0, i.e. theta = 3 PI / 4 and rho = 0
+        let diagonal = find(3.0 * FRAC_PI_4).unwrap();
+        assert_eq!(0.0, diagonal.rho().abs());
+        assert_eq!(lines[0], horizontal);
+
+        // coarser resolution gathers neighbouring pixels in the same cell
+        let lines = img.hough_lines(2.0, PI / 90.0, 40).unwrap();
+        assert_eq!(3, lines.len());
+        assert!(lines[0].votes() > 75);
+
+        // accumulators too large to be allocated
+        for (rho, theta) in [
+            (0.0, 0.1),
+            (1.0, 0.0),
+            (-1.0, 0.1),
+            (1.0, 1e-9),
+            (1e-12, 0.1),
+        ] {
+            assert!(matches!(
+                img.hough_lines(rho, theta, 10),
+                Err(VisionXError::Kind(VisionXErrorKind::InvalidParameter(_)))
+            ));
+        }
+    }
+
+    // Probabilistic transform finds segments and their ends
+    #[test]
+    fn segments() {
+        // horizontal segment with a gap of 2 pixels, vertical segment and a short one
+        let img = edges(100, 80, |x, y| {
+            (y == 15 && (10..=70).contains(&x) && !(40..42).contains(&x))
+                || (x == 85 && (20..=75).contains(&y))
+                || (y == 60 && (10..=14).contains(&x))
+        });
+
+        let segments = img.hough_lines_p(1.0, PI / 180.0, 20, 20.0, 3.0).unwrap();
+        assert_eq!(2, segments.len());
+        let mut ends: Vec<((u32, u32), (u32, u32))> = segments
+            .iter()
+            .map(|segment| {
+                let (a, b) = (segment.start(), segment.end());
+                (a.min(b), a.max(b))
+            })
+            .collect();
+        ends.sort();
+        assert_eq!(vec![((10, 15), (70, 15)), ((85, 20), (85, 75))], ends);
+        assert!(segments.iter().all(|segment| segment.votes() >= 20));
+
+        // gap is larger than allowed, the horizontal segment is split
+        let segments = img.hough_lines_p(1.0, PI / 180.0, 20, 20.0, 1.0).unwrap();
+        assert_eq!(3, segments.len());
+        // results are reproducible
+        assert_eq!(
+            segments,
+            img.hough_lines_p(1.0, PI / 180.0, 20, 20.0, 1.0).unwrap()
+        );
+    }
+
+    // Gradient transform finds circles of several radii
+    #[test]
+    fn circles() {
+        let disks = [((30.0, 30.0), 12.0), ((90.0, 50.0), 25.0)];
+        let pixels = Array2::from_shape_fn((90, 130), |(y, x)| {
+            let inside = disks
+                .iter()
+                .any(|&(c, r)| (x as f64 - c.0).hypot(y as f64 - c.1) <= r);
+            [if inside { 220 } else { 30 }]
+        });
+        let img = ImageData::new(130, 90, pixels);
+
+        let mut circles = img.hough_circles(10.0, 200.0, 100, 5, 40).unwrap();
+        assert_eq!(2, circles.len());
+        circles.sort_by(|a, b| a.radius().total_cmp(&b.radius()));
+        for (circle, &(center, radius)) in circles.iter().zip(&disks) {
+            assert!((circle.center().0 - center.0).abs() <= 1.0);
+            assert!((circle.center().1 - center.1).abs() <= 1.0);
+            assert!(
+                (circle.radius() - radius).abs() <= 1.0,
+                "{}",
+                circle.radius()
+            );
+        }
+
+        // radii outside of the range
+        assert!(img.hough_circles(10.0, 200.0, 100, 30, 40).unwrap().len() <= 1);
+        assert!(img.hough_circles(10.0, 200.0, 40, 20, 10).is_err());
+        // radii are clamped to the diagonal of the image
+        let mut large = img.hough_circles(10.0, 200.0, 100, 5, u32::MAX).unwrap();
+        large.sort_by(|a, b| a.radius().total_cmp(&b.radius()));
+        assert_eq!(2, large.len());
+        for (circle, &(center, radius)) in large.iter().zip(&disks) {
+            assert!((circle.center().0 - center.0).abs() <= 1.0);
+            assert!((circle.center().1 - center.1).abs() <= 1.0);
+            assert!((circle.radius() - radius).abs() <= 1.0);
+        }
+        assert!(img
+            .hough_circles(10.0, 200.0, 100, 200, u32::MAX)
+            .unwrap()
+            .is_empty());
+        let flat = ImageData::new(10, 10, Array2::from_elem((10, 10), [9u8]));
+        assert!(flat.hough_circles(5.0, 10.0, 1, 1, 5).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+/// Returns the index (row by row) of a pixel of a `width` x `height` grid, positions outside of the grid being clamped to its border (replicated border)
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn clamped_index(width: usize, height: usize, x: isize, y: isize) -> usize {
+    let x = x.clamp(0, width as isize - 1) as usize;
+    let y = y.clamp(0, height as isize - 1) as usize;
+    y * width + x
+}
+
+/// Smooths values (row by row) with a separable 5-tap binomial (Gaussian) kernel, rows then columns. Values outside of the grid are replicated from its border
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn smooth(values: &[f64], width: usize, height: usize) -> Vec<f64> {
+    const KERNEL: [f64; 5] = [1.0 / 16.0, 4.0 / 16.0, 6.0 / 16.0, 4.0 / 16.0, 1.0 / 16.0];
+    let index = |x: isize, y: isize| clamped_index(width, height, x, y);
+    let mut rows = vec![0.0; width * height];
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            rows[index(x, y)] = (0..5)
+                .map(|k| KERNEL[k] * values[index(x + k as isize - 2, y)])
+                .sum();
+        }
+    }
+    let mut smoothed = vec![0.0; width * height];
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            smoothed[index(x, y)] = (0..5)
+                .map(|k| KERNEL[k] * rows[index(x, y + k as isize - 2)])
+                .sum();
+        }
+    }
+    smoothed
+}
+
+/// Computes the horizontal and vertical gradients of values (row by row) with the 3x3 Sobel operator. Values outside of the grid are replicated from its border
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn sobel(values: &[f64], width: usize, height: usize) -> (Vec<f64>, Vec<f64>) {
+    let at = |x: isize, y: isize| values[clamped_index(width, height, x, y)];
+    let mut gx = vec![0.0; width * height];
+    let mut gy = vec![0.0; width * height];
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            let index = y as usize * width + x as usize;
+            gx[index] = at(x + 1, y - 1) + 2.0 * at(x + 1, y) + at(x + 1, y + 1)
+                - at(x - 1, y - 1)
+                - 2.0 * at(x - 1, y)
+                - at(x - 1, y + 1);
+            gy[index] = at(x - 1, y + 1) + 2.0 * at(x, y + 1) + at(x + 1, y + 1)
+                - at(x - 1, y - 1)
+                - 2.0 * at(x, y - 1)
+                - at(x + 1, y - 1);
+        }
+    }
+    (gx, gy)
+}
+
+/// Returns the positions `(x, y)` of a cell and its (up to 8) neighbours within a grid
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn neighbours(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    (y.saturating_sub(1)..(y + 2).min(height))
+        .flat_map(move |ny| (x.saturating_sub(1)..(x + 2).min(width)).map(move |nx| (nx, ny)))
+}
+
+/// Returns the indices (row by row) of the values accepted by `is_candidate` which are a maximum of their 3x3 neighbourhood, ties being resolved in favour of the first value
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn local_maxima<V: PartialOrd + Copy>(
+    values: &[V],
+    width: usize,
+    height: usize,
+    is_candidate: impl Fn(V) -> bool,
+) -> Vec<usize> {
+    let mut maxima = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            let value = values[y * width + x];
+            if !is_candidate(value) {
+                continue;
+            }
+            let is_max = neighbours(x, y, width, height).all(|(nx, ny)| {
+                let neighbour = values[ny * width + nx];
+                // neighbours met before the value must be lower, the others lower or equal
+                if (ny, nx) < (y, x) {
+                    neighbour < value
+                } else {
+                    neighbour <= value
+                }
+            });
+            if is_max {
+                maxima.push(y * width + x);
+            }
+        }
+    }
+    maxima
+}
+
+/// `XorShift` is a deterministic xorshift pseudo-random generator, so that sampling and shuffling give the same results on every run
+///
+/// ## Note
+/// Struct is used internally (crate scope)
+pub(crate) struct XorShift {
+    state: u64,
+}
+
+/// Core implementation of struct `XorShift`
+impl XorShift {
+    /// Creates the generator from a seed, which must not be `0`
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next pseudo-random value
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns a pseudo-random value within `0..bound`, `0` if `bound` is `0`
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound.max(1) as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod kernel_test {
+    use super::{local_maxima, smooth, sobel, XorShift};
+
+    // Smoothing keeps flat values, Sobel measures ramps and ties are resolved in favour of the first maximum
+    #[test]
+    fn kernels() {
+        let flat = vec![3.0; 6 * 4];
+        assert!(smooth(&flat, 6, 4).iter().all(|v| (v - 3.0).abs() < 1e-12));
+
+        let ramp: Vec<f64> = (0..6 * 4).map(|i| (i % 6) as f64).collect();
+        let (gx, gy) = sobel(&ramp, 6, 4);
+        // 8 times the slope inside, border replicated on the sides
+        assert_eq!(8.0, gx[6 + 2]);
+        assert_eq!(4.0, gx[6]);
+        assert!(gy.iter().all(|&v| v == 0.0));
+
+        let values = [0, 5, 5, 0, 0, 1, 0, 9, 0];
+        assert_eq!(vec![1, 7], local_maxima(&values, 3, 3, |v| v > 0));
+        assert_eq!(vec![7], local_maxima(&values, 3, 3, |v| v > 5));
+
+        // the sequence only depends on the seed
+        let mut a = XorShift::new(0x2545_F491_4F6C_DD1D);
+        let mut b = XorShift::new(0x2545_F491_4F6C_DD1D);
+        for _ in 0..100 {
+            let value = a.below(10);
+            assert!(value < 10);
+            assert_eq!(value, b.below(10));
+        }
+        assert_eq!(0, a.below(0));
+    }
+}
@@ -1,7 +1,65 @@
 /// Converts colorspace of an image
-/// 
+///
 /// Currently supports conversion b/w **RGB, Grayscale and HSV** colorspace
 mod color;
 
+/// Converts color depth of an image. Supports 8bit, 16bit and 32bit float color depth
+mod depth;
+
 /// Used for image size manipulation. Can be used in resizing or cropping an image
 mod frame;
+
+/// Fixtures shared by the tests of the image processing operations
+#[cfg(test)]
+mod test_utils;
+
+/// Labels connected components of a binary image along with their statistics (area, bounding box and centroid)
+mod components;
+
+pub use components::{ComponentStats, Connectivity};
+
+/// Finds the contours of a binary image and computes shape descriptors (area, perimeter, convex hull, enclosing shapes and Hu moments)
+mod contours;
+
+pub use contours::{Contour, RotatedRect};
+
+/// Computes spatial, central and normalised moments of an image, a labelled region or a contour, along with the properties derived from them
+mod moments;
+
+pub use moments::Moments;
+
+/// Finds lines, line segments and circles of an image with Hough transforms
+mod hough;
+
+pub use hough::{Circle, Line, LineSegment};
+
+/// Neighbourhood operations shared by the detectors (replicated border, binomial smoothing, Sobel gradients and local maxima) and the pseudo-random generator of the randomised algorithms
+pub(crate) mod kernel;
+
+/// Computes fast Fourier transforms, used to work in the frequency domain
+mod fft;
+
+/// Processes single channel images in the frequency domain: spectra, frequency filters and phase correlation
+mod frequency;
+
+pub use frequency::{FrequencyFilter, Spectrum};
+
+/// Matches a template against every window of an image
+mod template;
+
+pub use template::{Extrema, MatchMethod};
+
+/// Detects corners and keypoints of a grayscale image (Harris, Shi-Tomasi and FAST)
+mod features;
+
+pub use features::{CornerScore, KeyPoint};
+
+/// Computes binary descriptors of keypoints (ORB) and matches them with the Hamming distance
+mod descriptors;
+
+pub use descriptors::{match_descriptors, BinaryDescriptor, FeatureMatch};
+
+/// Estimates geometric transforms (similarity, affine, homography) from point correspondences with RANSAC or LMedS, and warps images through them
+mod geometry;
+
+pub use geometry::{estimate_transform, RobustMethod, Transform, TransformModel};
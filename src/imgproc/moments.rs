@@ -0,0 +1,453 @@
+use crate::{
+    core::{ImageData, ImageView},
+    errors::{VisionXError, VisionXResult},
+};
+use std::collections::BTreeMap;
+
+/// Orders `(p, q)` of the moments up to the third order, in the order they are stored
+///
+/// ## Note
+/// Constant is used internally (private scope)
+const ORDERS: [(usize, usize); 10] = [
+    (0, 0),
+    (1, 0),
+    (0, 1),
+    (2, 0),
+    (1, 1),
+    (0, 2),
+    (3, 0),
+    (2, 1),
+    (1, 2),
+    (0, 3),
+];
+
+/// `Moments` holds the spatial, central and normalised moments up to the third order of an image, a region or a contour, along with the properties derived from them
+///
+/// Returned by `ImageData::moments()`, `ImageData::region_moments()` (for label images) and `Contour::moments()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Moments {
+    spatial: [f64; 10],
+    central: [f64; 10],
+    normalized: [f64; 10],
+}
+
+/// Core implementation of struct `Moments`
+impl Moments {
+    /// Creates the moments from the spatial moments computed relative to `origin`, which keeps the sums accurate for regions lying far from the origin of the image
+    ///
+    /// ## Note
+    /// Function is used internally (crate scope)
+    pub(crate) fn from_shifted(shifted: [f64; 10], origin: (f64, f64)) -> Self {
+        let [m00, m10, m01, m20, m11, m02, m30, m21, m12, m03] = shifted;
+        if m00 == 0.0 {
+            return Moments {
+                spatial: [0.0; 10],
+                central: [0.0; 10],
+                normalized: [0.0; 10],
+            };
+        }
+
+        // central moments don't depend on the origin
+        let (cx, cy) = (m10 / m00, m01 / m00);
+        let central = [
+            m00,
+            0.0,
+            0.0,
+            m20 - cx * m10,
+            m11 - cx * m01,
+            m02 - cy * m01,
+            m30 - 3.0 * cx * m20 + 2.0 * cx * cx * m10,
+            m21 - 2.0 * cx * m11 - cy * m20 + 2.0 * cx * cx * m01,
+            m12 - 2.0 * cy * m11 - cx * m02 + 2.0 * cy * cy * m10,
+            m03 - 3.0 * cy * m02 + 2.0 * cy * cy * m01,
+        ];
+
+        // spatial moments are moved back to the origin of the image (binomial expansion)
+        let spatial = ORDERS.map(|(p, q)| {
+            let mut sum = 0.0;
+            for i in 0..=p {
+                for j in 0..=q {
+                    let coefficient = binomial(p, i) * binomial(q, j);
+                    let shift = origin.0.powi((p - i) as i32) * origin.1.powi((q - j) as i32);
+                    sum += coefficient * shift * shifted[order_index(i, j)];
+                }
+            }
+            sum
+        });
+
+        let mut normalized = [0.0; 10];
+        normalized[0] = 1.0;
+        for (k, &(p, q)) in ORDERS.iter().enumerate().skip(3) {
+            normalized[k] = central[k] / m00.powf(1.0 + (p + q) as f64 / 2.0);
+        }
+
+        Moments {
+            spatial,
+            central,
+            normalized,
+        }
+    }
+
+    /// Spatial (raw) moment `m_pq`, i.e. the sum of `value * x^p * y^q`. Returns `None` if `p + q > 3`
+    pub fn spatial(&self, p: usize, q: usize) -> Option<f64> {
+        Self::get(&self.spatial, p, q)
+    }
+
+    /// Central moment `mu_pq`, i.e. the spatial moment relative to the centroid, invariant to translation. Returns `None` if `p + q > 3`
+    pub fn central(&self, p: usize, q: usize) -> Option<f64> {
+        Self::get(&self.central, p, q)
+    }
+
+    /// Normalised central moment `nu_pq = mu_pq / mu_00^(1 + (p + q) / 2)`, invariant to translation and scale. Returns `None` if `p + q > 3`
+    pub fn normalized(&self, p: usize, q: usize) -> Option<f64> {
+        Self::get(&self.normalized, p, q)
+    }
+
+    /// Centroid `(x, y)` of the image or region, weighted by the pixel values. Returns `NaN` if the moments are computed over an empty (or black) region
+    pub fn centroid(&self) -> (f64, f64) {
+        let m00 = self.spatial[0];
+        (self.spatial[1] / m00, self.spatial[2] / m00)
+    }
+
+    /// Angle in radians within `(-PI / 2, PI / 2]` between the x-axis and the major axis of the ellipse having the same second central moments, clockwise as the y-axis points down
+    pub fn orientation(&self) -> f64 {
+        let (a, b, c) = self.covariance();
+        0.5 * (2.0 * b).atan2(a - c)
+    }
+
+    /// Eccentricity of the ellipse having the same second central moments, from `0.0` (circle) to `1.0` (line)
+    pub fn eccentricity(&self) -> f64 {
+        let (major, minor) = self.eigenvalues();
+        if major <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - minor / major).max(0.0).sqrt()
+    }
+
+    /// Lengths `(major, minor)` of the axes of the ellipse having the same second central moments, i.e. 4 times the square root of the eigenvalues of the covariance `(mu20, mu11, mu02) / mu00`
+    pub fn axis_lengths(&self) -> (f64, f64) {
+        let (major, minor) = self.eigenvalues();
+        (4.0 * major.max(0.0).sqrt(), 4.0 * minor.max(0.0).sqrt())
+    }
+
+    /// Seven Hu moments, which are invariant to translation, scale and rotation (the seventh one changes sign under reflection)
+    pub fn hu(&self) -> [f64; 7] {
+        let [_, _, _, n20, n11, n02, n30, n21, n12, n03] = self.normalized;
+        let (t0, t1) = (n30 + n12, n21 + n03);
+        let (q0, q1) = (n30 - 3.0 * n12, 3.0 * n21 - n03);
+        [
+            n20 + n02,
+            (n20 - n02).powi(2) + 4.0 * n11 * n11,
+            q0 * q0 + q1 * q1,
+            t0 * t0 + t1 * t1,
+            q0 * t0 * (t0 * t0 - 3.0 * t1 * t1) + q1 * t1 * (3.0 * t0 * t0 - t1 * t1),
+            (n20 - n02) * (t0 * t0 - t1 * t1) + 4.0 * n11 * t0 * t1,
+            q1 * t0 * (t0 * t0 - 3.0 * t1 * t1) - q0 * t1 * (3.0 * t0 * t0 - t1 * t1),
+        ]
+    }
+
+    /// Returns the moment of order `(p, q)` from a list of moments
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn get(moments: &[f64; 10], p: usize, q: usize) -> Option<f64> {
+        (p + q <= 3).then(|| moments[order_index(p, q)])
+    }
+
+    /// Returns the covariance `(mu20, mu11, mu02) / mu00` of the region
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn covariance(&self) -> (f64, f64, f64) {
+        let m00 = self.central[0];
+        if m00 == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+        (
+            self.central[3] / m00,
+            self.central[4] / m00,
+            self.central[5] / m00,
+        )
+    }
+
+    /// Returns the eigenvalues (largest first) of the covariance of the region
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn eigenvalues(&self) -> (f64, f64) {
+        let (a, b, c) = self.covariance();
+        let mean = (a + c) / 2.0;
+        let delta = ((a - c) / 2.0).hypot(b);
+        (mean + delta, mean - delta)
+    }
+}
+
+/// Implementation of image moments of a single channel image
+impl<T: Default + Copy + Into<f64>> ImageData<T, 1> {
+    /// Computes the moments of an image up to the third order, each pixel being weighted by its value. Accepts an optional mask, in which case only the pixels with a non-zero mask value are considered
+    ///
+    /// Centroid, orientation, eccentricity, axis lengths and Hu moments are then derived from `Moments`
+    ///
+    /// Returns `Err` if the mask and the image have different sizes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// // horizontal bar
+    /// let pixels = Array2::from_shape_fn((10, 10), |(y, x)| [if (2..8).contains(&x) && (4..6).contains(&y) { 255u8 } else { 0 }]);
+    /// let img = ImageData::new(10, 10, pixels);
+    ///
+    /// let moments = img.moments(None)?;
+    /// assert_eq!((4.5, 4.5), moments.centroid());
+    /// assert_eq!(0.0, moments.orientation());
+    /// assert!(moments.eccentricity() > 0.9);
+    /// # Ok(()) }
+    /// ```
+    pub fn moments(&self, mask: Option<&ImageData<u8, 1>>) -> VisionXResult<Moments> {
+        let mask = mask.map(|mask| mask.view());
+        self.view().moments(mask.as_ref())
+    }
+}
+
+/// Implementation of image moments of a borrowed single channel image
+impl<'a, T: Default + Copy + Into<f64>> ImageView<'a, T, 1> {
+    /// Computes the moments of the view, relative to the view, see `ImageData::moments()`
+    pub fn moments(&self, mask: Option<&ImageView<'_, u8, 1>>) -> VisionXResult<Moments> {
+        if let Some(mask) = mask {
+            if mask.pixels().dim() != self.pixels().dim() {
+                return Err(VisionXError::SizeMismatch {
+                    expected: (*self.width(), *self.height()),
+                    actual: (*mask.width(), *mask.height()),
+                });
+            }
+        }
+
+        // sums are computed relative to the centre of the image
+        let origin = ((*self.width() / 2) as f64, (*self.height() / 2) as f64);
+        let mut sums = [0.0; 10];
+        for ((y, x), px) in self.pixels().indexed_iter() {
+            if mask.is_some_and(|mask| mask.pixels()[(y, x)][0] == 0) {
+                continue;
+            }
+            accumulate(
+                &mut sums,
+                x as f64 - origin.0,
+                y as f64 - origin.1,
+                px[0].into(),
+            );
+        }
+        Ok(Moments::from_shifted(sums, origin))
+    }
+}
+
+/// Implementation of region moments of a label image
+impl ImageData<u32, 1> {
+    /// Computes the moments of each labelled region of a label image (e.g. returned by `ImageData::connected_components()`), every pixel of a region having a weight of `1`. The moments are mapped by label in increasing order, only labels present in the image are returned and label `0` (background) is skipped
+    ///
+    /// Area (`m00`), centroid, orientation, eccentricity and axis lengths give region properties similar to scikit-image's `regionprops`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::Connectivity;
+    ///
+    /// # fn main() {
+    /// let pixels = Array2::from_shape_fn((8, 8), |(y, x)| [if x < 3 || (y == 6 && x > 4) { 255 } else { 0 }]);
+    /// let mask = ImageData::new(8, 8, pixels);
+    ///
+    /// let (labels, _) = mask.connected_components(Connectivity::Eight);
+    /// let regions = labels.region_moments();
+    /// assert_eq!(Some(24.0), regions[&1].spatial(0, 0));
+    /// // vertical and horizontal bars
+    /// assert_eq!(std::f64::consts::FRAC_PI_2, regions[&1].orientation());
+    /// assert_eq!((6.0, 6.0), regions[&2].centroid());
+    /// # }
+    /// ```
+    pub fn region_moments(&self) -> BTreeMap<u32, Moments> {
+        self.view().region_moments()
+    }
+}
+
+/// Implementation of region moments of a borrowed label image
+impl<'a> ImageView<'a, u32, 1> {
+    /// Computes the moments of each labelled region of the view, see `ImageData::region_moments()`
+    pub fn region_moments(&self) -> BTreeMap<u32, Moments> {
+        // sums of each region are computed relative to the first pixel met, labels may be sparse
+        let mut regions: BTreeMap<u32, RegionSums> = BTreeMap::new();
+        for ((y, x), px) in self.pixels().indexed_iter() {
+            let label = px[0];
+            if label == 0 {
+                continue;
+            }
+
+            let (x, y) = (x as f64, y as f64);
+            let (sums, origin) = regions.entry(label).or_insert(([0.0; 10], (x, y)));
+            accumulate(sums, x - origin.0, y - origin.1, 1.0);
+        }
+
+        regions
+            .into_iter()
+            .map(|(label, (sums, origin))| (label, Moments::from_shifted(sums, origin)))
+            .collect()
+    }
+}
+
+/// Spatial moments of a region computed relative to an origin, along with the origin
+///
+/// ## Note
+/// Type is used internally (private scope)
+type RegionSums = ([f64; 10], (f64, f64));
+
+/// Returns the index of the moment of order `(p, q)` in `ORDERS`
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn order_index(p: usize, q: usize) -> usize {
+    ORDERS
+        .iter()
+        .position(|&order| order == (p, q))
+        .unwrap_or(0)
+}
+
+/// Computes the binomial coefficient `n choose k` for small values
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Adds the contribution of a weighted point to the spatial moments
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn accumulate(sums: &mut [f64; 10], x: f64, y: f64, weight: f64) {
+    let (x2, y2) = (x * x, y * y);
+    let terms = [1.0, x, y, x2, x * y, y2, x2 * x, x2 * y, x * y2, y2 * y];
+    for (sum, term) in sums.iter_mut().zip(terms) {
+        *sum += weight * term;
+    }
+}
+
+#[cfg(test)]
+mod moments_test {
+    use crate::core::ImageData;
+    use crate::errors::VisionXError;
+    use crate::imgproc::Connectivity;
+    use ndarray::Array2;
+
+    // Moments match the sums computed by definition
+    #[test]
+    fn image_moments() {
+        let pixels = Array2::from_shape_fn((30, 40), |(y, x)| [((x * 7 + y * 13) % 17) as u16]);
+        let img = ImageData::new(40, 30, pixels.clone());
+        let moments = img.moments(None).unwrap();
+
+        let sum = |f: &dyn Fn(f64, f64) -> f64| -> f64 {
+            pixels
+                .indexed_iter()
+                .map(|((y, x), px)| px[0] as f64 * f(x as f64, y as f64))
+                .sum()
+        };
+        let m00 = sum(&|_, _| 1.0);
+        let (cx, cy) = (sum(&|x, _| x) / m00, sum(&|_, y| y) / m00);
+        for p in 0..=3 {
+            for q in 0..=3 - p {
+                let spatial = sum(&|x, y| x.powi(p) * y.powi(q));
+                let central = sum(&|x, y| (x - cx).powi(p) * (y - cy).powi(q));
+                let normalized = central / m00.powf(1.0 + (p + q) as f64 / 2.0);
+                let (p, q) = (p as usize, q as usize);
+                let close = |a: f64, b: f64| (a - b).abs() <= 1e-9 * (1.0 + b.abs());
+                assert!(close(moments.spatial(p, q).unwrap(), spatial));
+                assert!(close(moments.central(p, q).unwrap(), central));
+                if p + q >= 2 {
+                    assert!(close(moments.normalized(p, q).unwrap(), normalized));
+                }
+            }
+        }
+        assert_eq!(None, moments.spatial(2, 2));
+        assert_eq!((cx, cy), moments.centroid());
+    }
+
+    // Masked pixels are skipped and the region properties are derived
+    #[test]
+    fn masked_properties() {
+        let img = ImageData::new(10, 10, Array2::from_elem((10, 10), [2.0f32]));
+        // 6x2 bar
+        let mask = Array2::from_shape_fn((10, 10), |(y, x)| {
+            [((1..7).contains(&x) && (3..5).contains(&y)) as u8]
+        });
+        let mask = ImageData::new(10, 10, mask);
+
+        let moments = img.moments(Some(&mask)).unwrap();
+        assert_eq!(Some(24.0), moments.spatial(0, 0));
+        assert_eq!((3.5, 3.5), moments.centroid());
+        assert_eq!(Some(70.0), moments.central(2, 0));
+        assert_eq!(Some(6.0), moments.central(0, 2));
+        assert_eq!(Some(0.0), moments.central(1, 1));
+        assert_eq!(0.0, moments.orientation());
+        assert!((moments.eccentricity() - (1.0 - 0.25 / (35.0f64 / 12.0)).sqrt()).abs() < 1e-12);
+        let (major, minor) = moments.axis_lengths();
+        assert!((major - 4.0 * (35.0f64 / 12.0).sqrt()).abs() < 1e-12);
+        assert_eq!(2.0, minor);
+
+        // transposed bar is vertical, Hu moments are unchanged
+        let transposed = ImageData::new(10, 10, mask.pixels().t().to_owned());
+        let other = img.moments(Some(&transposed)).unwrap();
+        assert_eq!(std::f64::consts::FRAC_PI_2, other.orientation());
+        for (a, b) in moments.hu().iter().zip(other.hu()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let small = ImageData::new(5, 5, Array2::from_elem((5, 5), [1u8]));
+        assert!(matches!(
+            img.moments(Some(&small)),
+            Err(VisionXError::SizeMismatch { .. })
+        ));
+        let black = ImageData::new(5, 5, Array2::from_elem((5, 5), [0u8]));
+        assert_eq!(0.0, black.moments(None).unwrap().eccentricity());
+    }
+
+    // Region moments of a label image match the statistics of the components
+    #[test]
+    fn region_moments() {
+        let pixels = Array2::from_shape_fn((300, 400), |(y, x)| {
+            let blob = (x as i64 - 350).pow(2) + (y as i64 - 250).pow(2) * 4 < 900;
+            let bar = (10..60).contains(&x) && (20..25).contains(&y);
+            [(blob || bar) as u8]
+        });
+        let img = ImageData::new(400, 300, pixels);
+        let (labels, stats) = img.connected_components(Connectivity::Eight);
+        let regions = labels.region_moments();
+
+        assert_eq!(stats.len(), regions.len());
+        for (stat, region) in stats.iter().zip(regions.values()) {
+            assert_eq!(Some(stat.area() as f64), region.spatial(0, 0));
+            let (a, b) = (stat.centroid(), region.centroid());
+            assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9);
+        }
+        // blob is an ellipse twice as wide as high
+        let blob = &regions[&2];
+        assert!(blob.orientation().abs() < 1e-9);
+        assert!((blob.eccentricity() - 0.75f64.sqrt()).abs() < 0.01);
+
+        // sparse labels don't allocate a region per label value
+        let mut pixels = Array2::from_elem((4, 4), [0u32]);
+        pixels[[1, 1]] = [u32::MAX];
+        pixels[[2, 3]] = [7];
+        let regions = ImageData::new(4, 4, pixels).region_moments();
+        assert_eq!(
+            vec![7, u32::MAX],
+            regions.keys().copied().collect::<Vec<u32>>()
+        );
+        assert_eq!((1.0, 1.0), regions[&u32::MAX].centroid());
+        assert_eq!(Some(1.0), regions[&7].spatial(0, 0));
+    }
+}
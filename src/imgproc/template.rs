@@ -0,0 +1,513 @@
+use super::fft::{fft_2d, Complex};
+use crate::{
+    core::{Grayscale32F, ImageData, ImageView},
+    errors::{VisionXErrorKind, VisionXResult},
+};
+
+/// `MatchMethod` defines how a template is compared with the image in `ImageData::match_template()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMethod {
+    /// Sum of squared differences, the best match is the minimum (`0.0` for a perfect match)
+    SumOfSquaredDifferences,
+    /// Sum of squared differences divided by the norms of the template and the window, the best match is the minimum
+    NormalizedSquaredDifferences,
+    /// Sum of the products of the template and the window, the best match is the maximum. Biased towards bright regions
+    CrossCorrelation,
+    /// Correlation coefficient, i.e. cross-correlation of the template and the window once their means are removed and divided by their norms. The best match is the maximum, within `[-1.0, 1.0]`, and it doesn't depend on the brightness and contrast of the window
+    NormalizedCrossCorrelation,
+}
+
+/// Implementation of template matching of an image
+impl<T: Default + Copy + Into<f64>, const N: usize> ImageData<T, N> {
+    /// Slides a template over the image and compares it with every window of the same size using the given method, the channels being summed. Returns the score map of size `(width - template width + 1, height - template height + 1)`, the score of the window whose top-left corner is `(x, y)` being at `(x, y)`
+    ///
+    /// Large templates are correlated in the frequency domain (FFT), which keeps matching tractable on full-resolution frames. Sums over the windows are computed with integral images
+    ///
+    /// Returns `Err` if the template is empty or larger than the image
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use vision_x::core::ImageData;
+    /// use vision_x::imgproc::MatchMethod;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let pixels = Array2::from_shape_fn((40, 60), |(y, x)| [((x * x + 3 * y * x + y) % 251) as u8]);
+    /// let img = ImageData::new(60, 40, pixels);
+    /// let template = img.crop((25, 12), (35, 20))?;
+    ///
+    /// let scores = img.match_template(&template, MatchMethod::NormalizedCrossCorrelation)?;
+    /// let extrema = scores.min_max_loc().unwrap();
+    /// assert_eq!((25, 12), extrema.max_loc());
+    /// assert!((extrema.max() - 1.0).abs() < 1e-4);
+    /// # Ok(()) }
+    /// ```
+    pub fn match_template(
+        &self,
+        template: &ImageData<T, N>,
+        method: MatchMethod,
+    ) -> VisionXResult<Grayscale32F> {
+        self.view().match_template(&template.view(), method)
+    }
+}
+
+/// Implementation of template matching of a borrowed image
+impl<'a, T: Default + Copy + Into<f64>, const N: usize> ImageView<'a, T, N> {
+    /// Slides a template over the view, see `ImageData::match_template()`
+    pub fn match_template(
+        &self,
+        template: &ImageView<'_, T, N>,
+        method: MatchMethod,
+    ) -> VisionXResult<Grayscale32F> {
+        let (width, height) = (*self.width() as usize, *self.height() as usize);
+        let (t_width, t_height) = (*template.width() as usize, *template.height() as usize);
+        if t_width == 0 || t_height == 0 || t_width > width || t_height > height {
+            let err = format!(
+                "matching a template of size ({t_width}, {t_height}) with an image of size ({width}, {height})"
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+
+        let (out_width, out_height) = (width - t_width + 1, height - t_height + 1);
+        let use_fft = prefers_fft(width, height, t_width, t_height);
+        let scores = match_scores(self, template, method, use_fft);
+
+        let pixels = ndarray::Array2::from_shape_fn((out_height, out_width), |(y, x)| {
+            [scores[y * out_width + x] as f32]
+        });
+        Ok(ImageData::new(out_width as u32, out_height as u32, pixels))
+    }
+}
+
+/// `Extrema` holds the minimum and maximum values of an image along with their positions, returned by `ImageData::min_max_loc()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extrema<T> {
+    min: T,
+    min_loc: (u32, u32),
+    max: T,
+    max_loc: (u32, u32),
+}
+
+/// Core implementation of struct `Extrema`
+impl<T: Copy> Extrema<T> {
+    /// Minimum value
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Position `(x, y)` of the minimum value
+    pub fn min_loc(&self) -> (u32, u32) {
+        self.min_loc
+    }
+
+    /// Maximum value
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Position `(x, y)` of the maximum value
+    pub fn max_loc(&self) -> (u32, u32) {
+        self.max_loc
+    }
+}
+
+/// Implementation to locate the extrema of a single channel image
+impl<T: Default + Copy + PartialOrd> ImageData<T, 1> {
+    /// Finds the minimum and maximum values of a single channel image along with their positions `(x, y)`. The first position met (row by row) is returned for ties and values which can't be compared (`NaN`) are skipped
+    ///
+    /// Returns `None` if the image is empty (or only holds `NaN`)
+    ///
+    /// Useful to find the best match in the score map returned by `ImageData::match_template()`
+    pub fn min_max_loc(&self) -> Option<Extrema<T>> {
+        let mut extrema: Option<Extrema<T>> = None;
+        for ((y, x), px) in self.pixels().indexed_iter() {
+            let value = px[0];
+            // NaN is the only value which can't be compared with itself
+            if value.partial_cmp(&value).is_none() {
+                continue;
+            }
+            let loc = (x as u32, y as u32);
+            match extrema.as_mut() {
+                None => {
+                    extrema = Some(Extrema {
+                        min: value,
+                        min_loc: loc,
+                        max: value,
+                        max_loc: loc,
+                    })
+                }
+                Some(extrema) => {
+                    if value < extrema.min {
+                        (extrema.min, extrema.min_loc) = (value, loc);
+                    }
+                    if value > extrema.max {
+                        (extrema.max, extrema.max_loc) = (value, loc);
+                    }
+                }
+            }
+        }
+        extrema
+    }
+}
+
+/// Returns `true` if correlating in the frequency domain is expected to be faster than sliding the template
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn prefers_fft(width: usize, height: usize, t_width: usize, t_height: usize) -> bool {
+    let direct = ((width - t_width + 1) * (height - t_height + 1) * t_width * t_height) as f64;
+    let size = (width.next_power_of_two() * height.next_power_of_two()) as f64;
+    // three transforms (image, template and inverse) with some overhead
+    direct > 10.0 * size * size.log2()
+}
+
+/// Computes the scores of every window, row by row
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn match_scores<T: Default + Copy + Into<f64>, const N: usize>(
+    img: &ImageView<'_, T, N>,
+    template: &ImageView<'_, T, N>,
+    method: MatchMethod,
+    use_fft: bool,
+) -> Vec<f64> {
+    let (width, height) = (*img.width() as usize, *img.height() as usize);
+    let (t_width, t_height) = (*template.width() as usize, *template.height() as usize);
+    let (out_width, out_height) = (width - t_width + 1, height - t_height + 1);
+    let count = (t_width * t_height) as f64;
+
+    let mut scores = vec![0.0; out_width * out_height];
+    // per channel: sum of products, sums and sums of squares of the windows
+    let mut numerators = vec![0.0; out_width * out_height];
+    let mut window_norms = vec![0.0; out_width * out_height];
+    let mut template_norm = 0.0;
+    for c in 0..N {
+        let image: Vec<f64> = img.pixels().iter().map(|px| px[c].into()).collect();
+        let templ: Vec<f64> = template.pixels().iter().map(|px| px[c].into()).collect();
+        let products = if use_fft {
+            correlate_fft(&image, width, height, &templ, t_width, t_height)
+        } else {
+            correlate(
+                &image, width, &templ, t_width, t_height, out_width, out_height,
+            )
+        };
+        let sums = window_sums(&image, width, height, t_width, t_height, false);
+        let squares = window_sums(&image, width, height, t_width, t_height, true);
+        let t_sum: f64 = templ.iter().sum();
+        let t_squares: f64 = templ.iter().map(|v| v * v).sum();
+
+        for i in 0..out_width * out_height {
+            match method {
+                MatchMethod::SumOfSquaredDifferences => {
+                    scores[i] += t_squares - 2.0 * products[i] + squares[i];
+                }
+                MatchMethod::NormalizedSquaredDifferences => {
+                    scores[i] += t_squares - 2.0 * products[i] + squares[i];
+                    window_norms[i] += squares[i];
+                }
+                MatchMethod::CrossCorrelation => scores[i] += products[i],
+                MatchMethod::NormalizedCrossCorrelation => {
+                    numerators[i] += products[i] - t_sum * sums[i] / count;
+                    window_norms[i] += squares[i] - sums[i] * sums[i] / count;
+                }
+            }
+        }
+        template_norm += match method {
+            MatchMethod::NormalizedCrossCorrelation => t_squares - t_sum * t_sum / count,
+            _ => t_squares,
+        };
+    }
+
+    // denominators below the rounding errors of the sums are treated as zero
+    let epsilon = 1e-12 * (1.0 + template_norm);
+    for i in 0..out_width * out_height {
+        let denominator = (template_norm.max(0.0) * window_norms[i].max(0.0)).sqrt();
+        scores[i] = match method {
+            MatchMethod::SumOfSquaredDifferences => scores[i].max(0.0),
+            MatchMethod::CrossCorrelation => scores[i],
+            MatchMethod::NormalizedSquaredDifferences => {
+                let ssd = scores[i].max(0.0);
+                if denominator > epsilon {
+                    ssd / denominator
+                } else if ssd <= epsilon {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            MatchMethod::NormalizedCrossCorrelation => {
+                if denominator > epsilon {
+                    (numerators[i] / denominator).clamp(-1.0, 1.0)
+                } else {
+                    0.0
+                }
+            }
+        };
+    }
+    scores
+}
+
+/// Computes the sum of the products of the template and every window by sliding the template
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn correlate(
+    image: &[f64],
+    width: usize,
+    template: &[f64],
+    t_width: usize,
+    t_height: usize,
+    out_width: usize,
+    out_height: usize,
+) -> Vec<f64> {
+    let mut products = vec![0.0; out_width * out_height];
+    for y in 0..out_height {
+        for x in 0..out_width {
+            let mut sum = 0.0;
+            for ty in 0..t_height {
+                let row = &image[(y + ty) * width + x..][..t_width];
+                let t_row = &template[ty * t_width..][..t_width];
+                sum += row.iter().zip(t_row).map(|(a, b)| a * b).sum::<f64>();
+            }
+            products[y * out_width + x] = sum;
+        }
+    }
+    products
+}
+
+/// Computes the sum of the products of the template and every window in the frequency domain, i.e. the product of the transform of the image with the conjugate transform of the template
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn correlate_fft(
+    image: &[f64],
+    width: usize,
+    height: usize,
+    template: &[f64],
+    t_width: usize,
+    t_height: usize,
+) -> Vec<f64> {
+    // windows never wrap around as the padded size is at least the size of the image
+    let (p_width, p_height) = (width.next_power_of_two(), height.next_power_of_two());
+    let pad = |data: &[f64], w: usize, h: usize| {
+        let mut padded = vec![Complex::default(); p_width * p_height];
+        for y in 0..h {
+            for x in 0..w {
+                padded[y * p_width + x] = Complex::new(data[y * w + x], 0.0);
+            }
+        }
+        fft_2d(&mut padded, p_width, p_height, false);
+        padded
+    };
+
+    let mut spectrum = pad(image, width, height);
+    let t_spectrum = pad(template, t_width, t_height);
+    for (value, t_value) in spectrum.iter_mut().zip(t_spectrum) {
+        *value = *value * t_value.conj();
+    }
+    fft_2d(&mut spectrum, p_width, p_height, true);
+
+    let (out_width, out_height) = (width - t_width + 1, height - t_height + 1);
+    let mut products = Vec::with_capacity(out_width * out_height);
+    for y in 0..out_height {
+        products.extend(
+            spectrum[y * p_width..][..out_width]
+                .iter()
+                .map(|value| value.re),
+        );
+    }
+    products
+}
+
+/// Computes the sum of the values (or of their squares) of every window with an integral image
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn window_sums(
+    image: &[f64],
+    width: usize,
+    height: usize,
+    t_width: usize,
+    t_height: usize,
+    squared: bool,
+) -> Vec<f64> {
+    // integral image with a leading row and column of zeros
+    let stride = width + 1;
+    let mut integral = vec![0.0; stride * (height + 1)];
+    for y in 0..height {
+        let mut row_sum = 0.0;
+        for x in 0..width {
+            let value = image[y * width + x];
+            row_sum += if squared { value * value } else { value };
+            integral[(y + 1) * stride + x + 1] = integral[y * stride + x + 1] + row_sum;
+        }
+    }
+
+    let (out_width, out_height) = (width - t_width + 1, height - t_height + 1);
+    let mut sums = Vec::with_capacity(out_width * out_height);
+    for y in 0..out_height {
+        for x in 0..out_width {
+            let (x1, y1) = (x + t_width, y + t_height);
+            sums.push(
+                integral[y1 * stride + x1] - integral[y * stride + x1] - integral[y1 * stride + x]
+                    + integral[y * stride + x],
+            );
+        }
+    }
+    sums
+}
+
+#[cfg(test)]
+mod template_test {
+    use super::match_scores;
+    use crate::core::ImageData;
+    use crate::imgproc::MatchMethod;
+    use ndarray::Array2;
+
+    const METHODS: [MatchMethod; 4] = [
+        MatchMethod::SumOfSquaredDifferences,
+        MatchMethod::NormalizedSquaredDifferences,
+        MatchMethod::CrossCorrelation,
+        MatchMethod::NormalizedCrossCorrelation,
+    ];
+
+    // Computes the score of a window by definition
+    fn brute_force<const N: usize>(
+        img: &ImageData<u8, N>,
+        template: &ImageData<u8, N>,
+        method: MatchMethod,
+        (x, y): (usize, usize),
+    ) -> f64 {
+        let pairs: Vec<(f64, f64, usize)> = template
+            .pixels()
+            .indexed_iter()
+            .flat_map(|((ty, tx), t)| {
+                let px = img.pixels()[(y + ty, x + tx)];
+                (0..N).map(move |c| (t[c] as f64, px[c] as f64, c))
+            })
+            .collect();
+        let count = template.pixels().len() as f64;
+        let mean = |c: usize, image: bool| {
+            pairs
+                .iter()
+                .filter(|p| p.2 == c)
+                .map(|p| if image { p.1 } else { p.0 })
+                .sum::<f64>()
+                / count
+        };
+
+        match method {
+            MatchMethod::SumOfSquaredDifferences => {
+                pairs.iter().map(|(t, i, _)| (t - i).powi(2)).sum()
+            }
+            MatchMethod::NormalizedSquaredDifferences => {
+                let ssd: f64 = pairs.iter().map(|(t, i, _)| (t - i).powi(2)).sum();
+                let tt: f64 = pairs.iter().map(|(t, _, _)| t * t).sum();
+                let ii: f64 = pairs.iter().map(|(_, i, _)| i * i).sum();
+                ssd / (tt * ii).sqrt()
+            }
+            MatchMethod::CrossCorrelation => pairs.iter().map(|(t, i, _)| t * i).sum(),
+            MatchMethod::NormalizedCrossCorrelation => {
+                let centred: Vec<(f64, f64)> = pairs
+                    .iter()
+                    .map(|&(t, i, c)| (t - mean(c, false), i - mean(c, true)))
+                    .collect();
+                let num: f64 = centred.iter().map(|(t, i)| t * i).sum();
+                let tt: f64 = centred.iter().map(|(t, _)| t * t).sum();
+                let ii: f64 = centred.iter().map(|(_, i)| i * i).sum();
+                num / (tt * ii).sqrt()
+            }
+        }
+    }
+
+    // Scores match their definition, on both the direct and the frequency domain paths
+    #[test]
+    fn scores() {
+        let pixels = Array2::from_shape_fn((23, 31), |(y, x)| {
+            [
+                (x * 37 + y * 11) as u8,
+                (x * y % 200) as u8,
+                ((x ^ y) * 9) as u8,
+            ]
+        });
+        let img = ImageData::new(31, 23, pixels);
+        let template = img.crop((9, 5), (16, 10)).unwrap();
+
+        for method in METHODS {
+            let direct = match_scores(&img.view(), &template.view(), method, false);
+            let fft = match_scores(&img.view(), &template.view(), method, true);
+            let out_width = 31 - 7 + 1;
+            for (i, (a, b)) in direct.iter().zip(&fft).enumerate() {
+                let expected = brute_force(&img, &template, method, (i % out_width, i / out_width));
+                let tolerance = 1e-6 * (1.0 + expected.abs());
+                assert!(
+                    (a - expected).abs() < tolerance,
+                    "{method:?} {a} vs {expected}"
+                );
+                assert!(
+                    (b - expected).abs() < tolerance,
+                    "{method:?} {b} vs {expected}"
+                );
+            }
+
+            let scores = img.match_template(&template, method).unwrap();
+            assert_eq!((25, 19), (*scores.width(), *scores.height()));
+            let extrema = scores.min_max_loc().unwrap();
+            match method {
+                MatchMethod::CrossCorrelation => (),
+                MatchMethod::NormalizedCrossCorrelation => assert_eq!((9, 5), extrema.max_loc()),
+                _ => assert_eq!((9, 5), extrema.min_loc()),
+            }
+        }
+    }
+
+    // Flat windows, float images, extrema and invalid templates
+    #[test]
+    fn edge_cases() {
+        // brightness and contrast changes don't affect the correlation coefficient
+        let pixels = Array2::from_shape_fn((64, 64), |(y, x)| {
+            [((x * x * 7 + y * y * 3 + x * y) % 97) as f32 / 97.0]
+        });
+        let img = ImageData::new(64, 64, pixels);
+        let template = img.crop((40, 30), (60, 50)).unwrap();
+        let template = ImageData::new(20, 20, template.pixels().mapv(|[v]| [0.5 * v + 0.2]));
+        let scores = img
+            .match_template(&template, MatchMethod::NormalizedCrossCorrelation)
+            .unwrap();
+        let extrema = scores.min_max_loc().unwrap();
+        assert_eq!((40, 30), extrema.max_loc());
+        assert!((extrema.max() - 1.0).abs() < 1e-4);
+
+        // flat windows and templates
+        let flat = ImageData::new(8, 8, Array2::from_elem((8, 8), [3u8]));
+        let small = ImageData::new(3, 3, Array2::from_elem((3, 3), [3u8]));
+        let scores = flat
+            .match_template(&small, MatchMethod::NormalizedCrossCorrelation)
+            .unwrap();
+        assert!(scores.as_raw_slice().iter().all(|&score| score == 0.0));
+        let scores = flat
+            .match_template(&small, MatchMethod::NormalizedSquaredDifferences)
+            .unwrap();
+        assert!(scores.as_raw_slice().iter().all(|&score| score == 0.0));
+
+        let with_nan = ImageData::new(
+            2,
+            2,
+            Array2::from_shape_vec((2, 2), vec![[f32::NAN], [2.0], [-1.0], [2.0]]).unwrap(),
+        );
+        let extrema = with_nan.min_max_loc().unwrap();
+        assert_eq!((-1.0, (0, 1)), (extrema.min(), extrema.min_loc()));
+        assert_eq!((2.0, (1, 0)), (extrema.max(), extrema.max_loc()));
+
+        assert!(small
+            .match_template(&flat, MatchMethod::CrossCorrelation)
+            .is_err());
+        let empty = ImageData::new(0, 0, Array2::from_elem((0, 0), [0u8]));
+        assert!(flat
+            .match_template(&empty, MatchMethod::CrossCorrelation)
+            .is_err());
+        assert!(empty.min_max_loc().is_none());
+    }
+}
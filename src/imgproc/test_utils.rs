@@ -0,0 +1,18 @@
+use crate::core::ImageData;
+use ndarray::Array2;
+
+/// Builds a mask from rows of '#' (foreground) and '.' (background)
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn mask(rows: &[&str]) -> ImageData<u8, 1> {
+    let (width, height) = (rows[0].len(), rows.len());
+    let pixels = Array2::from_shape_fn((height, width), |(y, x)| {
+        [if rows[y].as_bytes()[x] == b'#' {
+            255
+        } else {
+            0
+        }]
+    });
+    ImageData::new(width as u32, height as u32, pixels)
+}
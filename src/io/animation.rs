@@ -0,0 +1,1070 @@
+use crate::{
+    core::{Image, ImageData, PixelDepth},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+
+use super::{options::limit_error, ReadOptions};
+use image::{codecs::webp::WebPDecoder, AnimationDecoder, DynamicImage, ImageDecoder, ImageFormat};
+use ndarray::{s, Array2, Zip};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    num::NonZeroU64,
+    path::Path,
+    time::Duration,
+};
+use tiff::{decoder::DecodingResult, encoder::colortype};
+
+/// `Disposal` describes what happens to the area of a frame before the next frame of an animation is rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Disposal {
+    /// Frame is left as it is and the next frame is rendered over it
+    #[default]
+    None,
+    /// Area of the frame is cleared (fully transparent) before rendering the next frame
+    Background,
+    /// Area of the frame is restored to what it was before the frame was rendered
+    Previous,
+}
+
+/// `Frame` holds a single frame of an animation (or a page of a multi-page image) along with the time it's displayed and its disposal method
+///
+/// Frames returned by `io::read_frames()` are fully rendered, i.e., each frame has the size of the whole animation. The disposal method is the one stored in the file for that frame
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use std::time::Duration;
+/// use vision_x::core::{Image, ImageData};
+/// use vision_x::io::{Disposal, Frame};
+///
+/// # fn main() {
+/// let img = Image::ImageRgb(ImageData::new(64, 64, Array2::from_elem((64, 64), [255u8, 0, 0])));
+/// let mut frame = Frame::new(img, Duration::from_millis(100));
+/// frame.set_disposal(Disposal::Background);
+/// assert_eq!(frame.delay(), Duration::from_millis(100));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Frame {
+    image: Image,
+    delay: Duration,
+    disposal: Disposal,
+}
+
+/// Core implementation of struct `Frame`
+impl Frame {
+    /// Creates a new frame displayed for `delay`, with no disposal
+    pub fn new(image: Image, delay: Duration) -> Self {
+        Frame {
+            image,
+            delay,
+            disposal: Disposal::None,
+        }
+    }
+
+    /// Get the image of the frame
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Consumes the frame and returns its image
+    pub fn into_image(self) -> Image {
+        self.image
+    }
+
+    /// Get the time the frame is displayed
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Get the disposal method of the frame
+    pub fn disposal(&self) -> Disposal {
+        self.disposal
+    }
+
+    /// Set the image of the frame
+    pub fn set_image(&mut self, image: Image) {
+        self.image = image;
+    }
+
+    /// Set the time the frame is displayed
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    /// Set the disposal method of the frame
+    pub fn set_disposal(&mut self, disposal: Disposal) {
+        self.disposal = disposal;
+    }
+}
+
+/// `Frames` is an iterator over the frames of an image file, returned by `io::read_frames()`
+///
+/// Frames are decoded one at a time while iterating
+pub struct Frames {
+    frames: Box<dyn Iterator<Item = VisionXResult<Frame>>>,
+}
+
+/// Implements `Iterator` for `Frames`
+impl Iterator for Frames {
+    type Item = VisionXResult<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.frames.next()?;
+        if frame.is_err() {
+            // a frame which fails to decode ends the iteration
+            self.frames = Box::new(std::iter::empty());
+        }
+        Some(frame)
+    }
+}
+
+/// Reads all the frames of an animated image (GIF, APNG and WebP) or all the pages of a multi-page TIFF. Returns an iterator over the frames
+///
+/// Animation frames are RGBA images (16bit for 16bit APNG) rendered onto the whole canvas, TIFF pages keep their color type and size. Any other (still) image is returned as a single frame with no delay
+///
+/// Frames are read within the default `ReadOptions` limits, use `io::read_frames_with_options()` for other limits
+///
+/// Returns `Err` if path/file is not found or if any error occurs while reading the file header. Errors while decoding a frame are returned by the iterator and end the iteration
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// for frame in io::read_frames("images/jpg/lenna.jpg")? {
+///     let frame = frame?;
+///     println!("{} frame shown for {:?}", frame.image().to_str(), frame.delay());
+/// }
+/// # Ok(()) }
+/// ```
+pub fn read_frames<P: AsRef<Path>>(path: P) -> VisionXResult<Frames> {
+    read_frames_with_options(path, &ReadOptions::default())
+}
+
+/// Reads all the frames of an animated image or all the pages of a multi-page TIFF within the limits of `ReadOptions`, see `io::read_frames()`
+///
+/// The size of the animation (or of every page) and the bytes allocated to render a frame are checked before decoding its pixels
+///
+/// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the image exceeds a limit, with `VisionXErrorKind::UnsupportedFormat` if the format is unknown or not allowed, or if any error occurs while reading the file header. Errors while decoding a frame (including exceeded limits) are returned by the iterator and end the iteration
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, ReadOptions};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let mut options = ReadOptions::new();
+/// options.set_max_alloc(Some(16 * 1024 * 1024));
+/// for frame in io::read_frames_with_options("images/jpg/lenna.jpg", &options)? {
+///     println!("{} frame", frame?.image().to_str());
+/// }
+/// # Ok(()) }
+/// ```
+pub fn read_frames_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &ReadOptions,
+) -> VisionXResult<Frames> {
+    let path = path.as_ref();
+    let frames: Box<dyn Iterator<Item = VisionXResult<Frame>>> = match options.check_format(path)? {
+        ImageFormat::Gif => Box::new(GifFrames::new(path, options)?),
+        ImageFormat::Png => match ApngFrames::new(path, options)? {
+            Some(apng_frames) => Box::new(apng_frames),
+            None => read_single_frame(path, options)?,
+        },
+        ImageFormat::WebP => read_webp_frames(path, options)?,
+        ImageFormat::Tiff => Box::new(TiffFrames::new(path, options)?),
+        _ => read_single_frame(path, options)?,
+    };
+
+    Ok(Frames { frames })
+}
+
+/// Writes a sequence of frames into an animated GIF or APNG (`.png`), or into a multi-page TIFF
+///
+/// `plays` is the number of times the animation is played, `0` plays it forever (ignored for TIFF). GIF frames are quantized to 8bit RGBA with a 10ms delay precision and APNG frames are written as 8bit RGBA (16bit if the first frame has a higher color depth). Every GIF/APNG frame must have the same size, TIFF pages are written with their own color type and size
+///
+/// Returns `Ok` if write is successful, else, returns `Err` if no frames are given, if the frames can't be encoded in the format, if the format doesn't support animation (e.g. WebP) or if any error occurs while write operation
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use std::time::Duration;
+/// use vision_x::core::{Image, ImageData};
+/// use vision_x::io::{self, Frame};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let frames: Vec<Frame> = (0..8u8)
+///     .map(|idx| {
+///         let pixels = Array2::from_elem((32, 32), [idx * 32, 0, 255 - idx * 32]);
+///         Frame::new(Image::ImageRgb(ImageData::new(32, 32, pixels)), Duration::from_millis(80))
+///     })
+///     .collect();
+/// io::write_animation("images/test/jade_preview-doctest.gif", &frames, 0)?;
+/// # Ok(()) }
+/// ```
+pub fn write_animation<P: AsRef<Path>>(path: P, frames: &[Frame], plays: u16) -> VisionXResult<()> {
+    let path = path.as_ref();
+    if frames.is_empty() {
+        return Err(VisionXErrorKind::InvalidSize(
+            "writing an animation without frames".to_string(),
+        )
+        .into());
+    }
+
+    match ImageFormat::from_path(path)? {
+        ImageFormat::Gif => write_gif(path, frames, plays),
+        ImageFormat::Png => write_apng(path, frames, plays),
+        ImageFormat::Tiff => write_tiff(path, frames),
+        format => Err(VisionXErrorKind::UnsupportedFormat(format!(
+            "writing an animation into {:?} file",
+            format
+        ))
+        .into()),
+    }
+}
+
+/// Reads a still image as a single frame
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn read_single_frame(
+    path: &Path,
+    options: &ReadOptions,
+) -> VisionXResult<Box<dyn Iterator<Item = VisionXResult<Frame>>>> {
+    let frame = Frame::new(super::read_with_options(path, options)?, Duration::ZERO);
+    Ok(Box::new(std::iter::once(Ok(frame))))
+}
+
+/// Reads the frames of an animated WebP. The image crate renders the frames onto the canvas, hence, disposal is always `Disposal::None`
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn read_webp_frames(
+    path: &Path,
+    options: &ReadOptions,
+) -> VisionXResult<Box<dyn Iterator<Item = VisionXResult<Frame>>>> {
+    let mut decoder = WebPDecoder::new(BufReader::new(File::open(path)?))?;
+    if !decoder.has_animation() {
+        return read_single_frame(path, options);
+    }
+    // frames are rendered onto an RGBA canvas
+    let (width, height) = decoder.dimensions();
+    options.check_size((width, height), rgba_bytes(width, height, 1))?;
+    decoder.set_limits(options.limits()).map_err(limit_error)?;
+
+    let frames = decoder.into_frames().map(|frame| -> VisionXResult<Frame> {
+        let frame = frame?;
+        let (numerator, denominator) = frame.delay().numer_denom_ms();
+        let delay = Duration::from_millis(u64::from(numerator) / u64::from(denominator.max(1)));
+        let rgba_img = ImageData::from(frame.into_buffer());
+
+        Ok(Frame::new(Image::ImageRgba(rgba_img), delay))
+    });
+    Ok(Box::new(frames))
+}
+
+/// Operation used to render a (sub-)frame onto the canvas
+///
+/// ## Note
+/// Enum is used internally (private scope)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Blend {
+    /// Pixels of the frame replace the pixels of the canvas
+    Source,
+    /// Pixels of the frame are alpha-blended over the pixels of the canvas
+    Over,
+}
+
+/// `Canvas` renders the (sub-)frames of an animation, following the blend and disposal method of each frame
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct Canvas<T> {
+    pixels: ImageData<T, 4>,
+    /// Disposal of the last rendered frame, with its area (x, y, width, height) and the canvas saved before rendering it
+    disposal: Option<(Disposal, [usize; 4], Option<ImageData<T, 4>>)>,
+}
+
+/// Core implementation of struct `Canvas`
+impl<T: PixelDepth> Canvas<T> {
+    /// Creates a fully transparent canvas
+    fn new(width: u32, height: u32) -> Self {
+        let pixels = Array2::from_elem((height as usize, width as usize), [T::default(); 4]);
+        Canvas {
+            pixels: ImageData::new(width, height, pixels),
+            disposal: None,
+        }
+    }
+
+    /// Disposes the last frame and renders a (sub-)frame at `(x, y)`. Returns the rendered canvas
+    ///
+    /// Parts of the frame outside the canvas are clipped
+    fn render(
+        &mut self,
+        frame: &ImageData<T, 4>,
+        (x, y): (u32, u32),
+        blend: Blend,
+        disposal: Disposal,
+    ) -> ImageData<T, 4> {
+        if let Some((last_disposal, [x, y, width, height], saved)) = self.disposal.take() {
+            match (last_disposal, saved) {
+                (Disposal::Background, _) => self
+                    .pixels
+                    .view_mut()
+                    .into_pixels()
+                    .slice_mut(s![y..y + height, x..x + width])
+                    .fill([T::default(); 4]),
+                (Disposal::Previous, Some(saved)) => self.pixels = saved,
+                _ => {}
+            }
+        }
+
+        let saved = (disposal == Disposal::Previous).then(|| self.pixels.clone());
+        let (x, y) = (
+            (x as usize).min(*self.pixels.width() as usize),
+            (y as usize).min(*self.pixels.height() as usize),
+        );
+        let width = (*frame.width() as usize).min(*self.pixels.width() as usize - x);
+        let height = (*frame.height() as usize).min(*self.pixels.height() as usize - y);
+
+        let mut canvas = self.pixels.view_mut().into_pixels();
+        Zip::from(canvas.slice_mut(s![y..y + height, x..x + width]))
+            .and(frame.pixels().slice(s![..height, ..width]))
+            .for_each(|dst, src| match blend {
+                Blend::Source => *dst = *src,
+                Blend::Over => *dst = blend_over(dst, src),
+            });
+
+        self.disposal = Some((disposal, [x, y, width, height], saved));
+        self.pixels.clone()
+    }
+}
+
+/// Returns the number of bytes of an RGBA image with samples of `sample_bytes` bytes
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn rgba_bytes(width: u32, height: u32, sample_bytes: u64) -> u64 {
+    u64::from(width) * u64::from(height) * 4 * sample_bytes
+}
+
+/// Alpha-blends a (non-premultiplied) RGBA pixel over another
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn blend_over<T: PixelDepth>(dst: &[T; 4], src: &[T; 4]) -> [T; 4] {
+    let src_alpha = src[3].to_normalized();
+    if src_alpha >= 1.0 {
+        return *src;
+    }
+    let dst_alpha = dst[3].to_normalized() * (1.0 - src_alpha);
+    let alpha = src_alpha + dst_alpha;
+    if alpha <= 0.0 {
+        return [T::default(); 4];
+    }
+
+    let mut pixel = [T::from_normalized(alpha); 4];
+    for (channel, value) in pixel.iter_mut().take(3).enumerate() {
+        let color =
+            src[channel].to_normalized() * src_alpha + dst[channel].to_normalized() * dst_alpha;
+        *value = T::from_normalized(color / alpha);
+    }
+    pixel
+}
+
+/// Iterator over the frames of a GIF
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct GifFrames {
+    decoder: gif::Decoder<BufReader<File>>,
+    canvas: Canvas<u8>,
+}
+
+/// Core implementation of struct `GifFrames`
+impl GifFrames {
+    /// Reads the header of a GIF and checks the size of its canvas
+    fn new(path: &Path, options: &ReadOptions) -> VisionXResult<Self> {
+        let mut gif_options = gif::DecodeOptions::new();
+        gif_options.set_color_output(gif::ColorOutput::RGBA);
+        if let Some(max_alloc) = options.max_alloc().and_then(NonZeroU64::new) {
+            gif_options.set_memory_limit(gif::MemoryLimit::Bytes(max_alloc));
+        }
+        let decoder = gif_options.read_info(BufReader::new(File::open(path)?))?;
+
+        let (width, height) = (u32::from(decoder.width()), u32::from(decoder.height()));
+        options.check_size((width, height), rgba_bytes(width, height, 1))?;
+        let canvas = Canvas::new(width, height);
+
+        Ok(GifFrames { decoder, canvas })
+    }
+
+    /// Decodes and renders the next frame
+    fn next_frame(&mut self) -> VisionXResult<Option<Frame>> {
+        let Some(gif_frame) = self.decoder.read_next_frame()? else {
+            return Ok(None);
+        };
+
+        let disposal = match gif_frame.dispose {
+            gif::DisposalMethod::Background => Disposal::Background,
+            gif::DisposalMethod::Previous => Disposal::Previous,
+            _ => Disposal::None,
+        };
+        let sub_frame = ImageData::from_raw_vec(
+            u32::from(gif_frame.width),
+            u32::from(gif_frame.height),
+            gif_frame.buffer.to_vec(),
+        )?;
+        let position = (u32::from(gif_frame.left), u32::from(gif_frame.top));
+        // delay is stored in units of 10ms
+        let delay = Duration::from_millis(u64::from(gif_frame.delay) * 10);
+
+        let rgba_img = self
+            .canvas
+            .render(&sub_frame, position, Blend::Over, disposal);
+        let mut frame = Frame::new(Image::ImageRgba(rgba_img), delay);
+        frame.set_disposal(disposal);
+        Ok(Some(frame))
+    }
+}
+
+/// Implements `Iterator` for `GifFrames`
+impl Iterator for GifFrames {
+    type Item = VisionXResult<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+/// Canvas of an APNG, 8bit or 16bit as per the bit depth of the file
+///
+/// ## Note
+/// Enum is used internally (private scope)
+enum ApngCanvas {
+    Rgba(Canvas<u8>),
+    Rgba16(Canvas<u16>),
+}
+
+/// Iterator over the frames of an APNG
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct ApngFrames {
+    reader: png::Reader<BufReader<File>>,
+    canvas: ApngCanvas,
+    buffer: Vec<u8>,
+    remaining_frames: u32,
+}
+
+/// Core implementation of struct `ApngFrames`
+impl ApngFrames {
+    /// Reads the header of a PNG and checks the size of its canvas. Returns `None` if the PNG isn't animated
+    fn new(path: &Path, options: &ReadOptions) -> VisionXResult<Option<Self>> {
+        let limits = png::Limits {
+            bytes: options.max_buffer_size(),
+        };
+        let mut decoder = png::Decoder::new_with_limits(BufReader::new(File::open(path)?), limits);
+        // outputs either grayscale + alpha or RGBA samples of 8bit or 16bit
+        decoder.set_transformations(png::Transformations::ALPHA);
+        let mut reader = decoder.read_info()?;
+
+        let Some(animation_control) = reader.info().animation_control() else {
+            return Ok(None);
+        };
+        let remaining_frames = animation_control.num_frames;
+        // default image isn't a part of the animation when there's no frame control before it
+        if reader.info().frame_control().is_none() && remaining_frames > 0 {
+            reader.next_frame_info()?;
+        }
+
+        let (width, height) = reader.info().size();
+        let sixteen = reader.output_color_type().1 == png::BitDepth::Sixteen;
+        let buffer_size = reader.output_buffer_size().ok_or_else(|| {
+            VisionXErrorKind::InsufficientBufferSize("allocating apng frame buffer".to_string())
+        })?;
+        let canvas_bytes = rgba_bytes(width, height, if sixteen { 2 } else { 1 });
+        options.check_size((width, height), canvas_bytes + buffer_size as u64)?;
+        let canvas = match sixteen {
+            true => ApngCanvas::Rgba16(Canvas::new(width, height)),
+            false => ApngCanvas::Rgba(Canvas::new(width, height)),
+        };
+
+        Ok(Some(ApngFrames {
+            reader,
+            canvas,
+            buffer: vec![0; buffer_size],
+            remaining_frames,
+        }))
+    }
+
+    /// Decodes and renders the next frame
+    fn next_frame(&mut self) -> VisionXResult<Option<Frame>> {
+        if self.remaining_frames == 0 {
+            return Ok(None);
+        }
+        self.remaining_frames -= 1;
+
+        let output_info = self.reader.next_frame(&mut self.buffer)?;
+        let frame_control = self
+            .reader
+            .info()
+            .frame_control()
+            .copied()
+            .unwrap_or_default();
+        let disposal = match frame_control.dispose_op {
+            png::DisposeOp::None => Disposal::None,
+            png::DisposeOp::Background => Disposal::Background,
+            png::DisposeOp::Previous => Disposal::Previous,
+        };
+        let blend = match frame_control.blend_op {
+            png::BlendOp::Source => Blend::Source,
+            png::BlendOp::Over => Blend::Over,
+        };
+        let position = (frame_control.x_offset, frame_control.y_offset);
+        // denominator of 0 is treated as 100 (1/100th of a second)
+        let denominator = match frame_control.delay_den {
+            0 => 100,
+            value => u64::from(value),
+        };
+        let delay = Duration::from_millis(u64::from(frame_control.delay_num) * 1000 / denominator);
+
+        let (width, height) = (output_info.width, output_info.height);
+        let channels = output_info.color_type.samples();
+        let samples = &self.buffer[..output_info.line_size * height as usize];
+        let img = match &mut self.canvas {
+            ApngCanvas::Rgba(canvas) => {
+                let sub_frame =
+                    ImageData::from_raw_vec(width, height, expand_to_rgba(samples, channels))?;
+                Image::ImageRgba(canvas.render(&sub_frame, position, blend, disposal))
+            }
+            ApngCanvas::Rgba16(canvas) => {
+                // 16bit samples are stored in big endian order
+                let samples: Vec<u16> = samples
+                    .chunks_exact(2)
+                    .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+                    .collect();
+                let sub_frame =
+                    ImageData::from_raw_vec(width, height, expand_to_rgba(&samples, channels))?;
+                Image::ImageRgba16(canvas.render(&sub_frame, position, blend, disposal))
+            }
+        };
+
+        let mut frame = Frame::new(img, delay);
+        frame.set_disposal(disposal);
+        Ok(Some(frame))
+    }
+}
+
+/// Implements `Iterator` for `ApngFrames`
+impl Iterator for ApngFrames {
+    type Item = VisionXResult<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+/// Expands grayscale + alpha samples into RGBA samples, RGBA samples are copied as it is
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn expand_to_rgba<T: Copy>(samples: &[T], channels: usize) -> Vec<T> {
+    match channels {
+        2 => samples
+            .chunks_exact(2)
+            .flat_map(|px| [px[0], px[0], px[0], px[1]])
+            .collect(),
+        _ => samples.to_vec(),
+    }
+}
+
+/// Iterator over the pages of a TIFF
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct TiffFrames {
+    decoder: tiff::decoder::Decoder<BufReader<File>>,
+    options: ReadOptions,
+    done: bool,
+}
+
+/// Core implementation of struct `TiffFrames`
+impl TiffFrames {
+    /// Reads the header of a TIFF
+    fn new(path: &Path, options: &ReadOptions) -> VisionXResult<Self> {
+        let mut limits = tiff::decoder::Limits::default();
+        limits.decoding_buffer_size = options.max_buffer_size();
+        let decoder =
+            tiff::decoder::Decoder::new(BufReader::new(File::open(path)?))?.with_limits(limits);
+        Ok(TiffFrames {
+            decoder,
+            options: options.clone(),
+            done: false,
+        })
+    }
+
+    /// Decodes the next page
+    fn next_frame(&mut self) -> VisionXResult<Option<Frame>> {
+        if self.done {
+            return Ok(None);
+        }
+        // a page which fails to decode ends the iteration
+        self.done = true;
+
+        let (width, height) = self.decoder.dimensions()?;
+        let color_type = self.decoder.colortype()?;
+        // every page is checked before decoding it, unsupported color types are rejected below
+        let samples = match color_type {
+            tiff::ColorType::Gray(bits) => u64::from(bits),
+            tiff::ColorType::GrayA(bits) => 2 * u64::from(bits),
+            tiff::ColorType::RGB(bits) => 3 * u64::from(bits),
+            tiff::ColorType::RGBA(bits) => 4 * u64::from(bits),
+            _ => 0,
+        };
+        let bytes = u64::from(width) * u64::from(height) * samples / 8;
+        self.options.check_size((width, height), bytes)?;
+
+        let img = match (color_type, self.decoder.read_image()?) {
+            (tiff::ColorType::Gray(8), DecodingResult::U8(buffer)) => {
+                Image::ImageGrayscale(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::Gray(16), DecodingResult::U16(buffer)) => {
+                Image::ImageGrayscale16(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::Gray(32), DecodingResult::F32(buffer)) => {
+                Image::ImageGrayscale32F(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::GrayA(8), DecodingResult::U8(buffer)) => {
+                Image::ImageGrayscaleAlpha(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::GrayA(16), DecodingResult::U16(buffer)) => {
+                Image::ImageGrayscaleAlpha16(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::RGB(8), DecodingResult::U8(buffer)) => {
+                Image::ImageRgb(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::RGB(16), DecodingResult::U16(buffer)) => {
+                Image::ImageRgb16(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::RGB(32), DecodingResult::F32(buffer)) => {
+                Image::ImageRgb32F(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::RGBA(8), DecodingResult::U8(buffer)) => {
+                Image::ImageRgba(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::RGBA(16), DecodingResult::U16(buffer)) => {
+                Image::ImageRgba16(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (tiff::ColorType::RGBA(32), DecodingResult::F32(buffer)) => {
+                Image::ImageRgba32F(ImageData::from_raw_vec(width, height, buffer)?)
+            }
+            (color_type, _) => {
+                return Err(VisionXErrorKind::InvalidColorType(format!(
+                    "reading {:?} tiff page",
+                    color_type
+                ))
+                .into())
+            }
+        };
+
+        if self.decoder.more_images() {
+            self.decoder.next_image()?;
+            self.done = false;
+        }
+        Ok(Some(Frame::new(img, Duration::ZERO)))
+    }
+}
+
+/// Implements `Iterator` for `TiffFrames`
+impl Iterator for TiffFrames {
+    type Item = VisionXResult<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+/// Converts the images of the frames into 8bit or 16bit RGBA buffers and checks that every frame has the same size
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn rgba_frames<T, F>(
+    frames: &[Frame],
+    convert: F,
+) -> VisionXResult<(u32, u32, Vec<ImageData<T, 4>>)>
+where
+    T: Default + Copy,
+    F: Fn(DynamicImage) -> ImageData<T, 4>,
+{
+    let rgba_imgs = frames
+        .iter()
+        .map(|frame| Ok(convert(DynamicImage::try_from(frame.image().clone())?)))
+        .collect::<VisionXResult<Vec<ImageData<T, 4>>>>()?;
+
+    let (width, height) = (*rgba_imgs[0].width(), *rgba_imgs[0].height());
+    if let Some(rgba_img) = rgba_imgs
+        .iter()
+        .find(|img| (*img.width(), *img.height()) != (width, height))
+    {
+        return Err(VisionXError::SizeMismatch {
+            expected: (width, height),
+            actual: (*rgba_img.width(), *rgba_img.height()),
+        });
+    }
+
+    Ok((width, height, rgba_imgs))
+}
+
+/// Encodes the frames into an animated GIF
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn write_gif(path: &Path, frames: &[Frame], plays: u16) -> VisionXResult<()> {
+    let (width, height, rgba_imgs) = rgba_frames(frames, |img| ImageData::from(img.into_rgba8()))?;
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(VisionXErrorKind::InvalidSize(format!(
+            "writing gif of size ({}, {}), max size is ({}, {})",
+            width,
+            height,
+            u16::MAX,
+            u16::MAX
+        ))
+        .into());
+    };
+
+    let mut encoder = gif::Encoder::new(BufWriter::new(File::create(path)?), width, height, &[])?;
+    match plays {
+        0 => encoder.set_repeat(gif::Repeat::Infinite)?,
+        1 => {}
+        plays => encoder.set_repeat(gif::Repeat::Finite(plays - 1))?,
+    }
+
+    for (frame, mut rgba_img) in frames.iter().zip(rgba_imgs) {
+        let mut gif_frame =
+            gif::Frame::from_rgba_speed(width, height, rgba_img.as_raw_slice_mut(), 10);
+        // delay is stored in units of 10ms
+        gif_frame.delay = u16::try_from(frame.delay().as_millis() / 10).unwrap_or(u16::MAX);
+        gif_frame.dispose = match frame.disposal() {
+            Disposal::None => gif::DisposalMethod::Keep,
+            Disposal::Background => gif::DisposalMethod::Background,
+            Disposal::Previous => gif::DisposalMethod::Previous,
+        };
+        encoder.write_frame(&gif_frame)?;
+    }
+
+    Ok(())
+}
+
+/// Encodes the frames into an APNG
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn write_apng(path: &Path, frames: &[Frame], plays: u16) -> VisionXResult<()> {
+    let is_8bit = matches!(
+        frames[0].image(),
+        Image::ImageGrayscale(_)
+            | Image::ImageGrayscaleAlpha(_)
+            | Image::ImageRgb(_)
+            | Image::ImageRgba(_)
+    );
+    let (width, height, buffers): (u32, u32, Vec<Vec<u8>>) = if is_8bit {
+        let (width, height, rgba_imgs) =
+            rgba_frames(frames, |img| ImageData::from(img.into_rgba8()))?;
+        (
+            width,
+            height,
+            rgba_imgs.into_iter().map(ImageData::into_raw_vec).collect(),
+        )
+    } else {
+        let (width, height, rgba_imgs) =
+            rgba_frames(frames, |img| ImageData::from(img.into_rgba16()))?;
+        let buffers = rgba_imgs
+            .iter()
+            .map(|img| {
+                img.as_raw_slice()
+                    .iter()
+                    .flat_map(|value| value.to_be_bytes())
+                    .collect()
+            })
+            .collect();
+        (width, height, buffers)
+    };
+
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(if is_8bit {
+        png::BitDepth::Eight
+    } else {
+        png::BitDepth::Sixteen
+    });
+    encoder.set_animated(frames.len() as u32, u32::from(plays))?;
+
+    let mut writer = encoder.write_header()?;
+    for (frame, buffer) in frames.iter().zip(buffers) {
+        let delay = u16::try_from(frame.delay().as_millis()).unwrap_or(u16::MAX);
+        writer.set_frame_delay(delay, 1000)?;
+        writer.set_dispose_op(match frame.disposal() {
+            Disposal::None => png::DisposeOp::None,
+            Disposal::Background => png::DisposeOp::Background,
+            Disposal::Previous => png::DisposeOp::Previous,
+        })?;
+        writer.write_image_data(&buffer)?;
+    }
+    writer.finish()?;
+
+    Ok(())
+}
+
+/// Encodes every frame as a page of a TIFF
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn write_tiff(path: &Path, frames: &[Frame]) -> VisionXResult<()> {
+    let mut encoder = tiff::encoder::TiffEncoder::new(BufWriter::new(File::create(path)?))?;
+    for frame in frames {
+        match frame.image() {
+            Image::ImageGrayscale(img) => encoder.write_image::<colortype::Gray8>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageGrayscale16(img) => encoder.write_image::<colortype::Gray16>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageGrayscale32F(img) => encoder.write_image::<colortype::Gray32Float>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageRgb(img) => encoder.write_image::<colortype::RGB8>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageRgb16(img) => encoder.write_image::<colortype::RGB16>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageRgb32F(img) => encoder.write_image::<colortype::RGB32Float>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageRgba(img) => encoder.write_image::<colortype::RGBA8>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageRgba16(img) => encoder.write_image::<colortype::RGBA16>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            Image::ImageRgba32F(img) => encoder.write_image::<colortype::RGBA32Float>(
+                *img.width(),
+                *img.height(),
+                img.as_raw_slice(),
+            )?,
+            value => {
+                return Err(VisionXErrorKind::InvalidColorType(format!(
+                    "writing {} image as tiff page",
+                    value.to_str()
+                ))
+                .into())
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod animation_test {
+    use crate::core::{Image, ImageData};
+    use crate::errors::VisionXError;
+    use crate::io::{self, Disposal, Frame};
+    use ndarray::Array2;
+    use std::time::Duration;
+
+    fn rgba_frame(width: usize, height: usize, px: [u8; 4], delay: u64) -> Frame {
+        let pixels = Array2::from_elem((height, width), px);
+        let img = Image::ImageRgba(ImageData::new(width as u32, height as u32, pixels));
+        Frame::new(img, Duration::from_millis(delay))
+    }
+
+    fn first_pixel(frame: &Frame) -> [u8; 4] {
+        match frame.image() {
+            Image::ImageRgba(rgba) => *rgba.get_pixel_at(0, 0).unwrap(),
+            value => panic!("unexpected {} frame", value.to_str()),
+        }
+    }
+
+    // Frames written into a GIF are read back with their delay and disposal
+    #[test]
+    fn gif_roundtrip() {
+        let mut frames = vec![
+            rgba_frame(16, 8, [255, 0, 0, 255], 100),
+            rgba_frame(16, 8, [0, 255, 0, 255], 50),
+            rgba_frame(16, 8, [0, 0, 255, 255], 20),
+        ];
+        frames[1].set_disposal(Disposal::Background);
+
+        let path = "images/test/jade_frames.gif";
+        assert!(io::write_animation(path, &frames, 0).is_ok());
+
+        let read_frames: Vec<Frame> = io::read_frames(path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read_frames.len(), 3);
+        for (frame, read_frame) in frames.iter().zip(&read_frames) {
+            assert_eq!(first_pixel(frame), first_pixel(read_frame));
+            assert_eq!(frame.delay(), read_frame.delay());
+            assert_eq!(frame.disposal(), read_frame.disposal());
+        }
+    }
+
+    // Sub-frames of a GIF are rendered onto the canvas as per their disposal method
+    #[test]
+    fn gif_disposal() {
+        let path = "images/test/jade_disposal.gif";
+        {
+            let file = std::fs::File::create(path).unwrap();
+            let mut encoder = gif::Encoder::new(file, 4, 4, &[]).unwrap();
+            let mut write_frame = |px: [u8; 4], size: u16, pos: u16, dispose| {
+                let mut pixels = px.repeat(size as usize * size as usize);
+                let mut frame = gif::Frame::from_rgba(size, size, &mut pixels);
+                (frame.left, frame.top, frame.dispose) = (pos, pos, dispose);
+                encoder.write_frame(&frame).unwrap();
+            };
+            write_frame([255, 0, 0, 255], 4, 0, gif::DisposalMethod::Keep);
+            write_frame([0, 255, 0, 255], 2, 2, gif::DisposalMethod::Previous);
+            write_frame([0, 0, 255, 255], 2, 0, gif::DisposalMethod::Background);
+            write_frame([255, 255, 255, 255], 1, 3, gif::DisposalMethod::Keep);
+        }
+
+        let frames: Vec<Image> = io::read_frames(path)
+            .unwrap()
+            .map(|frame| frame.unwrap().into_image())
+            .collect();
+        let pixel = |idx: usize, x: usize, y: usize| match &frames[idx] {
+            Image::ImageRgba(rgba) => *rgba.get_pixel_at(x, y).unwrap(),
+            value => panic!("unexpected {} frame", value.to_str()),
+        };
+
+        assert_eq!(frames.len(), 4);
+        assert_eq!(pixel(1, 0, 0), [255, 0, 0, 255]);
+        assert_eq!(pixel(1, 3, 3), [0, 255, 0, 255]);
+        // green sub-frame is restored to red, blue sub-frame is drawn at the top left
+        assert_eq!(pixel(2, 3, 3), [255, 0, 0, 255]);
+        assert_eq!(pixel(2, 1, 1), [0, 0, 255, 255]);
+        // blue sub-frame is cleared
+        assert_eq!(pixel(3, 1, 1), [0, 0, 0, 0]);
+        assert_eq!(pixel(3, 2, 2), [255, 0, 0, 255]);
+        assert_eq!(pixel(3, 3, 3), [255, 255, 255, 255]);
+    }
+
+    // 16bit frames are written into an APNG without losing the color depth
+    #[test]
+    fn apng_roundtrip() {
+        let frames: Vec<Frame> = [1000u16, 30000, 65535]
+            .iter()
+            .map(|&value| {
+                let pixels = Array2::from_elem((4, 6), [value, 0, 65535 - value]);
+                Frame::new(
+                    Image::ImageRgb16(ImageData::new(6, 4, pixels)),
+                    Duration::from_millis(40),
+                )
+            })
+            .collect();
+
+        let path = "images/test/jade_frames.png";
+        assert!(io::write_animation(path, &frames, 2).is_ok());
+
+        let read_frames: Vec<Frame> = io::read_frames(path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read_frames.len(), 3);
+        for (value, frame) in [1000u16, 30000, 65535].iter().zip(&read_frames) {
+            assert_eq!(frame.delay(), Duration::from_millis(40));
+            match frame.image() {
+                Image::ImageRgba16(rgba16) => {
+                    assert_eq!(
+                        rgba16.get_pixel_at(5, 3),
+                        Some(&[*value, 0, 65535 - value, 65535])
+                    )
+                }
+                value => panic!("unexpected {} frame", value.to_str()),
+            }
+        }
+    }
+
+    // Pages of a TIFF keep their own color type and size
+    #[test]
+    fn tiff_pages() {
+        let frames = vec![
+            Frame::new(
+                Image::ImageGrayscale(ImageData::new(8, 4, Array2::from_elem((4, 8), [7u8]))),
+                Duration::ZERO,
+            ),
+            Frame::new(
+                Image::ImageRgb16(ImageData::new(
+                    3,
+                    5,
+                    Array2::from_elem((5, 3), [1u16, 2, 3]),
+                )),
+                Duration::ZERO,
+            ),
+        ];
+
+        let path = "images/test/jade_pages.tiff";
+        assert!(io::write_animation(path, &frames, 0).is_ok());
+
+        let pages: Vec<Image> = io::read_frames(path)
+            .unwrap()
+            .map(|frame| frame.unwrap().into_image())
+            .collect();
+        assert_eq!(pages.len(), 2);
+        match (&pages[0], &pages[1]) {
+            (Image::ImageGrayscale(gray), Image::ImageRgb16(rgb16)) => {
+                assert_eq!((*gray.width(), *gray.height()), (8, 4));
+                assert_eq!(rgb16.get_pixel_at(2, 4), Some(&[1, 2, 3]));
+            }
+            value => panic!("unexpected {} pages", value.0.to_str()),
+        }
+    }
+
+    // Still images are read as a single frame and invalid animations return `Err`
+    #[test]
+    fn single_frame_and_errors() {
+        let frames: Vec<Frame> = io::read_frames("images/jpg/lenna.jpg")
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].delay(), Duration::ZERO);
+
+        let frames = vec![rgba_frame(4, 4, [0; 4], 10), rgba_frame(4, 5, [0; 4], 10)];
+        match io::write_animation("images/test/jade_size.gif", &frames, 0) {
+            Err(VisionXError::SizeMismatch { expected, actual }) => {
+                assert_eq!(((4, 4), (4, 5)), (expected, actual))
+            }
+            _ => panic!("expected a size mismatch"),
+        }
+        assert!(io::write_animation("images/test/jade_empty.gif", &[], 0).is_err());
+        assert!(io::write_animation("images/test/jade_frames.webp", &frames[..1], 0).is_err());
+    }
+}
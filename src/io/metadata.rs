@@ -0,0 +1,371 @@
+use crate::{
+    core::Image,
+    errors::{VisionXErrorKind, VisionXResult},
+};
+
+use image::{
+    codecs::{jpeg::JpegEncoder, png::PngEncoder},
+    metadata::Orientation,
+    DynamicImage, ImageDecoder, ImageEncoder, ImageFormat, ImageReader,
+};
+use std::path::Path;
+
+/// Identifier of an XMP packet stored inside a JPEG APP1 segment
+const JPEG_XMP_HEADER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+/// Keyword of an XMP packet stored inside a PNG iTXt chunk
+const PNG_XMP_KEYWORD: &[u8] = b"XML:com.adobe.xmp";
+
+/// `Metadata` holds the metadata of an image file: EXIF, XMP, ICC profile and the orientation (read from the EXIF orientation tag)
+///
+/// Metadata is returned alongside the image by `io::read_with_metadata()` and can be embedded back using `io::write_with_metadata()`
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let (img, metadata) = io::read_with_metadata("images/jpg/lenna.jpg", true)?;
+/// if let Some(icc_profile) = metadata.icc_profile() {
+///     println!("embedded ICC profile of {} bytes", icc_profile.len());
+/// }
+/// io::write_with_metadata("images/test/jade_lenna-metadata.jpg", &img, &metadata)?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metadata {
+    exif: Option<Vec<u8>>,
+    xmp: Option<Vec<u8>>,
+    icc_profile: Option<Vec<u8>>,
+    orientation: Orientation,
+}
+
+/// Creates an empty `Metadata` object
+impl Default for Metadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Core implementation of struct `Metadata`
+impl Metadata {
+    /// Creates an empty `Metadata` object (no EXIF, XMP or ICC profile and no orientation)
+    pub fn new() -> Self {
+        Self {
+            exif: None,
+            xmp: None,
+            icc_profile: None,
+            orientation: Orientation::NoTransforms,
+        }
+    }
+
+    // Getters
+
+    /// Returns the raw EXIF chunk (TIFF structure, without the `Exif\0\0` prefix) if present
+    pub fn exif(&self) -> Option<&[u8]> {
+        self.exif.as_deref()
+    }
+
+    /// Returns the raw XMP packet if present
+    pub fn xmp(&self) -> Option<&[u8]> {
+        self.xmp.as_deref()
+    }
+
+    /// Returns the raw embedded ICC profile if present
+    pub fn icc_profile(&self) -> Option<&[u8]> {
+        self.icc_profile.as_deref()
+    }
+
+    /// Returns the orientation of the image stored in the EXIF chunk. `Orientation::NoTransforms` if absent or already applied while reading
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    // Setters
+
+    /// Set the raw EXIF chunk (TIFF structure, without the `Exif\0\0` prefix). The orientation is read from the chunk
+    pub fn set_exif(&mut self, exif: Option<Vec<u8>>) {
+        self.orientation = exif
+            .as_deref()
+            .and_then(Orientation::from_exif_chunk)
+            .unwrap_or(Orientation::NoTransforms);
+        self.exif = exif;
+    }
+
+    /// Set the raw XMP packet
+    pub fn set_xmp(&mut self, xmp: Option<Vec<u8>>) {
+        self.xmp = xmp;
+    }
+
+    /// Set the raw ICC profile
+    pub fn set_icc_profile(&mut self, icc_profile: Option<Vec<u8>>) {
+        self.icc_profile = icc_profile;
+    }
+}
+
+/// Reads an image file along with its metadata (EXIF, XMP and ICC profile). Returns a result with a tuple of `Image` and `Metadata`
+///
+/// If `apply_orientation` is `true`, the image is rotated/flipped as per the EXIF orientation tag (e.g. photos taken by a phone) and the tag is reset in the returned EXIF chunk, so that the image isn't rotated twice when written back
+///
+/// Returns `Err` if path/file is not found or if any error occurs while read operation
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let path = "images/jpg/lenna.jpg";
+/// let (img, metadata) = io::read_with_metadata(path, true)?;
+/// assert!(metadata.exif().is_none());
+/// # Ok(()) }
+/// ```
+pub fn read_with_metadata<P: AsRef<Path>>(
+    path: P,
+    apply_orientation: bool,
+) -> VisionXResult<(Image, Metadata)> {
+    let mut decoder = ImageReader::open(path)?
+        .with_guessed_format()?
+        .into_decoder()?;
+
+    let mut metadata = Metadata::new();
+    metadata.set_exif(decoder.exif_metadata()?);
+    metadata.set_xmp(decoder.xmp_metadata()?);
+    metadata.set_icc_profile(decoder.icc_profile()?);
+    // some formats (e.g. TIFF) store the orientation outside of the EXIF chunk
+    metadata.orientation = decoder.orientation()?;
+
+    let mut raw_image = DynamicImage::from_decoder(decoder)?;
+    if apply_orientation {
+        raw_image.apply_orientation(metadata.orientation);
+        if let Some(exif) = metadata.exif.as_mut() {
+            // returns `None` if the chunk has no (valid) orientation tag, nothing to reset then
+            let _ = Orientation::remove_from_exif_chunk(exif);
+        }
+        metadata.orientation = Orientation::NoTransforms;
+    }
+
+    Ok((Image::try_from(raw_image)?, metadata))
+}
+
+/// Writes an image into a file and embeds the metadata (EXIF, XMP and ICC profile)
+///
+/// Metadata is embedded only for JPEG and PNG files, any other format is written without metadata (see `io::write()`)
+///
+/// Returns `Ok` if write is successful, else, returns `Err` if path/file is not found or if any error occurs while write operation
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let (img, metadata) = io::read_with_metadata("images/jpg/cat.jpg", false)?;
+/// let grayscale_img = img.grayscale();
+/// io::write_with_metadata("images/test/jade_cat-metadata.png", &grayscale_img, &metadata)?;
+/// # Ok(()) }
+/// ```
+pub fn write_with_metadata<P: AsRef<Path>>(
+    path: P,
+    img: &Image,
+    metadata: &Metadata,
+) -> VisionXResult<()> {
+    let path = path.as_ref();
+    let mut bytes: Vec<u8> = Vec::new();
+    match ImageFormat::from_path(path)? {
+        ImageFormat::Jpeg => {
+            let mut encoder = JpegEncoder::new(&mut bytes);
+            set_encoder_metadata(&mut encoder, metadata)?;
+            super::encode(img, encoder)?;
+
+            if let Some(xmp) = metadata.xmp() {
+                insert_jpeg_xmp(&mut bytes, xmp)?;
+            }
+        }
+        ImageFormat::Png => {
+            let mut encoder = PngEncoder::new(&mut bytes);
+            set_encoder_metadata(&mut encoder, metadata)?;
+            super::encode(img, encoder)?;
+
+            if let Some(xmp) = metadata.xmp() {
+                insert_png_xmp(&mut bytes, xmp)?;
+            }
+        }
+        _ => return super::write(path, img),
+    }
+
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+/// Passes EXIF and ICC profile to the encoder
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn set_encoder_metadata<E: ImageEncoder>(
+    encoder: &mut E,
+    metadata: &Metadata,
+) -> VisionXResult<()> {
+    if let Some(icc_profile) = metadata.icc_profile() {
+        encoder
+            .set_icc_profile(icc_profile.to_vec())
+            .map_err(image::ImageError::Unsupported)?;
+    }
+    if let Some(exif) = metadata.exif() {
+        encoder
+            .set_exif_metadata(exif.to_vec())
+            .map_err(image::ImageError::Unsupported)?;
+    }
+
+    Ok(())
+}
+
+/// Inserts an XMP packet as an APP1 segment into an encoded JPEG stream (right after the SOI marker and the JFIF segment)
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn insert_jpeg_xmp(bytes: &mut Vec<u8>, xmp: &[u8]) -> VisionXResult<()> {
+    // segment length includes the two bytes of the length field
+    let length = 2 + JPEG_XMP_HEADER.len() + xmp.len();
+    if length > u16::MAX as usize {
+        let err = format!(
+            "embedding XMP packet of {} bytes into a JPEG segment",
+            xmp.len()
+        );
+        return Err(VisionXErrorKind::InsufficientBufferSize(err).into());
+    }
+
+    let mut offset: usize = 2;
+    if bytes.len() >= 6 && bytes[2..4] == [0xFF, 0xE0] {
+        offset = 4 + u16::from_be_bytes([bytes[4], bytes[5]]) as usize;
+    }
+
+    let mut segment: Vec<u8> = Vec::with_capacity(length + 2);
+    segment.extend_from_slice(&[0xFF, 0xE1]);
+    segment.extend_from_slice(&(length as u16).to_be_bytes());
+    segment.extend_from_slice(JPEG_XMP_HEADER);
+    segment.extend_from_slice(xmp);
+    bytes.splice(offset..offset, segment);
+
+    Ok(())
+}
+
+/// Inserts an XMP packet as an (uncompressed) iTXt chunk into an encoded PNG stream (right before the first IDAT chunk, as decoders read metadata only from the header)
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn insert_png_xmp(bytes: &mut Vec<u8>, xmp: &[u8]) -> VisionXResult<()> {
+    // every chunk: length (4 bytes), type (4 bytes), data and CRC (4 bytes)
+    let mut offset: usize = 8;
+    while offset + 8 <= bytes.len() && &bytes[offset + 4..offset + 8] != b"IDAT" {
+        let length = u32::from_be_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ]);
+        offset += 12 + length as usize;
+    }
+    if offset + 8 > bytes.len() {
+        return Err(VisionXErrorKind::InsufficientBufferSize(
+            "locating IDAT chunk while embedding XMP packet".to_string(),
+        )
+        .into());
+    }
+
+    // iTXt: keyword, null separator, compression flag, compression method, empty language tag and translated keyword
+    let mut data: Vec<u8> = Vec::with_capacity(PNG_XMP_KEYWORD.len() + 5 + xmp.len());
+    data.extend_from_slice(PNG_XMP_KEYWORD);
+    data.extend_from_slice(&[0, 0, 0, 0, 0]);
+    data.extend_from_slice(xmp);
+
+    let mut chunk: Vec<u8> = Vec::with_capacity(data.len() + 12);
+    chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(b"iTXt");
+    chunk.extend_from_slice(&data);
+    chunk.extend_from_slice(&crc32(&chunk[4..]).to_be_bytes());
+    bytes.splice(offset..offset, chunk);
+
+    Ok(())
+}
+
+/// Computes CRC-32 (ISO-HDLC) checksum used by PNG chunks and native files
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    crc.update(data);
+    crc.sum()
+}
+
+#[cfg(test)]
+mod metadata_test {
+    use crate::core::Image;
+    use crate::io::{self, Metadata};
+    use image::metadata::Orientation;
+
+    // Big endian EXIF chunk with a single IFD entry: orientation = 6 (rotate 90 degrees clockwise)
+    const EXIF_ROTATE_90: [u8; 26] = [
+        b'M', b'M', 0, 42, 0, 0, 0, 8, 0, 1, 0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0,
+    ];
+    const XMP: &[u8] = b"<x:xmpmeta xmlns:x=\"adobe:ns:meta/\"></x:xmpmeta>";
+
+    fn dimensions(img: &Image) -> (u32, u32) {
+        match img {
+            Image::ImageRgb(rgb) => (*rgb.width(), *rgb.height()),
+            Image::ImageGrayscale(grayscale) => (*grayscale.width(), *grayscale.height()),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // Write metadata into a file and read it back with and without applying the orientation
+    fn roundtrip(path: &str) {
+        // non-square image, so that rotation is visible in its dimension
+        let img: Image = match io::read("images/jpg/cat.jpg").unwrap() {
+            Image::ImageRgb(rgb) => Image::ImageRgb(rgb.resize(64, 32)),
+            value => panic!("unexpected {} image", value.to_str()),
+        };
+        let (width, height) = dimensions(&img);
+
+        let mut metadata = Metadata::new();
+        metadata.set_exif(Some(EXIF_ROTATE_90.to_vec()));
+        metadata.set_xmp(Some(XMP.to_vec()));
+        assert_eq!(metadata.orientation(), Orientation::Rotate90);
+        assert!(io::write_with_metadata(path, &img, &metadata).is_ok());
+
+        let (raw_img, raw_metadata) = io::read_with_metadata(path, false).unwrap();
+        assert_eq!(dimensions(&raw_img), (width, height));
+        assert_eq!(raw_metadata.orientation(), Orientation::Rotate90);
+        assert_eq!(raw_metadata.exif(), Some(&EXIF_ROTATE_90[..]));
+        assert_eq!(raw_metadata.xmp(), Some(XMP));
+
+        let (rotated_img, rotated_metadata) = io::read_with_metadata(path, true).unwrap();
+        assert_eq!(dimensions(&rotated_img), (height, width));
+        assert_eq!(rotated_metadata.orientation(), Orientation::NoTransforms);
+        let exif = rotated_metadata.exif().unwrap();
+        assert_eq!(
+            Orientation::from_exif_chunk(exif),
+            Some(Orientation::NoTransforms)
+        );
+    }
+
+    #[test]
+    fn jpeg_roundtrip() {
+        roundtrip("images/test/jade_metadata.jpg");
+    }
+
+    #[test]
+    fn png_roundtrip() {
+        roundtrip("images/test/jade_metadata.png");
+    }
+
+    // Known CRC-32 value used by every PNG file
+    #[test]
+    fn crc32() {
+        assert_eq!(super::crc32(b"IEND"), 0xAE42_6082);
+    }
+}
@@ -0,0 +1,468 @@
+use crate::{
+    core::{Image, ImageData},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+
+use image::{
+    EncodableLayout, ImageBuffer, ImageEncoder, Luma, LumaA, PixelWithColorType, Rgb, Rgba,
+};
+use std::path::Path;
+
+/// Reads and writes animated (multi-frame) images
+mod animation;
+
+/// Reads and writes image metadata (EXIF, XMP and ICC profile)
+mod metadata;
+
+/// Saves and loads image data losslessly in the native (vision-x) format
+mod native;
+
+/// Reads the properties of an image file without decoding its pixels
+mod probe;
+
+/// Reads images within limits (size, allocation and format) for untrusted inputs
+mod options;
+
+/// Reads and writes PNM (PBM, PGM, PPM), PAM and PFM files natively
+mod pnm;
+
+/// Converts raw YUV frames (NV12, I420, YUYV and UYVY) from and into images
+mod raw;
+
+/// Converts pixels between ICC color profiles (color management)
+mod profile;
+
+/// Reads images scaled down to a maximum size
+mod scaled;
+
+/// Reads and writes very large images tile by tile in bounded memory
+mod tiled;
+
+pub use animation::{
+    read_frames, read_frames_with_options, write_animation, Disposal, Frame, Frames,
+};
+pub use metadata::{read_with_metadata, write_with_metadata, Metadata};
+pub use native::{load_native, load_native_with_options, save_native, Compression, NativeElement};
+pub use options::{read_with_options, ReadOptions};
+pub use pnm::{
+    read_pam, read_pnm, read_pnm_with_options, write_pam, write_pnm, PnmEncoding, PnmSample,
+};
+pub use probe::{probe, ImageInfo};
+pub use profile::{convert_profile, read_with_profile, ColorProfile};
+pub use raw::{read_raw, read_raw_with_options, write_raw, PixelFormat, YuvColorSpace};
+pub use scaled::{read_scaled, read_scaled_with_options};
+pub use tiled::{Tile, TiledReader, TiledWriter};
+
+/// Reads an image file. Returns a result with an `Image` enum containing raw data like pixels in `ImageData` struct wrapped inside the enum
+///
+/// Returns `Err` if path/file is not found or if any error occurs while read operation
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let path = "images/jpg/lenna.jpg";
+/// let img = io::read(path)?;
+/// # Ok(()) }
+/// ```
+pub fn read<P: AsRef<Path>>(path: P) -> VisionXResult<Image> {
+    // the decoded buffer is moved into `ImageData` (see `TryFrom<DynamicImage> for Image`)
+    let raw_image = image::open(path)?;
+    Image::try_from(raw_image)
+}
+
+/// Writes a bufferstream into a image file.
+///
+/// Returns `Ok` if write is successful, else, returns `Err` if path/file is not found or if any error occurs while write operation
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::{Image, ImageData};
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let width: usize = 128;
+/// let height: usize = 128;
+/// let mut pixels = Array2::from_elem((height, width), [0u8; 1]);
+/// for y in 0..height {
+///     for x in 0..width {
+///         if x < width / 2 {
+///             pixels[(x, y)] = [255; 1];
+///         } else {
+///             pixels[(x, y)] = [0; 1];
+///         }
+///     }
+/// }
+///
+/// let new_path = "images/test/jade_write-doctest.jpg";
+/// let img = Image::ImageGrayscale(ImageData::new(width as u32, height as u32, pixels));
+/// io::write(new_path, &img)?;
+/// # Ok(()) }
+/// ```
+pub fn write<P: AsRef<Path>>(path: P, img: &Image) -> VisionXResult<()> {
+    match img {
+        Image::ImageGrayscale(gray_img) => write_buffer::<Luma<u8>, _, 1>(path, gray_img),
+        Image::ImageGrayscaleAlpha(gray_alpha_img) => {
+            write_buffer::<LumaA<u8>, _, 2>(path, gray_alpha_img)
+        }
+        Image::ImageRgb(rgb_img) => write_buffer::<Rgb<u8>, _, 3>(path, rgb_img),
+        Image::ImageRgba(rgba_img) => write_buffer::<Rgba<u8>, _, 4>(path, rgba_img),
+        Image::ImageGrayscale16(gray16_img) => write_buffer::<Luma<u16>, _, 1>(path, gray16_img),
+        Image::ImageGrayscaleAlpha16(gray_alpha16_img) => {
+            write_buffer::<LumaA<u16>, _, 2>(path, gray_alpha16_img)
+        }
+        Image::ImageRgb16(rgb16_img) => write_buffer::<Rgb<u16>, _, 3>(path, rgb16_img),
+        Image::ImageRgba16(rgba16_img) => write_buffer::<Rgba<u16>, _, 4>(path, rgba16_img),
+        Image::ImageRgb32F(rgb32f_img) => write_buffer::<Rgb<f32>, _, 3>(path, rgb32f_img),
+        Image::ImageRgba32F(rgba32f_img) => write_buffer::<Rgba<f32>, _, 4>(path, rgba32f_img),
+        value => Err(VisionXErrorKind::InvalidColorType(format!(
+            "writing {} image to file",
+            value.to_str()
+        ))
+        .into()),
+    }
+}
+
+/// Encodes the pixels of an image into a file. The pixels are borrowed by the encoder without any copy
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn write_buffer<Px, P, const N: usize>(
+    path: P,
+    img: &ImageData<Px::Subpixel, N>,
+) -> VisionXResult<()>
+where
+    Px: PixelWithColorType,
+    Px::Subpixel: Default,
+    [Px::Subpixel]: EncodableLayout,
+    P: AsRef<Path>,
+{
+    let buffer_option: Option<ImageBuffer<Px, &[Px::Subpixel]>> =
+        ImageBuffer::from_raw(*img.width(), *img.height(), img.as_raw_slice());
+
+    match buffer_option {
+        Some(buffer) => Ok(buffer.save(path)?),
+        None => Err(buffer_size_error::<Px, N>(img)),
+    }
+}
+
+/// Encodes an image using the given encoder. The pixels are borrowed by the encoder without any copy
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn encode<E: ImageEncoder>(img: &Image, encoder: E) -> VisionXResult<()> {
+    match img {
+        Image::ImageGrayscale(gray_img) => encode_buffer::<Luma<u8>, _, 1>(gray_img, encoder),
+        Image::ImageGrayscaleAlpha(gray_alpha_img) => {
+            encode_buffer::<LumaA<u8>, _, 2>(gray_alpha_img, encoder)
+        }
+        Image::ImageRgb(rgb_img) => encode_buffer::<Rgb<u8>, _, 3>(rgb_img, encoder),
+        Image::ImageRgba(rgba_img) => encode_buffer::<Rgba<u8>, _, 4>(rgba_img, encoder),
+        Image::ImageGrayscale16(gray16_img) => {
+            encode_buffer::<Luma<u16>, _, 1>(gray16_img, encoder)
+        }
+        Image::ImageGrayscaleAlpha16(gray_alpha16_img) => {
+            encode_buffer::<LumaA<u16>, _, 2>(gray_alpha16_img, encoder)
+        }
+        Image::ImageRgb16(rgb16_img) => encode_buffer::<Rgb<u16>, _, 3>(rgb16_img, encoder),
+        Image::ImageRgba16(rgba16_img) => encode_buffer::<Rgba<u16>, _, 4>(rgba16_img, encoder),
+        Image::ImageRgb32F(rgb32f_img) => encode_buffer::<Rgb<f32>, _, 3>(rgb32f_img, encoder),
+        Image::ImageRgba32F(rgba32f_img) => encode_buffer::<Rgba<f32>, _, 4>(rgba32f_img, encoder),
+        value => Err(VisionXErrorKind::InvalidColorType(format!(
+            "encoding {} image",
+            value.to_str()
+        ))
+        .into()),
+    }
+}
+
+/// Encodes the pixels of an image using the given encoder
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_buffer<Px, E, const N: usize>(
+    img: &ImageData<Px::Subpixel, N>,
+    encoder: E,
+) -> VisionXResult<()>
+where
+    Px: PixelWithColorType,
+    Px::Subpixel: Default,
+    [Px::Subpixel]: EncodableLayout,
+    E: ImageEncoder,
+{
+    let buffer_option: Option<ImageBuffer<Px, &[Px::Subpixel]>> =
+        ImageBuffer::from_raw(*img.width(), *img.height(), img.as_raw_slice());
+
+    match buffer_option {
+        Some(buffer) => Ok(buffer.write_with_encoder(encoder)?),
+        None => Err(buffer_size_error::<Px, N>(img)),
+    }
+}
+
+/// Returns the error for pixels which don't fill an image buffer of the image size
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn buffer_size_error<Px, const N: usize>(img: &ImageData<Px::Subpixel, N>) -> VisionXError
+where
+    Px: PixelWithColorType,
+    Px::Subpixel: Default,
+{
+    VisionXError::BufferSize {
+        expected: *img.width() as usize * *img.height() as usize * Px::CHANNEL_COUNT as usize,
+        actual: img.as_raw_slice().len(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unnecessary_unwrap, clippy::needless_range_loop)]
+mod read_image_test {
+
+    // Read grayscale8bit image
+    #[test]
+    fn grayscale() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/basn0g01.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        if let Image::ImageGrayscale(grayscale) = raw_img {
+            for x in 0..*grayscale.width() as usize {
+                let pixel_opt: Option<&[u8; 1]> = grayscale.get_pixel_at(x, 0);
+                if pixel_opt.is_some() {
+                    if x == (*grayscale.width() - 1) as usize {
+                        assert_eq!(pixel_opt.unwrap(), &[0u8]);
+                    } else {
+                        assert_eq!(pixel_opt.unwrap(), &[255u8]);
+                    }
+                }
+            }
+
+            for y in 0..*grayscale.height() as usize {
+                let pixel_opt: Option<&[u8; 1]> = grayscale.get_pixel_at(15, y);
+                if pixel_opt.is_some() {
+                    if y >= (*grayscale.height() / 2) as usize {
+                        assert_eq!(pixel_opt.unwrap(), &[0u8]);
+                    } else {
+                        assert_eq!(pixel_opt.unwrap(), &[255u8]);
+                    }
+                }
+            }
+        }
+    }
+
+    // Read grayscale + alpha channel 8bit image
+    #[test]
+    fn grayscale_alpha() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/basn4a08.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        if let Image::ImageGrayscaleAlpha(grayscale_alpha) = raw_img {
+            let vec: [u8; 32] = [
+                0, 8, 16, 24, 32, 41, 49, 57, 65, 74, 82, 90, 98, 106, 115, 123, 131, 139, 148,
+                156, 164, 172, 180, 189, 197, 205, 213, 222, 230, 238, 246, 255,
+            ];
+
+            for x in 0..*grayscale_alpha.width() as usize {
+                let pixel_opt: Option<&[u8; 2]> =
+                    grayscale_alpha.get_pixel_at(x, (*grayscale_alpha.height() - 1) as usize);
+                if pixel_opt.is_some() {
+                    assert_eq!(pixel_opt.unwrap(), &[0u8, vec[x]]);
+                }
+            }
+        }
+    }
+
+    // Read rgb8bit image
+    #[test]
+    fn rgb() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/scenery.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        if let Image::ImageRgb(rgb) = raw_img {
+            let vec: [[u8; 3]; 10] = [
+                [82, 38, 37], // 960, 1480
+                [74, 34, 35], // 961, 1482
+                [62, 33, 35], // 962, 1484
+                [63, 42, 49], // 963, 1486
+                [40, 43, 50], // 964, 1488
+                [25, 28, 37], // 965, 1490
+                [36, 40, 52], // 966, 1492
+                [37, 43, 57], // 967, 1494
+                [34, 41, 57], // 968, 1496
+                [32, 39, 58], // 969, 1498
+            ];
+            for x in 960..970 {
+                let i = x - 960;
+                let pixel_opt: Option<&[u8; 3]> = rgb.get_pixel_at(x, 1480 + (i * 2));
+                if pixel_opt.is_some() {
+                    assert_eq!(pixel_opt.unwrap(), &vec[i]);
+                }
+            }
+        }
+    }
+
+    // Read rgba8bit image
+    #[test]
+    fn rgba() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/cat.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        if let Image::ImageRgba(rgba) = raw_img {
+            let vec: [[u8; 4]; 10] = [
+                [130, 64, 20, 255], // 1364, 444
+                [127, 62, 22, 255], // 1365, 446
+                [130, 64, 22, 255], // 1366, 448
+                [129, 62, 19, 255], // 1367, 450
+                [127, 64, 26, 255], // 1368, 452
+                [130, 66, 24, 255], // 1369, 454
+                [133, 70, 34, 255], // 1370, 456
+                [144, 80, 42, 255], // 1371, 458
+                [148, 86, 41, 255], // 1372, 460
+                [136, 84, 47, 255], // 1373, 462
+            ];
+            for x in 1364..1374 {
+                let i = x - 1364;
+                let pixel_opt: Option<&[u8; 4]> = rgba.get_pixel_at(x, 444 + (i * 2));
+                if pixel_opt.is_some() {
+                    assert_eq!(pixel_opt.unwrap(), &vec[i]);
+                }
+            }
+
+            let pixel_opt: Option<&[u8; 4]> = rgba.get_pixel_at(21, 23);
+            if pixel_opt.is_some() {
+                assert_eq!(pixel_opt.unwrap(), &[255, 255, 255, 0]);
+            }
+        }
+    }
+}
+
+//
+#[cfg(test)]
+mod write_image_test {
+
+    // Write a grayscale8bit image
+    // File format is changed while writing new image
+    #[test]
+    fn grayscale() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/basn0g01.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        let path: &str = "images/test/jade_basn0g01.jpg";
+        let res = io::write(path, &raw_img);
+        assert!(res.is_ok());
+    }
+
+    // Write a grayscale + alpha 8bit image
+    #[test]
+    fn grayscale_alpha() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/basn4a08.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        let path: &str = "images/test/jade_basn4a08.png";
+        let res = io::write(path, &raw_img);
+        assert!(res.is_ok());
+    }
+
+    // Write a rgb8bit image
+    // File format is changed while writing new image
+    // TODO: check why .tiff is slow and doesn't generate/write
+    #[test]
+    fn rgb() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/jpg/cat.jpg";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        let path: &str = "images/test/jade_catboy2.png";
+        let res = io::write(path, &raw_img);
+        assert!(res.is_ok());
+
+        let path: &str = "images/test/jade_catboy2.bmp";
+        let res = io::write(path, &raw_img);
+        assert!(res.is_ok());
+    }
+
+    // Write a rgba8bit image
+    #[test]
+    fn rgba() {
+        use crate::core::Image;
+        use crate::errors::VisionXResult;
+        use crate::io;
+
+        let path: &str = "images/png/cat.png";
+        let res: VisionXResult<Image> = io::read(path);
+        assert!(res.is_ok());
+
+        let raw_img: Image = res.unwrap();
+        let path: &str = "images/test/jade_catboy.png";
+        let res = io::write(path, &raw_img);
+        assert!(res.is_ok());
+    }
+
+    // Write a grayscale16bit image and read it back
+    // Pixel values must be preserved byte to byte
+    #[test]
+    fn grayscale16_roundtrip() {
+        use crate::core::Image;
+        use crate::io;
+
+        let path: &str = "images/png/basn0g16.png";
+        let raw_img: Image = io::read(path).unwrap();
+
+        let path: &str = "images/test/jade_basn0g16.png";
+        assert!(io::write(path, &raw_img).is_ok());
+
+        let new_img: Image = io::read(path).unwrap();
+        match (raw_img, new_img) {
+            (Image::ImageGrayscale16(raw_img), Image::ImageGrayscale16(new_img)) => {
+                assert_eq!(raw_img.pixels(), new_img.pixels());
+            }
+            _ => panic!("expected grayscale16 images"),
+        }
+    }
+}
@@ -0,0 +1,503 @@
+use crate::{
+    core::ImageData,
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+    io::{metadata::crc32, Metadata, ReadOptions},
+};
+
+use flate2::{read::ZlibDecoder, write::ZlibEncoder};
+use ruzstd::{
+    decoding::StreamingDecoder,
+    encoding::{compress_to_vec, CompressionLevel},
+};
+use std::{
+    io::{Read, Write},
+    path::Path,
+};
+
+/// Identifier at the start of every native file
+const NATIVE_MAGIC: &[u8; 4] = b"VSNX";
+/// Latest version of the native format, files of a newer version can't be loaded
+const NATIVE_VERSION: u8 = 1;
+/// Length of an absent metadata chunk, chunks are shorter
+const ABSENT_CHUNK: u32 = u32::MAX;
+
+/// `Compression` represents how the pixels of a native file are compressed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Pixels are stored as it is, fastest to save and load
+    #[default]
+    None,
+    /// Pixels are compressed with deflate (zlib)
+    Deflate,
+    /// Pixels are compressed with Zstandard, faster than deflate for a similar size
+    Zstd,
+}
+
+/// Core implementation for enum `Compression`
+impl Compression {
+    /// Returns the identifier of the compression stored in a native file
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn id(&self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Deflate => 1,
+            Compression::Zstd => 2,
+        }
+    }
+}
+
+/// `NativeElement` is implemented for the pixel element types which can be saved into a native file (see `io::save_native()`)
+///
+/// Elements are stored in little endian order
+pub trait NativeElement: Default + Copy {
+    /// Identifier of the element type stored in a native file
+    const TYPE_ID: u8;
+    /// Size of the element in bytes
+    const SIZE: usize;
+
+    /// Appends the element in little endian order
+    fn extend_le_bytes(self, bytes: &mut Vec<u8>);
+
+    /// Reads the element from `SIZE` bytes in little endian order
+    fn from_le_slice(bytes: &[u8]) -> Self;
+}
+
+/// Implements `NativeElement` for primitive types
+macro_rules! impl_native_element {
+    ($($element:ty => $type_id:literal,)*) => {
+        $(
+            impl NativeElement for $element {
+                const TYPE_ID: u8 = $type_id;
+                const SIZE: usize = std::mem::size_of::<$element>();
+
+                fn extend_le_bytes(self, bytes: &mut Vec<u8>) {
+                    bytes.extend(self.to_le_bytes());
+                }
+
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    let mut le_bytes = [0u8; std::mem::size_of::<$element>()];
+                    le_bytes.copy_from_slice(bytes);
+                    <$element>::from_le_bytes(le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_native_element! {
+    u8 => 0,
+    i8 => 1,
+    u16 => 2,
+    i16 => 3,
+    u32 => 4,
+    i32 => 5,
+    f32 => 6,
+    f64 => 7,
+}
+
+/// Saves any `ImageData` losslessly into a versioned native (vision-x) file, along with its metadata
+///
+/// The file stores the width, height, channel count, element type, metadata (EXIF, XMP and ICC profile), a CRC32 checksum and the (optionally compressed) pixels
+///
+/// Returns `Ok` if save is successful, else, returns `Err` if the pixels don't match the width and height, or if any error occurs while write operation
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::ImageData;
+/// use vision_x::io::{self, Compression, Metadata};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let hsv = ImageData::new(64, 32, Array2::from_elem((32, 64), [120.0f32, 0.5, 0.75]));
+/// io::save_native("images/test/jade_hsv-doctest.vsnx", &hsv, &Metadata::new(), Compression::Zstd)?;
+///
+/// let (loaded, _) = io::load_native::<f32, 3, _>("images/test/jade_hsv-doctest.vsnx")?;
+/// assert_eq!(loaded.pixels(), hsv.pixels());
+/// # Ok(()) }
+/// ```
+pub fn save_native<P, T, const N: usize>(
+    path: P,
+    img: &ImageData<T, N>,
+    metadata: &Metadata,
+    compression: Compression,
+) -> VisionXResult<()>
+where
+    P: AsRef<Path>,
+    T: NativeElement,
+{
+    let (width, height) = (*img.width(), *img.height());
+    if img.pixels().dim() != (height as usize, width as usize) {
+        return Err(VisionXErrorKind::InvalidSize(format!(
+            "saving image data of size ({}, {}) with pixels of shape {:?}",
+            width,
+            height,
+            img.pixels().dim()
+        ))
+        .into());
+    }
+
+    let mut payload: Vec<u8> = Vec::with_capacity(img.as_raw_slice().len() * T::SIZE);
+    img.as_raw_slice()
+        .iter()
+        .for_each(|value| value.extend_le_bytes(&mut payload));
+    let checksum = crc32(&payload);
+    let payload = match compression {
+        Compression::None => payload,
+        Compression::Deflate => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(&payload)?;
+            encoder.finish()?
+        }
+        Compression::Zstd => compress_to_vec(payload.as_slice(), CompressionLevel::Fastest),
+    };
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(payload.len() + 64);
+    bytes.extend(NATIVE_MAGIC);
+    bytes.extend([NATIVE_VERSION, T::TYPE_ID, compression.id(), 0]);
+    bytes.extend((N as u32).to_le_bytes());
+    bytes.extend(width.to_le_bytes());
+    bytes.extend(height.to_le_bytes());
+    for chunk in [metadata.exif(), metadata.xmp(), metadata.icc_profile()] {
+        match chunk {
+            Some(chunk) => {
+                bytes.extend(chunk_len(chunk.len())?.to_le_bytes());
+                bytes.extend(chunk);
+            }
+            None => bytes.extend(ABSENT_CHUNK.to_le_bytes()),
+        }
+    }
+    bytes.extend(checksum.to_le_bytes());
+    bytes.extend((payload.len() as u64).to_le_bytes());
+    bytes.extend(payload);
+
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+/// Loads an `ImageData` along with its metadata from a native (vision-x) file (see `io::save_native()`)
+///
+/// The image data is loaded within the default `ReadOptions` limits, use `io::load_native_with_options()` for other limits
+///
+/// Returns `Err` if path/file is not found, if the element type or channel count of the file doesn't match `T` and `N`, if the file was saved by a newer version, or if the file is corrupted (checksum mismatch)
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::ImageData;
+/// use vision_x::io::{self, Compression, Metadata};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let labels = ImageData::new(16, 16, Array2::from_shape_fn((16, 16), |(y, x)| [(y / 4 * 4 + x / 4) as u32]));
+/// io::save_native("images/test/jade_labels-doctest.vsnx", &labels, &Metadata::new(), Compression::Deflate)?;
+///
+/// let (loaded, _) = io::load_native::<u32, 1, _>("images/test/jade_labels-doctest.vsnx")?;
+/// assert_eq!(loaded.get_pixel_at(15, 15), Some(&[15]));
+/// assert!(io::load_native::<u8, 1, _>("images/test/jade_labels-doctest.vsnx").is_err());
+/// # Ok(()) }
+/// ```
+pub fn load_native<T, const N: usize, P>(path: P) -> VisionXResult<(ImageData<T, N>, Metadata)>
+where
+    T: NativeElement,
+    P: AsRef<Path>,
+{
+    load_native_with_options(path, &ReadOptions::default())
+}
+
+/// Loads an `ImageData` along with its metadata from a native (vision-x) file within the limits of `ReadOptions`, see `io::load_native()`. The allowed formats aren't checked, native files having no `ImageFormat`
+///
+/// The size of the image data and the bytes of its elements are checked from the header before decompressing them
+///
+/// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the image data exceeds a limit, or if any error occurs as for `io::load_native()`
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::ImageData;
+/// use vision_x::io::{self, Compression, Metadata, ReadOptions};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let img = ImageData::new(64, 64, Array2::from_elem((64, 64), [0u8; 3]));
+/// io::save_native("images/test/jade_options-doctest.vsnx", &img, &Metadata::new(), Compression::Zstd)?;
+///
+/// let mut options = ReadOptions::new();
+/// options.set_max_width(Some(32));
+/// assert!(io::load_native_with_options::<u8, 3, _>("images/test/jade_options-doctest.vsnx", &options).is_err());
+/// # Ok(()) }
+/// ```
+pub fn load_native_with_options<T, const N: usize, P>(
+    path: P,
+    options: &ReadOptions,
+) -> VisionXResult<(ImageData<T, N>, Metadata)>
+where
+    T: NativeElement,
+    P: AsRef<Path>,
+{
+    let bytes = std::fs::read(path)?;
+    let mut reader = NativeReader {
+        bytes: &bytes,
+        pos: 0,
+    };
+
+    if reader.take(4)? != NATIVE_MAGIC {
+        return Err(invalid_data(
+            "loading native file, magic number doesn't match",
+        ));
+    }
+    let [version, type_id, compression_id, _] = reader.array::<4>()?;
+    if version > NATIVE_VERSION {
+        return Err(VisionXErrorKind::UnsupportedFormat(format!(
+            "loading native file of version {}, latest supported version is {}",
+            version, NATIVE_VERSION
+        ))
+        .into());
+    }
+    if type_id != T::TYPE_ID {
+        return Err(VisionXErrorKind::InvalidImageDepthSize(format!(
+            "loading native file with element type {} into image data with element type {}",
+            type_id,
+            T::TYPE_ID
+        ))
+        .into());
+    }
+
+    let channels = u32::from_le_bytes(reader.array()?);
+    if channels as usize != N {
+        return Err(VisionXErrorKind::InvalidColorType(format!(
+            "loading native file with {} channels into image data with {} channels",
+            channels, N
+        ))
+        .into());
+    }
+    let width = u32::from_le_bytes(reader.array()?);
+    let height = u32::from_le_bytes(reader.array()?);
+
+    let mut metadata = Metadata::new();
+    metadata.set_exif(reader.chunk()?);
+    metadata.set_xmp(reader.chunk()?);
+    metadata.set_icc_profile(reader.chunk()?);
+
+    let checksum = u32::from_le_bytes(reader.array()?);
+    let payload_len = usize::try_from(u64::from_le_bytes(reader.array()?))
+        .map_err(|_| invalid_data("loading native file, payload is too large"))?;
+    let payload = reader.take(payload_len)?;
+
+    let len = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|len| len.checked_mul(N * T::SIZE))
+        .ok_or_else(|| invalid_data("loading native file, image is too large"))?;
+    options.check_size((width, height), len as u64)?;
+    // decompressed data is limited to the expected length
+    let limit = len as u64 + 1;
+    let mut buffer: Vec<u8> = Vec::new();
+    match compression_id {
+        0 => buffer.extend(payload),
+        1 => {
+            ZlibDecoder::new(payload)
+                .take(limit)
+                .read_to_end(&mut buffer)?;
+        }
+        2 => {
+            StreamingDecoder::new(payload)?
+                .take(limit)
+                .read_to_end(&mut buffer)?;
+        }
+        id => {
+            return Err(VisionXErrorKind::UnsupportedFormat(format!(
+                "loading native file with compression {}",
+                id
+            ))
+            .into())
+        }
+    }
+
+    if buffer.len() != len {
+        return Err(invalid_data(&format!(
+            "loading native file, expected {} bytes of pixels but found {}",
+            len,
+            buffer.len()
+        )));
+    }
+    if crc32(&buffer) != checksum {
+        return Err(invalid_data("loading native file, checksum doesn't match"));
+    }
+
+    let values: Vec<T> = buffer.chunks_exact(T::SIZE).map(T::from_le_slice).collect();
+    Ok((ImageData::from_raw_vec(width, height, values)?, metadata))
+}
+
+/// Returns the length of a metadata chunk as stored in a native file
+///
+/// Returns `Err` if the length doesn't fit in the 32bit field or if it matches the length of an absent chunk
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn chunk_len(len: usize) -> VisionXResult<u32> {
+    match u32::try_from(len) {
+        Ok(len) if len != ABSENT_CHUNK => Ok(len),
+        _ => Err(VisionXErrorKind::InvalidSize(format!(
+            "saving metadata chunk of {} bytes into native file, chunks are limited to {} bytes",
+            len,
+            ABSENT_CHUNK - 1
+        ))
+        .into()),
+    }
+}
+
+/// Returns an `InvalidData` error
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn invalid_data(err: &str) -> VisionXError {
+    VisionXErrorKind::InvalidData(err.to_string()).into()
+}
+
+/// `NativeReader` reads the fields of a native file from bytes
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct NativeReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+/// Core implementation of struct `NativeReader`
+impl<'a> NativeReader<'a> {
+    /// Reads the next `len` bytes
+    fn take(&mut self, len: usize) -> VisionXResult<&'a [u8]> {
+        let bytes = self
+            .bytes
+            .get(self.pos..)
+            .and_then(|bytes| bytes.get(..len))
+            .ok_or_else(|| invalid_data("loading native file, file is truncated"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Reads the next `L` bytes into an array
+    fn array<const L: usize>(&mut self) -> VisionXResult<[u8; L]> {
+        let mut array = [0u8; L];
+        array.copy_from_slice(self.take(L)?);
+        Ok(array)
+    }
+
+    /// Reads a length prefixed metadata chunk
+    fn chunk(&mut self) -> VisionXResult<Option<Vec<u8>>> {
+        match u32::from_le_bytes(self.array()?) {
+            ABSENT_CHUNK => Ok(None),
+            len => Ok(Some(self.take(len as usize)?.to_vec())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod native_test {
+    use crate::core::ImageData;
+    use crate::io::{self, Compression, Metadata, ReadOptions};
+    use ndarray::Array2;
+
+    // Image data of every element type is saved and loaded without loss, with every compression
+    #[test]
+    fn roundtrip() {
+        let rgba16 = ImageData::new(
+            7,
+            5,
+            Array2::from_shape_fn((5, 7), |(y, x)| [(y * 7 + x) as u16 * 1000, 1, 2, u16::MAX]),
+        );
+        let gradient = ImageData::new(
+            9,
+            3,
+            Array2::from_shape_fn((3, 9), |(y, x)| {
+                [x as f32 - y as f32 * 0.33, f32::MIN_POSITIVE]
+            }),
+        );
+        let signed = ImageData::new(
+            4,
+            4,
+            Array2::from_shape_fn((4, 4), |(y, x)| [x as i16 - 8 * y as i16]),
+        );
+
+        for compression in [Compression::None, Compression::Deflate, Compression::Zstd] {
+            let path = format!("images/test/jade_roundtrip-{:?}.vsnx", compression);
+
+            assert!(io::save_native(&path, &rgba16, &Metadata::new(), compression).is_ok());
+            let (img, _) = io::load_native::<u16, 4, _>(&path).unwrap();
+            assert_eq!(img.pixels(), rgba16.pixels());
+
+            assert!(io::save_native(&path, &gradient, &Metadata::new(), compression).is_ok());
+            let (img, _) = io::load_native::<f32, 2, _>(&path).unwrap();
+            assert_eq!(img.pixels(), gradient.pixels());
+
+            assert!(io::save_native(&path, &signed, &Metadata::new(), compression).is_ok());
+            let (img, _) = io::load_native::<i16, 1, _>(&path).unwrap();
+            assert_eq!(img.pixels(), signed.pixels());
+        }
+    }
+
+    // Metadata is saved along with the image data
+    #[test]
+    fn metadata() {
+        let mut metadata = Metadata::new();
+        metadata.set_xmp(Some(b"<x:xmpmeta/>".to_vec()));
+        metadata.set_icc_profile(Some(Vec::new()));
+
+        let path = "images/test/jade_metadata.vsnx";
+        let img = ImageData::new(2, 2, Array2::from_elem((2, 2), [1u8; 3]));
+        assert!(io::save_native(path, &img, &metadata, Compression::Zstd).is_ok());
+
+        let (_, loaded) = io::load_native::<u8, 3, _>(path).unwrap();
+        assert_eq!(loaded, metadata);
+    }
+
+    // Mismatching element types, channels, newer versions and corrupted files return `Err`
+    #[test]
+    fn errors() {
+        let path = "images/test/jade_errors.vsnx";
+        let img = ImageData::new(8, 8, Array2::from_elem((8, 8), [3u16; 3]));
+        assert!(io::save_native(path, &img, &Metadata::new(), Compression::None).is_ok());
+
+        assert!(io::load_native::<u8, 3, _>(path).is_err());
+        assert!(io::load_native::<u16, 4, _>(path).is_err());
+        assert!(io::load_native::<u16, 3, _>(path).is_ok());
+
+        let bytes = std::fs::read(path).unwrap();
+        let mut corrupted = bytes.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        std::fs::write(path, &corrupted).unwrap();
+        assert!(io::load_native::<u16, 3, _>(path).is_err());
+
+        let mut newer = bytes.clone();
+        newer[4] = u8::MAX;
+        std::fs::write(path, &newer).unwrap();
+        assert!(io::load_native::<u16, 3, _>(path).is_err());
+
+        std::fs::write(path, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(io::load_native::<u16, 3, _>(path).is_err());
+
+        // chunk lengths must not be truncated nor collide with an absent chunk
+        assert_eq!(super::chunk_len(1024).unwrap(), 1024);
+        assert_eq!(
+            super::chunk_len(u32::MAX as usize - 1).unwrap(),
+            u32::MAX - 1
+        );
+        assert!(super::chunk_len(u32::MAX as usize).is_err());
+        assert!(super::chunk_len(u32::MAX as usize + 1).is_err());
+
+        // limits are checked before the payload is decompressed
+        let mut options = ReadOptions::new();
+        options.set_max_alloc(Some(8 * 8 * 3 * 2 - 1));
+        std::fs::write(path, &bytes).unwrap();
+        assert!(io::load_native_with_options::<u16, 3, _>(path, &options).is_err());
+        options.set_max_alloc(Some(8 * 8 * 3 * 2));
+        assert!(io::load_native_with_options::<u16, 3, _>(path, &options).is_ok());
+        options.set_max_height(Some(7));
+        assert!(io::load_native_with_options::<u16, 3, _>(path, &options).is_err());
+    }
+}
@@ -0,0 +1,473 @@
+use crate::{
+    core::Image,
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+
+use image::{DynamicImage, ImageDecoder, ImageError, ImageFormat, ImageReader, Limits};
+use std::path::Path;
+
+/// `ReadOptions` holds the limits checked while reading an image with `io::read_with_options()`, meant for untrusted inputs (e.g. uploads)
+///
+/// By default, there is no limit on the size or the format of the image and the decoders may allocate at most 512 MiB
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, ReadOptions};
+/// use image::ImageFormat;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let mut options = ReadOptions::new();
+/// options.set_max_width(Some(4096));
+/// options.set_max_height(Some(4096));
+/// options.set_max_alloc(Some(64 * 1024 * 1024));
+/// options.set_formats(Some(vec![ImageFormat::Jpeg, ImageFormat::Png]));
+/// let img = io::read_with_options("images/jpg/lenna.jpg", &options)?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadOptions {
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+    max_alloc: Option<u64>,
+    formats: Option<Vec<ImageFormat>>,
+}
+
+/// Creates `ReadOptions` with the default limits
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Core implementation of struct `ReadOptions`
+impl ReadOptions {
+    /// Creates `ReadOptions` with no limit on the size or format and an allocation limit of 512 MiB
+    pub fn new() -> Self {
+        Self {
+            max_width: None,
+            max_height: None,
+            max_alloc: Limits::default().max_alloc,
+            formats: None,
+        }
+    }
+
+    // Getters
+
+    /// Returns the maximum width of an image, `None` if unlimited
+    pub fn max_width(&self) -> Option<u32> {
+        self.max_width
+    }
+
+    /// Returns the maximum height of an image, `None` if unlimited
+    pub fn max_height(&self) -> Option<u32> {
+        self.max_height
+    }
+
+    /// Returns the maximum number of bytes allocated while decoding (including the decoded pixels), `None` if unlimited
+    pub fn max_alloc(&self) -> Option<u64> {
+        self.max_alloc
+    }
+
+    /// Returns the formats allowed to be read, `None` if any format is allowed
+    pub fn formats(&self) -> Option<&[ImageFormat]> {
+        self.formats.as_deref()
+    }
+
+    // Setters
+
+    /// Set the maximum width of an image, `None` for no limit
+    pub fn set_max_width(&mut self, max_width: Option<u32>) {
+        self.max_width = max_width;
+    }
+
+    /// Set the maximum height of an image, `None` for no limit
+    pub fn set_max_height(&mut self, max_height: Option<u32>) {
+        self.max_height = max_height;
+    }
+
+    /// Set the maximum number of bytes allocated while decoding (including the decoded pixels), `None` for no limit
+    pub fn set_max_alloc(&mut self, max_alloc: Option<u64>) {
+        self.max_alloc = max_alloc;
+    }
+
+    /// Set the formats allowed to be read, `None` to allow any format. The format is guessed from the content of the file and not from its extension
+    pub fn set_formats(&mut self, formats: Option<Vec<ImageFormat>>) {
+        self.formats = formats;
+    }
+
+    /// Guesses the format of a file from its content, returns `Err` if it is unknown or not allowed
+    ///
+    /// ## Note
+    /// Function is used internally (crate scope)
+    pub(crate) fn check_format(&self, path: &Path) -> VisionXResult<ImageFormat> {
+        let format = match ImageReader::open(path)?.with_guessed_format()?.format() {
+            Some(format) => format,
+            None => {
+                let err = format!("reading file {} of unknown format", path.display());
+                return Err(VisionXErrorKind::UnsupportedFormat(err).into());
+            }
+        };
+        self.check_allowed(format)?;
+        Ok(format)
+    }
+
+    /// Checks that a format is allowed to be read, returns `Err` with `VisionXErrorKind::UnsupportedFormat` otherwise
+    ///
+    /// ## Note
+    /// Function is used internally (crate scope)
+    pub(crate) fn check_allowed(&self, format: ImageFormat) -> VisionXResult<()> {
+        if let Some(formats) = &self.formats {
+            if !formats.contains(&format) {
+                let err = format!(
+                    "reading {:?} image, allowed formats are {:?}",
+                    format, formats
+                );
+                return Err(VisionXErrorKind::UnsupportedFormat(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the size of an image and the number of bytes allocated to decode it (as read from its header), returns `Err` with `VisionXErrorKind::LimitExceeded` if any limit is exceeded
+    ///
+    /// ## Note
+    /// Function is used internally (crate scope)
+    pub(crate) fn check_size(&self, (width, height): (u32, u32), bytes: u64) -> VisionXResult<()> {
+        if self.max_width.is_some_and(|max| width > max)
+            || self.max_height.is_some_and(|max| height > max)
+        {
+            let err = format!(
+                "reading image of size ({}, {}), maximum size is ({}, {})",
+                width,
+                height,
+                self.max_width
+                    .map_or("none".to_string(), |max| max.to_string()),
+                self.max_height
+                    .map_or("none".to_string(), |max| max.to_string())
+            );
+            return Err(VisionXErrorKind::LimitExceeded(err).into());
+        }
+        if let Some(max_alloc) = self.max_alloc.filter(|&max| bytes > max) {
+            let err = format!(
+                "reading image of {} bytes, maximum allocation is {} bytes",
+                bytes, max_alloc
+            );
+            return Err(VisionXErrorKind::LimitExceeded(err).into());
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum allocation as a buffer size for the codecs, `usize::MAX` if unlimited
+    ///
+    /// ## Note
+    /// Function is used internally (crate scope)
+    pub(crate) fn max_buffer_size(&self) -> usize {
+        self.max_alloc
+            .map_or(usize::MAX, |max| usize::try_from(max).unwrap_or(usize::MAX))
+    }
+
+    /// Returns the limits given to the decoders of the image crate
+    ///
+    /// ## Note
+    /// Function is used internally (crate scope)
+    pub(crate) fn limits(&self) -> Limits {
+        let mut limits = Limits::no_limits();
+        limits.max_image_width = self.max_width;
+        limits.max_image_height = self.max_height;
+        limits.max_alloc = self.max_alloc;
+        limits
+    }
+}
+
+/// Reads an image file within the limits of `ReadOptions`. Use it instead of `io::read()` for untrusted files
+///
+/// The format and size of the image are checked from its header before decoding its pixels, so that an oversized image (or a decompression bomb) is rejected without allocating its pixels. The decoders are given the same limits while decoding
+///
+/// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the image exceeds a limit, with `VisionXErrorKind::UnsupportedFormat` if the format is unknown or not allowed, or if any error occurs while read operation
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, ReadOptions};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let mut options = ReadOptions::new();
+/// options.set_max_width(Some(100));
+/// // lenna.jpg is 225 pixels wide
+/// assert!(io::read_with_options("images/jpg/lenna.jpg", &options).is_err());
+/// # Ok(()) }
+/// ```
+pub fn read_with_options<P: AsRef<Path>>(path: P, options: &ReadOptions) -> VisionXResult<Image> {
+    let path = path.as_ref();
+    let format = options.check_format(path)?;
+    let mut reader = ImageReader::open(path)?;
+    reader.set_format(format);
+
+    let mut decoder = reader.into_decoder().map_err(limit_error)?;
+    options.check_size(decoder.dimensions(), decoder.total_bytes())?;
+
+    decoder.set_limits(options.limits()).map_err(limit_error)?;
+
+    let raw_image = DynamicImage::from_decoder(decoder).map_err(limit_error)?;
+    Image::try_from(raw_image)
+}
+
+/// Converts a limit error of a decoder into `VisionXErrorKind::LimitExceeded`, any other error is kept
+///
+/// ## Note
+/// Function is used internally (crate scope)
+pub(crate) fn limit_error(err: ImageError) -> VisionXError {
+    match err {
+        ImageError::Limits(err) => {
+            VisionXErrorKind::LimitExceeded(format!("decoding image, {}", err)).into()
+        }
+        err => err.into(),
+    }
+}
+
+#[cfg(test)]
+mod options_test {
+    use crate::core::{Image, ImageData};
+    use crate::errors::{VisionXError, VisionXErrorKind, VisionXResult};
+    use crate::imgproc::kernel::XorShift;
+    use crate::io::{self, metadata::crc32, Frame, ReadOptions, TiledReader};
+    use image::ImageFormat;
+    use ndarray::Array2;
+    use std::{path::PathBuf, time::Duration};
+
+    // Returns true if the result is an error of kind `LimitExceeded`
+    fn is_limit_exceeded<T>(res: &VisionXResult<T>) -> bool {
+        matches!(
+            res,
+            Err(VisionXError::Kind(VisionXErrorKind::LimitExceeded(_)))
+        )
+    }
+
+    // Writes a png chunk
+    fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        let start = png.len();
+        png.extend_from_slice(kind);
+        png.extend_from_slice(data);
+        let crc = crc32(&png[start..]);
+        png.extend_from_slice(&crc.to_be_bytes());
+    }
+
+    // Size, allocation and format limits are checked
+    #[test]
+    fn limits() {
+        let path = "images/jpg/lenna.jpg";
+        let mut options = ReadOptions::new();
+        assert!(io::read_with_options(path, &options).is_ok());
+
+        options.set_max_width(Some(225));
+        options.set_max_height(Some(200));
+        assert!(is_limit_exceeded(&io::read_with_options(path, &options)));
+        options.set_max_height(Some(225));
+        assert!(io::read_with_options(path, &options).is_ok());
+
+        options.set_max_alloc(Some(225 * 225 * 3 - 1));
+        assert!(is_limit_exceeded(&io::read_with_options(path, &options)));
+        options.set_max_alloc(None);
+
+        options.set_formats(Some(vec![ImageFormat::Png]));
+        assert!(io::read_with_options(path, &options).is_err());
+        options.set_formats(Some(vec![ImageFormat::Png, ImageFormat::Jpeg]));
+        assert!(io::read_with_options(path, &options).is_ok());
+    }
+
+    // Animations and tiles are read within the limits
+    #[test]
+    fn frame_and_tile_limits() {
+        let path = "images/jpg/lenna.jpg";
+        let mut options = ReadOptions::new();
+        assert!(io::read_frames_with_options(path, &options).is_ok());
+        assert!(TiledReader::open_with_options(path, 64, 64, &options).is_ok());
+
+        options.set_max_width(Some(200));
+        assert!(is_limit_exceeded(&io::read_frames_with_options(
+            path, &options
+        )));
+        assert!(is_limit_exceeded(&TiledReader::open_with_options(
+            path, 64, 64, &options
+        )));
+        options.set_max_width(None);
+
+        let frames: Vec<Frame> = (0..2)
+            .map(|i| {
+                let pixels = Array2::from_elem((120, 100), [i * 100, 50, 0, 255]);
+                Frame::new(
+                    Image::ImageRgba(ImageData::new(100, 120, pixels)),
+                    Duration::ZERO,
+                )
+            })
+            .collect();
+        for extension in ["gif", "png", "tiff"] {
+            let path = format!("images/test/jade_options-frames.{}", extension);
+            io::write_animation(&path, &frames, 0).unwrap();
+            // canvas and frame buffer
+            options.set_max_alloc(Some(2 * 100 * 120 * 4));
+            let res = io::read_frames_with_options(&path, &options)
+                .and_then(|frames| frames.collect::<VisionXResult<Vec<Frame>>>());
+            assert!(res.is_ok(), "{}", extension);
+            options.set_max_alloc(Some(100 * 120 * 2));
+            let res = io::read_frames_with_options(&path, &options)
+                .and_then(|frames| frames.collect::<VisionXResult<Vec<Frame>>>());
+            assert!(is_limit_exceeded(&res), "{}", extension);
+        }
+
+        let mut options = ReadOptions::new();
+        let path = "images/test/jade_options-frames.tiff";
+        options.set_max_alloc(Some(100 * 64 * 4));
+        assert!(TiledReader::open_with_options(path, 64, 64, &options).is_ok());
+        assert!(is_limit_exceeded(&TiledReader::open_with_options(
+            path, 64, 65, &options
+        )));
+        options.set_formats(Some(vec![ImageFormat::Png]));
+        assert!(TiledReader::open_with_options(path, 64, 64, &options).is_err());
+        assert!(io::read_frames_with_options(path, &options).is_err());
+    }
+
+    // A tiny png claiming a huge size is rejected before its pixels are allocated
+    #[test]
+    fn decompression_bomb() {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut header = Vec::new();
+        header.extend_from_slice(&60000u32.to_be_bytes());
+        header.extend_from_slice(&60000u32.to_be_bytes());
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+        chunk(&mut png, b"IHDR", &header);
+        chunk(
+            &mut png,
+            b"IDAT",
+            &[0x78, 0x9C, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01],
+        );
+        chunk(&mut png, b"IEND", &[]);
+        let path = "images/test/jade_options-bomb.png";
+        std::fs::write(path, &png).unwrap();
+
+        let info = io::probe(path).unwrap();
+        assert_eq!((60000, 60000), (info.width(), info.height()));
+        assert!(is_limit_exceeded(&io::read_with_options(
+            path,
+            &ReadOptions::new()
+        )));
+
+        let mut options = ReadOptions::new();
+        options.set_max_alloc(None);
+        options.set_max_width(Some(10000));
+        assert!(is_limit_exceeded(&io::read_with_options(path, &options)));
+    }
+
+    // Randomly corrupted files never panic any decoder entry point, they are either read or rejected with an error
+    #[test]
+    fn fuzz() {
+        // mutated files are written outside of the repository
+        let dir = std::env::temp_dir().join(format!("vision-x-fuzz-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let frames: Vec<Frame> = (0..2)
+            .map(|i| {
+                let pixels = Array2::from_elem((12, 10), [i * 100, 50, 0, 255]);
+                Frame::new(
+                    Image::ImageRgba(ImageData::new(10, 12, pixels)),
+                    Duration::ZERO,
+                )
+            })
+            .collect();
+        io::write_animation(dir.join("seed.gif"), &frames, 0).unwrap();
+        io::write_animation(dir.join("seed.tiff"), &frames, 0).unwrap();
+        let rgb = ImageData::new(9, 7, Array2::from_elem((7, 9), [10u8, 20, 30]));
+        io::write_pnm(
+            dir.join("seed.ppm"),
+            &Image::ImageRgb(rgb.clone()),
+            io::PnmEncoding::Binary,
+        )
+        .unwrap();
+        let metadata = io::Metadata::new();
+        io::save_native(
+            dir.join("seed.vsnx"),
+            &rgb,
+            &metadata,
+            io::Compression::Deflate,
+        )
+        .unwrap();
+
+        let seeds = [
+            (PathBuf::from("images/jpg/lenna.jpg"), "jpg"),
+            (PathBuf::from("images/png/basn2c16.png"), "png"),
+            (PathBuf::from("images/png/basn0g01.png"), "png"),
+            (PathBuf::from("images/png/basn4a08.png"), "png"),
+            (dir.join("seed.gif"), "gif"),
+            (dir.join("seed.tiff"), "tiff"),
+            (dir.join("seed.ppm"), "ppm"),
+            (dir.join("seed.vsnx"), "vsnx"),
+        ];
+
+        let mut options = ReadOptions::new();
+        options.set_max_width(Some(4096));
+        options.set_max_height(Some(4096));
+        options.set_max_alloc(Some(64 * 1024 * 1024));
+
+        // fixed seed, so that failures can be reproduced
+        let mut random = XorShift::new(0x2545_F491_4F6C_DD1D);
+
+        for (seed, extension) in seeds {
+            let original = std::fs::read(seed).unwrap();
+            let path = dir.join(format!("fuzz.{}", extension));
+            for _ in 0..100 {
+                let mut data = original.clone();
+                match random.below(4) {
+                    // flip a few bits
+                    0 => {
+                        for _ in 0..1 + random.below(8) {
+                            let index = random.below(data.len());
+                            data[index] ^= 1 << random.below(8);
+                        }
+                    }
+                    // truncate
+                    1 => data.truncate(random.below(data.len())),
+                    // overwrite a range with a single value
+                    2 => {
+                        let start = random.below(data.len());
+                        let end = (start + random.below(64)).min(data.len());
+                        let value = [0x00, 0xFF, 0x7F][random.below(3)];
+                        data[start..end].fill(value);
+                    }
+                    // duplicate a range
+                    _ => {
+                        let start = random.below(data.len());
+                        let end = (start + random.below(256)).min(data.len());
+                        let range = data[start..end].to_vec();
+                        let at = random.below(data.len());
+                        data.splice(at..at, range);
+                    }
+                }
+                std::fs::write(&path, &data).unwrap();
+
+                let _ = io::read_with_options(&path, &options);
+                let _ = io::read_pnm_with_options(&path, &options);
+                let _ = io::load_native_with_options::<u8, 3, _>(&path, &options);
+                let _ = io::read_raw_with_options(
+                    &data,
+                    8,
+                    8,
+                    io::PixelFormat::Nv12,
+                    io::YuvColorSpace::default(),
+                    &options,
+                );
+                let _ = io::probe(&path);
+                let _ = io::read_scaled_with_options(&path, 16, 16, &options);
+                if let Ok(frames) = io::read_frames_with_options(&path, &options) {
+                    frames.for_each(drop);
+                }
+                if let Ok(tiles) = TiledReader::open_with_options(&path, 8, 8, &options) {
+                    tiles.for_each(drop);
+                }
+            }
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,1039 @@
+use crate::{
+    core::{Image, ImageData, PixelDepth},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+
+use super::ReadOptions;
+use image::ImageFormat;
+use std::{fmt::Display, path::Path};
+
+/// `PnmEncoding` represents how the samples of a PNM file are stored
+///
+/// PAM and PFM files only have a binary encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PnmEncoding {
+    /// Samples are written as decimal numbers (plain PBM, PGM and PPM)
+    Ascii,
+    /// Samples are written as raw bytes
+    Binary,
+}
+
+/// Type of a PNM file, decided by the extension of the path
+///
+/// ## Note
+/// Enum is used internally (private scope)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PnmFormat {
+    /// Bilevel (black and white) image, `.pbm`
+    Pbm,
+    /// Grayscale image, `.pgm`
+    Pgm,
+    /// RGB image, `.ppm`
+    Ppm,
+    /// Image with arbitrary channels, `.pam`
+    Pam,
+    /// Float image, `.pfm`
+    Pfm,
+    /// Any of the above, picked as per the image, `.pnm`
+    Pnm,
+}
+
+/// Reads a PBM, PGM, PPM (ASCII and binary), PAM or PFM file. Returns a result with an `Image` enum
+///
+/// Bilevel images are returned as 8bit grayscale images (black is `0` and white is `255`). Samples with a max value other than 255 or 65535 are scaled to 8bit (max value below 256) or 16bit. PFM files are returned as 32bit float grayscale/RGB images. PAM files with more than 4 channels are read with `io::read_pam()`
+///
+/// The image is read within the default `ReadOptions` limits, use `io::read_pnm_with_options()` for other limits
+///
+/// Returns `Err` if path/file is not found or if the file is malformed
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, PnmEncoding};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let img = io::read("images/jpg/lenna.jpg")?;
+/// io::write_pnm("images/test/jade_lenna-doctest.ppm", &img, PnmEncoding::Binary)?;
+/// let pnm_img = io::read_pnm("images/test/jade_lenna-doctest.ppm")?;
+/// assert_eq!(pnm_img.to_str(), "rgb");
+/// # Ok(()) }
+/// ```
+pub fn read_pnm<P: AsRef<Path>>(path: P) -> VisionXResult<Image> {
+    read_pnm_with_options(path, &ReadOptions::default())
+}
+
+/// Reads a PBM, PGM, PPM, PAM or PFM file within the limits of `ReadOptions`, see `io::read_pnm()`. The file is read if `ImageFormat::Pnm` is allowed
+///
+/// The size of the image and the bytes of its samples are checked from its header before decoding them
+///
+/// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the image exceeds a limit, with `VisionXErrorKind::UnsupportedFormat` if PNM files aren't allowed, or if path/file is not found or the file is malformed
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, PnmEncoding, ReadOptions};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let img = io::read("images/jpg/lenna.jpg")?;
+/// io::write_pnm("images/test/jade_lenna-options-doctest.ppm", &img, PnmEncoding::Binary)?;
+/// let mut options = ReadOptions::new();
+/// options.set_max_alloc(Some(100 * 100 * 3));
+/// assert!(io::read_pnm_with_options("images/test/jade_lenna-options-doctest.ppm", &options).is_err());
+/// # Ok(()) }
+/// ```
+pub fn read_pnm_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &ReadOptions,
+) -> VisionXResult<Image> {
+    options.check_allowed(ImageFormat::Pnm)?;
+    let bytes = std::fs::read(path)?;
+    decode_pnm(&bytes, options)
+}
+
+/// Writes an image into a PBM, PGM, PPM, PAM or PFM file, as per the extension of the path (`.pnm` picks the format as per the image)
+///
+/// - `.pbm`: 8bit grayscale image, pixels below 128 are written as black
+/// - `.pgm`: 8bit and 16bit grayscale image
+/// - `.ppm`: 8bit and 16bit RGB image
+/// - `.pam`: 8bit and 16bit grayscale, grayscale + alpha, RGB and RGBA image (binary only)
+/// - `.pfm`: 32bit float grayscale, RGB and HSV image (binary only). HSV pixels are stored as it is and read back as a 32bit float RGB image holding the same values, i.e., no value is lost (wrap them back with `Image::ImageHsv`)
+///
+/// Returns `Ok` if write is successful, else, returns `Err` if the format doesn't support the image or the encoding, or if any error occurs while write operation
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::{Image, ImageData};
+/// use vision_x::io::{self, PnmEncoding};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let pixels = Array2::from_elem((4, 8), [0.25f32, 0.5, 1.0]);
+/// let img = Image::ImageHsv(ImageData::new(8, 4, pixels));
+/// io::write_pnm("images/test/jade_hsv-doctest.pfm", &img, PnmEncoding::Binary)?;
+/// let Image::ImageRgb32F(pfm_img) = io::read_pnm("images/test/jade_hsv-doctest.pfm")? else {
+///     unreachable!()
+/// };
+/// assert_eq!(Image::ImageHsv(pfm_img).to_str(), "hsv");
+/// # Ok(()) }
+/// ```
+pub fn write_pnm<P: AsRef<Path>>(path: P, img: &Image, encoding: PnmEncoding) -> VisionXResult<()> {
+    let path = path.as_ref();
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase());
+    let format = match extension.as_deref() {
+        Some("pbm") => PnmFormat::Pbm,
+        Some("pgm") => PnmFormat::Pgm,
+        Some("ppm") => PnmFormat::Ppm,
+        Some("pam") => PnmFormat::Pam,
+        Some("pfm") => PnmFormat::Pfm,
+        Some("pnm") => PnmFormat::Pnm,
+        _ => {
+            return Err(VisionXErrorKind::UnsupportedFormat(format!(
+                "writing pnm file with path {}",
+                path.display()
+            ))
+            .into())
+        }
+    };
+
+    let bytes = encode_pnm(img, format, encoding)?;
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+/// Reads a PAM file with `N` channels (any number, e.g. multispectral data) into `ImageData`
+///
+/// Samples are scaled to the color depth `T` (8bit or 16bit) as per the max value of the file
+///
+/// Returns `Err` if path/file is not found, if the file is malformed or if its depth is not `N`
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use vision_x::core::ImageData;
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let pixels = Array2::from_elem((4, 8), [1u16, 2, 3, 4, 5, 6]);
+/// io::write_pam("images/test/jade_bands-doctest.pam", &ImageData::new(8, 4, pixels))?;
+/// let img: ImageData<u16, 6> = io::read_pam("images/test/jade_bands-doctest.pam")?;
+/// assert_eq!(img.get_pixel_at(7, 3), Some(&[1, 2, 3, 4, 5, 6]));
+/// # Ok(()) }
+/// ```
+pub fn read_pam<T: PnmSample, const N: usize, P: AsRef<Path>>(
+    path: P,
+) -> VisionXResult<ImageData<T, N>> {
+    let bytes = std::fs::read(path)?;
+    let mut reader = PnmReader {
+        bytes: &bytes,
+        pos: 0,
+    };
+    let magic = reader.token()?;
+    if magic != b"P7" {
+        return Err(invalid_data(&format!(
+            "reading pam file with magic number {:?}",
+            String::from_utf8_lossy(magic)
+        )));
+    }
+
+    let header = PamHeader::read(&mut reader)?;
+    if header.depth as usize != N {
+        return Err(VisionXErrorKind::InvalidColorType(format!(
+            "reading pam file with {} channels into an image with {} channels",
+            header.depth, N
+        ))
+        .into());
+    }
+    let count = sample_count(header.width, header.height, header.depth)?;
+    let buffer: Vec<T> = match reader.samples(count, header.max_value, false)? {
+        Samples::U8(buffer) => buffer
+            .into_iter()
+            .map(|value| T::from_normalized(value.to_normalized()))
+            .collect(),
+        Samples::U16(buffer) => buffer
+            .into_iter()
+            .map(|value| T::from_normalized(value.to_normalized()))
+            .collect(),
+    };
+    ImageData::from_raw_vec(header.width, header.height, buffer)
+}
+
+/// Writes an 8bit or 16bit image with `N` channels (any number) into a PAM file
+///
+/// The tuple type is written for 1 to 4 channels (grayscale, grayscale + alpha, RGB and RGBA), and left out otherwise
+///
+/// Returns `Ok` if write is successful, else, returns `Err` if any error occurs while write operation
+pub fn write_pam<T: PnmSample, const N: usize, P: AsRef<Path>>(
+    path: P,
+    img: &ImageData<T, N>,
+) -> VisionXResult<()> {
+    let tuple_type = match N {
+        1 => Some("GRAYSCALE"),
+        2 => Some("GRAYSCALE_ALPHA"),
+        3 => Some("RGB"),
+        4 => Some("RGB_ALPHA"),
+        _ => None,
+    };
+    std::fs::write(path, encode_pam(img, tuple_type))?;
+    Ok(())
+}
+
+/// Samples of an 8bit or 16bit PNM file
+///
+/// ## Note
+/// Enum is used internally (private scope)
+enum Samples {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+}
+
+/// `PnmReader` reads the header and samples of a PNM file from bytes
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct PnmReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+/// Core implementation of struct `PnmReader`
+impl<'a> PnmReader<'a> {
+    /// Skips whitespaces and comments (from `#` till the end of the line)
+    fn skip_whitespace(&mut self) {
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            match byte {
+                b'#' => {
+                    while self.bytes.get(self.pos).is_some_and(|&byte| byte != b'\n') {
+                        self.pos += 1;
+                    }
+                }
+                byte if byte.is_ascii_whitespace() => self.pos += 1,
+                _ => break,
+            }
+        }
+    }
+
+    /// Reads the next whitespace separated token
+    fn token(&mut self) -> VisionXResult<&'a [u8]> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|byte| !byte.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+
+        if start == self.pos {
+            return Err(invalid_data("reading pnm file, unexpected end of file"));
+        }
+        Ok(&self.bytes[start..self.pos])
+    }
+
+    /// Reads the next token as a number
+    fn number<T: std::str::FromStr>(&mut self) -> VisionXResult<T> {
+        let token = self.token()?;
+        std::str::from_utf8(token)
+            .ok()
+            .and_then(|token| token.parse().ok())
+            .ok_or_else(|| {
+                invalid_data(&format!(
+                    "reading pnm file, {:?} is not a valid number",
+                    String::from_utf8_lossy(token)
+                ))
+            })
+    }
+
+    /// Skips the single whitespace between the header and the binary samples, returns the binary samples
+    fn raster(&mut self, len: usize) -> VisionXResult<&'a [u8]> {
+        let start = self.pos + 1;
+        self.bytes
+            .get(start..)
+            .and_then(|raster| raster.get(..len))
+            .ok_or_else(|| invalid_data("reading pnm file, raster is truncated"))
+    }
+
+    /// Reads `count` samples which are at most `max_value`
+    fn samples(&mut self, count: usize, max_value: u32, ascii: bool) -> VisionXResult<Samples> {
+        if max_value == 0 || max_value > u32::from(u16::MAX) {
+            return Err(invalid_data(&format!(
+                "reading pnm file with max value {}",
+                max_value
+            )));
+        }
+        // every sample takes at least a byte, protects from allocating a huge buffer for a malformed header
+        if count > self.bytes.len() {
+            return Err(invalid_data("reading pnm file, raster is truncated"));
+        }
+
+        let mut values: Vec<u32> = Vec::with_capacity(count);
+        if ascii {
+            for _ in 0..count {
+                values.push(self.number()?);
+            }
+        } else if max_value < 256 {
+            values.extend(self.raster(count)?.iter().map(|&value| u32::from(value)));
+        } else {
+            let len = count.saturating_mul(2);
+            let raster = self.raster(len)?;
+            values.extend(
+                raster
+                    .chunks_exact(2)
+                    .map(|bytes| u32::from(u16::from_be_bytes([bytes[0], bytes[1]]))),
+            );
+        }
+
+        if values.iter().any(|&value| value > max_value) {
+            return Err(invalid_data(&format!(
+                "reading pnm file, sample is larger than max value {}",
+                max_value
+            )));
+        }
+        let samples = match max_value {
+            255 => Samples::U8(values.iter().map(|&value| value as u8).collect()),
+            65535 => Samples::U16(values.iter().map(|&value| value as u16).collect()),
+            0..=254 => Samples::U8(
+                values
+                    .iter()
+                    .map(|&value| ((value * 255 + max_value / 2) / max_value) as u8)
+                    .collect(),
+            ),
+            _ => Samples::U16(
+                values
+                    .iter()
+                    .map(|&value| ((value * 65535 + max_value / 2) / max_value) as u16)
+                    .collect(),
+            ),
+        };
+        Ok(samples)
+    }
+}
+
+/// Returns an `InvalidData` error
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn invalid_data(err: &str) -> VisionXError {
+    VisionXErrorKind::InvalidData(err.to_string()).into()
+}
+
+/// Decodes a PNM file
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn decode_pnm(bytes: &[u8], options: &ReadOptions) -> VisionXResult<Image> {
+    let mut reader = PnmReader { bytes, pos: 0 };
+    let magic = reader.token()?;
+    match magic {
+        b"P1" | b"P4" => decode_pbm(&mut reader, magic == b"P1", options),
+        b"P2" | b"P5" => {
+            let (width, height, max_value) = (reader.number()?, reader.number()?, reader.number()?);
+            let size = (width, height, 1);
+            decode_samples(&mut reader, size, max_value, magic == b"P2", options)
+        }
+        b"P3" | b"P6" => {
+            let (width, height, max_value) = (reader.number()?, reader.number()?, reader.number()?);
+            let size = (width, height, 3);
+            decode_samples(&mut reader, size, max_value, magic == b"P3", options)
+        }
+        b"P7" => decode_pam(&mut reader, options),
+        b"Pf" => decode_pfm(&mut reader, 1, options),
+        b"PF" => decode_pfm(&mut reader, 3, options),
+        _ => Err(invalid_data(&format!(
+            "reading pnm file with magic number {:?}",
+            String::from_utf8_lossy(magic)
+        ))),
+    }
+}
+
+/// Returns the number of samples in an image
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn sample_count(width: u32, height: u32, channels: u32) -> VisionXResult<usize> {
+    (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|count| count.checked_mul(channels as usize))
+        .filter(|&count| count > 0)
+        .ok_or_else(|| {
+            invalid_data(&format!(
+                "reading pnm file of size ({}, {}) with {} channels",
+                width, height, channels
+            ))
+        })
+}
+
+/// Decodes the samples of a PGM, PPM or PAM file into an 8bit or 16bit image
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn decode_samples(
+    reader: &mut PnmReader,
+    (width, height, channels): (u32, u32, u32),
+    max_value: u32,
+    ascii: bool,
+    options: &ReadOptions,
+) -> VisionXResult<Image> {
+    let count = sample_count(width, height, channels)?;
+    let sample_bytes = if max_value > 255 { 2 } else { 1 };
+    options.check_size((width, height), count as u64 * sample_bytes)?;
+    let img = match (channels, reader.samples(count, max_value, ascii)?) {
+        (1, Samples::U8(buffer)) => {
+            Image::ImageGrayscale(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (1, Samples::U16(buffer)) => {
+            Image::ImageGrayscale16(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (2, Samples::U8(buffer)) => {
+            Image::ImageGrayscaleAlpha(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (2, Samples::U16(buffer)) => {
+            Image::ImageGrayscaleAlpha16(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (3, Samples::U8(buffer)) => {
+            Image::ImageRgb(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (3, Samples::U16(buffer)) => {
+            Image::ImageRgb16(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (4, Samples::U8(buffer)) => {
+            Image::ImageRgba(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        (4, Samples::U16(buffer)) => {
+            Image::ImageRgba16(ImageData::from_raw_vec(width, height, buffer)?)
+        }
+        _ => {
+            return Err(VisionXErrorKind::InvalidColorType(format!(
+                "reading pam file with {} channels, use `io::read_pam()` instead",
+                channels
+            ))
+            .into())
+        }
+    };
+    Ok(img)
+}
+
+/// Decodes a PBM file into an 8bit grayscale image
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn decode_pbm(reader: &mut PnmReader, ascii: bool, options: &ReadOptions) -> VisionXResult<Image> {
+    let (width, height): (u32, u32) = (reader.number()?, reader.number()?);
+    let count = sample_count(width, height, 1)?;
+    options.check_size((width, height), count as u64)?;
+
+    let mut buffer: Vec<u8> = Vec::with_capacity(count.min(reader.bytes.len() * 8));
+    if ascii {
+        // bits don't need to be separated by whitespaces
+        for _ in 0..count {
+            reader.skip_whitespace();
+            match reader.bytes.get(reader.pos) {
+                Some(b'0') => buffer.push(255),
+                Some(b'1') => buffer.push(0),
+                _ => return Err(invalid_data("reading pbm file, expected a bit")),
+            }
+            reader.pos += 1;
+        }
+    } else {
+        let row_len = (width as usize).div_ceil(8);
+        let raster = reader.raster(row_len * height as usize)?;
+        for row in raster.chunks_exact(row_len) {
+            buffer.extend(
+                (0..width as usize).map(|x| match row[x / 8] >> (7 - x % 8) & 1 {
+                    0 => 255,
+                    _ => 0,
+                }),
+            );
+        }
+    }
+
+    Ok(Image::ImageGrayscale(ImageData::from_raw_vec(
+        width, height, buffer,
+    )?))
+}
+
+/// Header of a PAM file
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct PamHeader {
+    width: u32,
+    height: u32,
+    depth: u32,
+    max_value: u32,
+}
+
+/// Core implementation of struct `PamHeader`
+impl PamHeader {
+    /// Reads the header fields till `ENDHDR`, the magic number being already read
+    fn read(reader: &mut PnmReader) -> VisionXResult<Self> {
+        let (mut width, mut height, mut depth, mut max_value) = (None, None, None, None);
+        loop {
+            match reader.token()? {
+                b"WIDTH" => width = Some(reader.number()?),
+                b"HEIGHT" => height = Some(reader.number()?),
+                b"DEPTH" => depth = Some(reader.number()?),
+                b"MAXVAL" => max_value = Some(reader.number()?),
+                b"TUPLTYPE" => {
+                    // channels are decided by the depth, tuple type is informative
+                    while reader
+                        .bytes
+                        .get(reader.pos)
+                        .is_some_and(|&byte| byte != b'\n')
+                    {
+                        reader.pos += 1;
+                    }
+                }
+                b"ENDHDR" => break,
+                token => {
+                    return Err(invalid_data(&format!(
+                        "reading pam header, unknown field {:?}",
+                        String::from_utf8_lossy(token)
+                    )))
+                }
+            }
+        }
+
+        match (width, height, depth, max_value) {
+            (Some(width), Some(height), Some(depth), Some(max_value)) => Ok(PamHeader {
+                width,
+                height,
+                depth,
+                max_value,
+            }),
+            _ => Err(invalid_data(
+                "reading pam header, WIDTH, HEIGHT, DEPTH and MAXVAL are required",
+            )),
+        }
+    }
+}
+
+/// Decodes a PAM file
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn decode_pam(reader: &mut PnmReader, options: &ReadOptions) -> VisionXResult<Image> {
+    let header = PamHeader::read(reader)?;
+    decode_samples(
+        reader,
+        (header.width, header.height, header.depth),
+        header.max_value,
+        false,
+        options,
+    )
+}
+
+/// Decodes a PFM file into a 32bit float grayscale or RGB image
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn decode_pfm(
+    reader: &mut PnmReader,
+    channels: u32,
+    options: &ReadOptions,
+) -> VisionXResult<Image> {
+    let (width, height, scale): (u32, u32, f32) =
+        (reader.number()?, reader.number()?, reader.number()?);
+    let count = sample_count(width, height, channels)?;
+    options.check_size((width, height), count as u64 * 4)?;
+    let raster = reader.raster(count.saturating_mul(4))?;
+
+    // negative scale denotes little endian samples, rows are stored from bottom to top
+    let row_len = width as usize * channels as usize * 4;
+    let buffer: Vec<f32> = raster
+        .chunks_exact(row_len)
+        .rev()
+        .flat_map(|row| row.chunks_exact(4))
+        .map(|bytes| {
+            let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            match scale < 0.0 {
+                true => f32::from_le_bytes(bytes),
+                false => f32::from_be_bytes(bytes),
+            }
+        })
+        .collect();
+
+    match channels {
+        1 => Ok(Image::ImageGrayscale32F(ImageData::from_raw_vec(
+            width, height, buffer,
+        )?)),
+        _ => Ok(Image::ImageRgb32F(ImageData::from_raw_vec(
+            width, height, buffer,
+        )?)),
+    }
+}
+
+/// `PnmSample` represents a sample of an 8bit or 16bit PNM file, implemented for `u8` and `u16`
+pub trait PnmSample: PixelDepth + Display {
+    /// Max value of the sample
+    const MAX: u32;
+
+    /// Appends the sample in big endian order
+    fn extend_be_bytes(self, bytes: &mut Vec<u8>);
+}
+
+impl PnmSample for u8 {
+    const MAX: u32 = 255;
+
+    fn extend_be_bytes(self, bytes: &mut Vec<u8>) {
+        bytes.push(self);
+    }
+}
+
+impl PnmSample for u16 {
+    const MAX: u32 = 65535;
+
+    fn extend_be_bytes(self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.to_be_bytes());
+    }
+}
+
+/// Encodes an image into a PNM file
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_pnm(img: &Image, format: PnmFormat, encoding: PnmEncoding) -> VisionXResult<Vec<u8>> {
+    use PnmFormat::{Pam, Pbm, Pfm, Pgm, Pnm, Ppm};
+
+    let ascii = encoding == PnmEncoding::Ascii;
+    if ascii && matches!(format, Pam | Pfm) {
+        return Err(VisionXErrorKind::UnsupportedFormat(format!(
+            "writing {:?} file with ascii encoding",
+            format
+        ))
+        .into());
+    }
+
+    match (format, img) {
+        (Pbm, Image::ImageGrayscale(gray)) => Ok(encode_pbm(gray, ascii)),
+        (Pgm | Pnm, Image::ImageGrayscale(gray)) => Ok(encode_samples(gray, ascii)),
+        (Pgm | Pnm, Image::ImageGrayscale16(gray16)) => Ok(encode_samples(gray16, ascii)),
+        (Ppm | Pnm, Image::ImageRgb(rgb)) => Ok(encode_samples(rgb, ascii)),
+        (Ppm | Pnm, Image::ImageRgb16(rgb16)) => Ok(encode_samples(rgb16, ascii)),
+        (Pam, Image::ImageGrayscale(gray)) => Ok(encode_pam(gray, Some("GRAYSCALE"))),
+        (Pam, Image::ImageGrayscale16(gray16)) => Ok(encode_pam(gray16, Some("GRAYSCALE"))),
+        (Pam, Image::ImageRgb(rgb)) => Ok(encode_pam(rgb, Some("RGB"))),
+        (Pam, Image::ImageRgb16(rgb16)) => Ok(encode_pam(rgb16, Some("RGB"))),
+        (Pam | Pnm, Image::ImageGrayscaleAlpha(gray_alpha)) if !ascii => {
+            Ok(encode_pam(gray_alpha, Some("GRAYSCALE_ALPHA")))
+        }
+        (Pam | Pnm, Image::ImageGrayscaleAlpha16(gray_alpha16)) if !ascii => {
+            Ok(encode_pam(gray_alpha16, Some("GRAYSCALE_ALPHA")))
+        }
+        (Pam | Pnm, Image::ImageRgba(rgba)) if !ascii => Ok(encode_pam(rgba, Some("RGB_ALPHA"))),
+        (Pam | Pnm, Image::ImageRgba16(rgba16)) if !ascii => {
+            Ok(encode_pam(rgba16, Some("RGB_ALPHA")))
+        }
+        (Pfm | Pnm, Image::ImageGrayscale32F(gray32f)) if !ascii => Ok(encode_pfm(gray32f)),
+        (Pfm | Pnm, Image::ImageRgb32F(rgb32f)) if !ascii => Ok(encode_pfm(rgb32f)),
+        (Pfm | Pnm, Image::ImageHsv(hsv)) if !ascii => Ok(encode_pfm(hsv)),
+        (format, value) => Err(VisionXErrorKind::InvalidColorType(format!(
+            "writing {} image into {:?} file with {:?} encoding",
+            value.to_str(),
+            format,
+            encoding
+        ))
+        .into()),
+    }
+}
+
+/// Appends the values as decimal numbers separated by whitespaces, lines are kept within 70 characters
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn extend_ascii<T: Display>(bytes: &mut Vec<u8>, values: impl Iterator<Item = T>, separator: &str) {
+    let mut line_len = 0;
+    for value in values {
+        let value = value.to_string();
+        if line_len > 0 && line_len + separator.len() + value.len() > 70 {
+            bytes.push(b'\n');
+            line_len = 0;
+        } else if line_len > 0 {
+            bytes.extend(separator.as_bytes());
+            line_len += separator.len();
+        }
+        bytes.extend(value.as_bytes());
+        line_len += value.len();
+    }
+    bytes.push(b'\n');
+}
+
+/// Encodes an 8bit grayscale image into a PBM file
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_pbm(img: &ImageData<u8, 1>, ascii: bool) -> Vec<u8> {
+    let mut bytes = format!(
+        "{}\n{} {}\n",
+        if ascii { "P1" } else { "P4" },
+        img.width(),
+        img.height()
+    )
+    .into_bytes();
+    // black pixels are stored as 1, rows are padded to a byte in binary encoding
+    let bit = |value: &u8| u8::from(*value < 128);
+    if ascii {
+        extend_ascii(&mut bytes, img.as_raw_slice().iter().map(bit), "");
+    } else {
+        let row_len = (*img.width() as usize).max(1);
+        for row in img.as_raw_slice().chunks_exact(row_len) {
+            bytes.extend(row.chunks(8).map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (idx, value)| byte | bit(value) << (7 - idx))
+            }));
+        }
+    }
+    bytes
+}
+
+/// Encodes an 8bit or 16bit grayscale image into a PGM file, or an RGB image into a PPM file
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_samples<T: PnmSample, const N: usize>(img: &ImageData<T, N>, ascii: bool) -> Vec<u8> {
+    let magic = match (N, ascii) {
+        (1, true) => "P2",
+        (1, false) => "P5",
+        (_, true) => "P3",
+        (_, false) => "P6",
+    };
+    let mut bytes =
+        format!("{}\n{} {}\n{}\n", magic, img.width(), img.height(), T::MAX).into_bytes();
+    if ascii {
+        extend_ascii(&mut bytes, img.as_raw_slice().iter(), " ");
+    } else {
+        img.as_raw_slice()
+            .iter()
+            .for_each(|value| value.extend_be_bytes(&mut bytes));
+    }
+    bytes
+}
+
+/// Encodes an 8bit or 16bit image into a PAM file, the tuple type is optional
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_pam<T: PnmSample, const N: usize>(
+    img: &ImageData<T, N>,
+    tuple_type: Option<&str>,
+) -> Vec<u8> {
+    let mut header = format!(
+        "P7\nWIDTH {}\nHEIGHT {}\nDEPTH {}\nMAXVAL {}\n",
+        img.width(),
+        img.height(),
+        N,
+        T::MAX
+    );
+    if let Some(tuple_type) = tuple_type {
+        header.push_str(&format!("TUPLTYPE {}\n", tuple_type));
+    }
+    header.push_str("ENDHDR\n");
+    let mut bytes = header.into_bytes();
+    img.as_raw_slice()
+        .iter()
+        .for_each(|value| value.extend_be_bytes(&mut bytes));
+    bytes
+}
+
+/// Encodes a 32bit float image with 1 or 3 channels into a little endian PFM file
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_pfm<const N: usize>(img: &ImageData<f32, N>) -> Vec<u8> {
+    let magic = if N == 1 { "Pf" } else { "PF" };
+    let mut bytes = format!("{}\n{} {}\n-1.0\n", magic, img.width(), img.height()).into_bytes();
+    // rows are stored from bottom to top
+    let row_len = *img.width() as usize * N;
+    for row in img.as_raw_slice().chunks_exact(row_len.max(1)).rev() {
+        row.iter()
+            .for_each(|value| bytes.extend(value.to_le_bytes()));
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod pnm_test {
+    use super::{decode_pnm, encode_pnm, PnmFormat};
+    use crate::core::{Image, ImageData};
+    use crate::errors::VisionXErrorKind;
+    use crate::io::{self, PnmEncoding, ReadOptions};
+    use ndarray::Array2;
+
+    fn gradient<T: Default + Copy, const N: usize>(
+        width: usize,
+        height: usize,
+        f: impl Fn(usize) -> [T; N],
+    ) -> ImageData<T, N> {
+        let pixels = Array2::from_shape_fn((height, width), |(y, x)| f(y * width + x));
+        ImageData::new(width as u32, height as u32, pixels)
+    }
+
+    // PGM, PPM and PAM files are written and read back without any loss
+    #[test]
+    fn roundtrip() {
+        let gray = gradient(7, 3, |idx| [(idx * 11) as u8]);
+        let rgb16 = gradient(5, 4, |idx| [(idx * 3000) as u16, 7, 65535]);
+        let rgba = gradient(3, 9, |idx| [idx as u8, 2, 3, 200]);
+
+        for encoding in [PnmEncoding::Ascii, PnmEncoding::Binary] {
+            let path = format!("images/test/jade_gray-{:?}.pgm", encoding);
+            assert!(io::write_pnm(&path, &Image::ImageGrayscale(gray.clone()), encoding).is_ok());
+            match io::read_pnm(&path).unwrap() {
+                Image::ImageGrayscale(img) => assert_eq!(img.pixels(), gray.pixels()),
+                value => panic!("unexpected {} image", value.to_str()),
+            }
+
+            let path = format!("images/test/jade_rgb16-{:?}.ppm", encoding);
+            assert!(io::write_pnm(&path, &Image::ImageRgb16(rgb16.clone()), encoding).is_ok());
+            match io::read_pnm(&path).unwrap() {
+                Image::ImageRgb16(img) => assert_eq!(img.pixels(), rgb16.pixels()),
+                value => panic!("unexpected {} image", value.to_str()),
+            }
+        }
+
+        let path = "images/test/jade_rgba.pam";
+        assert!(io::write_pnm(path, &Image::ImageRgba(rgba.clone()), PnmEncoding::Binary).is_ok());
+        match io::read_pnm(path).unwrap() {
+            Image::ImageRgba(img) => assert_eq!(img.pixels(), rgba.pixels()),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+        // PAM has no ascii encoding
+        assert!(io::write_pnm(path, &Image::ImageRgba(rgba), PnmEncoding::Ascii).is_err());
+    }
+
+    // Float and HSV images are written into PFM without any loss
+    #[test]
+    fn float() {
+        let options = ReadOptions::new();
+        let rgb32f = gradient(6, 5, |idx| [idx as f32 * 0.37, 0.5, -1.25]);
+        let path = "images/test/jade_rgb32f.pfm";
+        assert!(io::write_pnm(
+            path,
+            &Image::ImageRgb32F(rgb32f.clone()),
+            PnmEncoding::Binary
+        )
+        .is_ok());
+        match io::read_pnm(path).unwrap() {
+            Image::ImageRgb32F(img) => assert_eq!(img.pixels(), rgb32f.pixels()),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+
+        // HSV values are read back unchanged as a 3 channel float image
+        let hsv = gradient(6, 5, |idx| [idx as f32 * 11.3, 1.0 / (idx + 1) as f32, 0.7]);
+        for path in ["images/test/jade_hsv.pfm", "images/test/jade_hsv.pnm"] {
+            assert!(
+                io::write_pnm(path, &Image::ImageHsv(hsv.clone()), PnmEncoding::Binary).is_ok()
+            );
+            match io::read_pnm(path).unwrap() {
+                Image::ImageRgb32F(img) => assert_eq!(img.pixels(), hsv.pixels()),
+                value => panic!("unexpected {} image", value.to_str()),
+            }
+        }
+
+        // big endian grayscale PFM, rows are stored from bottom to top
+        let mut bytes = b"Pf\n1 2\n1.0\n".to_vec();
+        bytes.extend(2.5f32.to_be_bytes());
+        bytes.extend(0.5f32.to_be_bytes());
+        match decode_pnm(&bytes, &options).unwrap() {
+            Image::ImageGrayscale32F(img) => assert_eq!(img.as_raw_slice(), &[0.5, 2.5]),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // PAM files with any number of channels are read and written as `ImageData`
+    #[test]
+    fn pam_channels() {
+        let bands = gradient(5, 3, |idx| [idx as u8, 1, 2, 3, 4, 5, 6, 255 - idx as u8]);
+        let path = "images/test/jade_bands.pam";
+        assert!(io::write_pam(path, &bands).is_ok());
+        let img: ImageData<u8, 8> = io::read_pam(path).unwrap();
+        assert_eq!(img.pixels(), bands.pixels());
+
+        // samples are scaled to the requested color depth
+        let img16: ImageData<u16, 8> = io::read_pam(path).unwrap();
+        assert_eq!(img16.get_pixel_at(4, 2).unwrap()[7], 241 * 257);
+        // the depth must match the channels, `read_pnm` has no image type for 8 channels
+        assert!(io::read_pam::<u8, 4, _>(path).is_err());
+        assert!(io::read_pnm(path).is_err());
+
+        // PAM files with 1 to 4 channels are readable either way
+        let gray_alpha = gradient(2, 2, |idx| [idx as u16 * 1000, 65535]);
+        assert!(io::write_pam("images/test/jade_gray-alpha.pam", &gray_alpha).is_ok());
+        match io::read_pnm("images/test/jade_gray-alpha.pam").unwrap() {
+            Image::ImageGrayscaleAlpha16(img) => assert_eq!(img.pixels(), gray_alpha.pixels()),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // Bilevel images are read as 8bit grayscale images
+    #[test]
+    fn pbm() {
+        let options = ReadOptions::new();
+        let img = gradient(11, 3, |idx| [if idx % 3 == 0 { 0u8 } else { 255 }]);
+        for encoding in [PnmEncoding::Ascii, PnmEncoding::Binary] {
+            let bytes = encode_pnm(
+                &Image::ImageGrayscale(img.clone()),
+                PnmFormat::Pbm,
+                encoding,
+            )
+            .unwrap();
+            match decode_pnm(&bytes, &options).unwrap() {
+                Image::ImageGrayscale(pbm_img) => assert_eq!(pbm_img.pixels(), img.pixels()),
+                value => panic!("unexpected {} image", value.to_str()),
+            }
+        }
+
+        // bits of a plain PBM don't need to be separated
+        match decode_pnm(b"P1\n# comment\n3 2\n010\n1 1 0", &options).unwrap() {
+            Image::ImageGrayscale(pbm_img) => {
+                assert_eq!(pbm_img.as_raw_slice(), &[255, 0, 255, 0, 0, 255])
+            }
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // Files written natively are readable by the image crate and vice versa
+    #[test]
+    fn interop() {
+        let options = ReadOptions::new();
+        let rgb = gradient(9, 4, |idx| [idx as u8, (idx * 5) as u8, 255 - idx as u8]);
+        let bytes = encode_pnm(
+            &Image::ImageRgb(rgb.clone()),
+            PnmFormat::Ppm,
+            PnmEncoding::Binary,
+        )
+        .unwrap();
+        let raw_img = image::load_from_memory(&bytes).unwrap().into_rgb8();
+        assert_eq!(raw_img.as_raw().as_slice(), rgb.as_raw_slice());
+
+        let mut pam_bytes: Vec<u8> = Vec::new();
+        let gray_alpha =
+            image::GrayAlphaImage::from_raw(2, 2, vec![1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        gray_alpha
+            .write_to(
+                &mut std::io::Cursor::new(&mut pam_bytes),
+                image::ImageFormat::Pnm,
+            )
+            .unwrap();
+        match decode_pnm(&pam_bytes, &options).unwrap() {
+            Image::ImageGrayscaleAlpha(img) => {
+                assert_eq!(img.as_raw_slice(), gray_alpha.as_raw().as_slice())
+            }
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // Samples are scaled as per the max value, malformed files return `Err`
+    #[test]
+    fn max_value_and_errors() {
+        let options = ReadOptions::new();
+        match decode_pnm(b"P2 2 1 15 0 15", &options).unwrap() {
+            Image::ImageGrayscale(img) => assert_eq!(img.as_raw_slice(), &[0, 255]),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+        match decode_pnm(b"P2 2 1 1023 0 1023", &options).unwrap() {
+            Image::ImageGrayscale16(img) => assert_eq!(img.as_raw_slice(), &[0, 65535]),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+
+        assert!(decode_pnm(b"P2 2 1 15 0 16", &options).is_err());
+        assert!(decode_pnm(b"P5 2 2 255\n\x00\x01\x02", &options).is_err());
+        assert!(decode_pnm(b"P6 65535 65535 255\n\x00", &options).is_err());
+        assert!(decode_pnm(b"P7\nWIDTH 1\nHEIGHT 1\nENDHDR\n\x00", &options).is_err());
+        assert!(decode_pnm(b"P9 1 1 255\n\x00", &options).is_err());
+
+        let hsv = Image::ImageHsv(gradient(2, 2, |_| [0f32; 3]));
+        assert!(io::write_pnm("images/test/jade_hsv.ppm", &hsv, PnmEncoding::Binary).is_err());
+        assert!(io::write_pnm("images/test/jade_hsv.pam", &hsv, PnmEncoding::Binary).is_err());
+        assert!(io::write_pnm("images/test/jade_hsv.pfm", &hsv, PnmEncoding::Ascii).is_err());
+    }
+
+    // Size, allocation and format limits are checked from the header
+    #[test]
+    fn limits() {
+        let mut options = ReadOptions::new();
+        options.set_max_width(Some(100));
+        assert!(decode_pnm(b"P5 100 2 255\n\x00\x01\x02\x03", &options).is_err());
+        let bytes = [b"P5 100 2 255\n".as_slice(), &[7; 200]].concat();
+        assert!(decode_pnm(&bytes, &options).is_ok());
+        options.set_max_alloc(Some(399));
+        let bytes = [b"P5 100 2 65535\n".as_slice(), &[7; 400]].concat();
+        let err = decode_pnm(&bytes, &options).err().unwrap();
+        assert!(matches!(
+            err.kind(),
+            Some(VisionXErrorKind::LimitExceeded(_))
+        ));
+        // huge images are rejected before their samples are read
+        options.set_max_width(Some(4096));
+        options.set_max_alloc(None);
+        for bytes in [
+            b"P4 60000 60000\n".as_slice(),
+            b"PF 5000 5000 -1.0\n",
+            b"P7\nWIDTH 60000\nHEIGHT 60000\nDEPTH 4\nMAXVAL 255\nENDHDR\n",
+        ] {
+            let err = decode_pnm(bytes, &options).err().unwrap();
+            assert!(matches!(
+                err.kind(),
+                Some(VisionXErrorKind::LimitExceeded(_))
+            ));
+        }
+
+        let path = "images/test/jade_limits.pgm";
+        std::fs::write(path, b"P2 2 1 15 0 15").unwrap();
+        options.set_formats(Some(vec![image::ImageFormat::Png]));
+        assert!(io::read_pnm_with_options(path, &options).is_err());
+        options.set_formats(Some(vec![image::ImageFormat::Pnm]));
+        assert!(io::read_pnm_with_options(path, &options).is_ok());
+    }
+}
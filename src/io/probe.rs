@@ -0,0 +1,202 @@
+use crate::errors::{VisionXErrorKind, VisionXResult};
+
+use image::{ColorType, ImageDecoder, ImageFormat, ImageReader};
+use std::{fs::File, io::BufReader, path::Path};
+
+/// `ImageInfo` holds the properties of an image file read from its header, returned by `io::probe()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageInfo {
+    width: u32,
+    height: u32,
+    color_type: ColorType,
+    format: ImageFormat,
+    frame_count: u32,
+}
+
+/// Core implementation of struct `ImageInfo`
+impl ImageInfo {
+    /// Width of the image (of the canvas for animations)
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height of the image (of the canvas for animations)
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Color type of the image as returned by `io::read()` (e.g. palette images are read as RGB(A))
+    pub fn color_type(&self) -> ColorType {
+        self.color_type
+    }
+
+    /// Number of bits per sample (channel) of the image as returned by `io::read()`
+    pub fn bit_depth(&self) -> u8 {
+        (self.color_type.bits_per_pixel() / self.color_type.channel_count() as u16) as u8
+    }
+
+    /// Format of the image file, guessed from its content
+    pub fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    /// Number of frames of an animation (GIF, APNG and WebP) or pages of a TIFF, `1` for any other image
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+}
+
+/// Reads the properties (size, color type, bit depth, format and frame count) of an image file without decoding its pixels
+///
+/// Only the header is read for most formats. Frames of a GIF are counted without decompressing them and pages of a TIFF by walking its directories
+///
+/// Returns `Err` if path/file is not found, if the format is unknown or if any error occurs while reading the header
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let path = "images/jpg/lenna.jpg";
+/// let info = io::probe(path)?;
+/// if info.width() > 10_000 || info.height() > 10_000 {
+///     println!("image is too large");
+/// }
+/// # Ok(()) }
+/// ```
+pub fn probe<P: AsRef<Path>>(path: P) -> VisionXResult<ImageInfo> {
+    let path = path.as_ref();
+    let reader = ImageReader::open(path)?.with_guessed_format()?;
+    let format = match reader.format() {
+        Some(format) => format,
+        None => {
+            let err = format!("probing file {} of unknown format", path.display());
+            return Err(VisionXErrorKind::UnsupportedFormat(err).into());
+        }
+    };
+
+    let decoder = reader.into_decoder()?;
+    let (width, height) = decoder.dimensions();
+    let color_type = decoder.color_type();
+    drop(decoder);
+
+    let frame_count = match format {
+        ImageFormat::Gif => gif_frame_count(path)?,
+        ImageFormat::Png => png_frame_count(path)?,
+        ImageFormat::WebP => image_webp::WebPDecoder::new(BufReader::new(File::open(path)?))?
+            .num_frames()
+            .max(1),
+        ImageFormat::Tiff => tiff_page_count(path)?,
+        _ => 1,
+    };
+
+    Ok(ImageInfo {
+        width,
+        height,
+        color_type,
+        format,
+        frame_count,
+    })
+}
+
+/// Counts the frames of a GIF without decompressing them
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn gif_frame_count(path: &Path) -> VisionXResult<u32> {
+    let mut options = gif::DecodeOptions::new();
+    options.skip_frame_decoding(true);
+    let mut decoder = options.read_info(BufReader::new(File::open(path)?))?;
+
+    let mut count = 0;
+    while decoder.next_frame_info()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Reads the number of frames of an APNG from its animation control chunk
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn png_frame_count(path: &Path) -> VisionXResult<u32> {
+    let reader = png::Decoder::new(BufReader::new(File::open(path)?)).read_info()?;
+    Ok(reader
+        .info()
+        .animation_control()
+        .map_or(1, |animation_control| animation_control.num_frames))
+}
+
+/// Counts the pages (image file directories) of a TIFF
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn tiff_page_count(path: &Path) -> VisionXResult<u32> {
+    let mut decoder = tiff::decoder::Decoder::new(BufReader::new(File::open(path)?))?;
+
+    let mut count = 1;
+    while decoder.more_images() {
+        decoder.next_image()?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod probe_test {
+    use crate::core::{Image, ImageData};
+    use crate::io::{self, Frame};
+    use image::{ColorType, ImageFormat};
+    use ndarray::Array2;
+    use std::time::Duration;
+
+    // Properties of still images match the decoded image
+    #[test]
+    fn still_images() {
+        let info = io::probe("images/jpg/lenna.jpg").unwrap();
+        let img = io::read("images/jpg/lenna.jpg").unwrap();
+        if let Image::ImageRgb(rgb) = img {
+            assert_eq!((*rgb.width(), *rgb.height()), (info.width(), info.height()));
+        }
+        assert_eq!(ColorType::Rgb8, info.color_type());
+        assert_eq!(8, info.bit_depth());
+        assert_eq!(ImageFormat::Jpeg, info.format());
+        assert_eq!(1, info.frame_count());
+
+        let info = io::probe("images/png/basn2c16.png").unwrap();
+        assert_eq!((32, 32), (info.width(), info.height()));
+        assert_eq!(ColorType::Rgb16, info.color_type());
+        assert_eq!(16, info.bit_depth());
+        assert_eq!(ImageFormat::Png, info.format());
+        assert_eq!(1, info.frame_count());
+
+        assert!(io::probe("images/jpg/missing.jpg").is_err());
+        assert!(io::probe("images/test/README.md").is_err());
+    }
+
+    // Frames of animations and pages of TIFF are counted
+    #[test]
+    fn frame_count() {
+        let frames: Vec<Frame> = (0..3)
+            .map(|i| {
+                let pixels = Array2::from_elem((6, 4), [i * 50, 0, 0, 255]);
+                let img = Image::ImageRgba(ImageData::new(4, 6, pixels));
+                Frame::new(img, Duration::from_millis(40))
+            })
+            .collect();
+
+        for (path, format) in [
+            ("images/test/jade_probe.gif", ImageFormat::Gif),
+            ("images/test/jade_probe.png", ImageFormat::Png),
+            ("images/test/jade_probe.tiff", ImageFormat::Tiff),
+        ] {
+            io::write_animation(path, &frames, 0).unwrap();
+            let info = io::probe(path).unwrap();
+            assert_eq!((4, 6), (info.width(), info.height()));
+            assert_eq!(format, info.format());
+            assert_eq!(3, info.frame_count());
+        }
+    }
+}
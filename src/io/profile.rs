@@ -0,0 +1,280 @@
+use crate::{
+    core::{Image, ImageData},
+    errors::{VisionXErrorKind, VisionXResult},
+    io::{read_with_metadata, Metadata},
+};
+
+use moxcms::{
+    ColorProfile as IccProfile, DataColorSpace, Layout, TransformExecutor, TransformOptions,
+};
+use std::path::Path;
+use std::sync::Arc;
+
+/// `ColorProfile` represents the RGB color profiles (color spaces) an image can be converted into
+///
+/// Images without an embedded ICC profile are treated as sRGB
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorProfile {
+    /// Standard RGB color space used by most displays and the web
+    Srgb,
+    /// Wide-gamut color space used by modern displays and phone cameras
+    DisplayP3,
+    /// Wide-gamut color space used by cameras and print workflows
+    AdobeRgb,
+}
+
+/// Core implementation for enum `ColorProfile`
+impl ColorProfile {
+    /// Returns the ICC profile of the color space which can be embedded into an image file (see `Metadata::set_icc_profile()`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::io::ColorProfile;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// let icc_profile: Vec<u8> = ColorProfile::DisplayP3.to_icc()?;
+    /// assert_eq!(&icc_profile[36..40], b"acsp");
+    /// # Ok(()) }
+    /// ```
+    pub fn to_icc(&self) -> VisionXResult<Vec<u8>> {
+        Ok(self.profile().encode()?)
+    }
+
+    /// Returns the equivalent `moxcms` profile
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn profile(&self) -> IccProfile {
+        match self {
+            ColorProfile::Srgb => IccProfile::new_srgb(),
+            ColorProfile::DisplayP3 => IccProfile::new_display_p3(),
+            ColorProfile::AdobeRgb => IccProfile::new_adobe_rgb(),
+        }
+    }
+}
+
+/// Converts the pixels of an image from the color profile embedded in its metadata (sRGB if none) into another color profile
+///
+/// The ICC profile of the target color space is set in the metadata, so that it's embedded while writing the image (see `io::write_with_metadata()`). Grayscale images are returned unchanged
+///
+/// Returns `Err` if the embedded ICC profile is invalid or doesn't describe an RGB color space, or if the image is in HSV colorspace
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, ColorProfile};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let (img, mut metadata) = io::read_with_metadata("images/jpg/lenna.jpg", true)?;
+/// let p3_img = io::convert_profile(&img, &mut metadata, ColorProfile::DisplayP3)?;
+/// io::write_with_metadata("images/test/jade_lenna-p3.png", &p3_img, &metadata)?;
+/// # Ok(()) }
+/// ```
+pub fn convert_profile(
+    img: &Image,
+    metadata: &mut Metadata,
+    target: ColorProfile,
+) -> VisionXResult<Image> {
+    let source: IccProfile = match metadata.icc_profile() {
+        Some(icc_profile) => IccProfile::new_from_slice(icc_profile)?,
+        None => IccProfile::new_srgb(),
+    };
+    let converted_img: Image = match img {
+        Image::ImageGrayscale(_)
+        | Image::ImageGrayscaleAlpha(_)
+        | Image::ImageGrayscale16(_)
+        | Image::ImageGrayscaleAlpha16(_)
+        | Image::ImageGrayscale32F(_)
+        | Image::ImageGrayscaleAlpha32F(_) => return Ok(img.clone()),
+        Image::ImageHsv(_) => {
+            return Err(VisionXErrorKind::InvalidColorType(
+                "converting color profile of hsv image".to_string(),
+            )
+            .into())
+        }
+        _ if source.color_space != DataColorSpace::Rgb => {
+            return Err(VisionXErrorKind::InvalidColorType(format!(
+                "converting color profile from an embedded {:?} ICC profile",
+                source.color_space
+            ))
+            .into())
+        }
+        Image::ImageRgb(rgb) => {
+            let transform = source.create_transform_8bit(
+                Layout::Rgb,
+                &target.profile(),
+                Layout::Rgb,
+                TransformOptions::default(),
+            )?;
+            Image::ImageRgb(transform_pixels(rgb, transform)?)
+        }
+        Image::ImageRgba(rgba) => {
+            let transform = source.create_transform_8bit(
+                Layout::Rgba,
+                &target.profile(),
+                Layout::Rgba,
+                TransformOptions::default(),
+            )?;
+            Image::ImageRgba(transform_pixels(rgba, transform)?)
+        }
+        Image::ImageRgb16(rgb16) => {
+            let transform = source.create_transform_16bit(
+                Layout::Rgb,
+                &target.profile(),
+                Layout::Rgb,
+                TransformOptions::default(),
+            )?;
+            Image::ImageRgb16(transform_pixels(rgb16, transform)?)
+        }
+        Image::ImageRgba16(rgba16) => {
+            let transform = source.create_transform_16bit(
+                Layout::Rgba,
+                &target.profile(),
+                Layout::Rgba,
+                TransformOptions::default(),
+            )?;
+            Image::ImageRgba16(transform_pixels(rgba16, transform)?)
+        }
+        Image::ImageRgb32F(rgb32f) => {
+            let transform = source.create_transform_f32(
+                Layout::Rgb,
+                &target.profile(),
+                Layout::Rgb,
+                TransformOptions::default(),
+            )?;
+            Image::ImageRgb32F(transform_pixels(rgb32f, transform)?)
+        }
+        Image::ImageRgba32F(rgba32f) => {
+            let transform = source.create_transform_f32(
+                Layout::Rgba,
+                &target.profile(),
+                Layout::Rgba,
+                TransformOptions::default(),
+            )?;
+            Image::ImageRgba32F(transform_pixels(rgba32f, transform)?)
+        }
+    };
+
+    metadata.set_icc_profile(Some(target.to_icc()?));
+    Ok(converted_img)
+}
+
+/// Reads an image file along with its metadata and converts its pixels into the given color profile (see `io::read_with_metadata()` and `io::convert_profile()`)
+///
+/// Returns `Err` if path/file is not found, if any error occurs while read operation or if the embedded ICC profile can't be used for the conversion
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, ColorProfile};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let (img, metadata) = io::read_with_profile("images/jpg/cat.jpg", true, ColorProfile::Srgb)?;
+/// assert!(metadata.icc_profile().is_some());
+/// # Ok(()) }
+/// ```
+pub fn read_with_profile<P: AsRef<Path>>(
+    path: P,
+    apply_orientation: bool,
+    target: ColorProfile,
+) -> VisionXResult<(Image, Metadata)> {
+    let (img, mut metadata) = read_with_metadata(path, apply_orientation)?;
+    let img = convert_profile(&img, &mut metadata, target)?;
+
+    Ok((img, metadata))
+}
+
+/// Transforms every pixel of an image using a color transform
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn transform_pixels<T: Default + Copy, const N: usize>(
+    img: &ImageData<T, N>,
+    transform: Arc<dyn TransformExecutor<T> + Send + Sync>,
+) -> VisionXResult<ImageData<T, N>> {
+    let src: &[T] = img.as_raw_slice();
+    let mut dst: Vec<T> = vec![T::default(); src.len()];
+    transform.transform(src, &mut dst)?;
+
+    ImageData::from_raw_vec(*img.width(), *img.height(), dst)
+}
+
+#[cfg(test)]
+mod profile_test {
+    use crate::core::{Image, ImageData};
+    use crate::io::{self, ColorProfile, Metadata};
+    use ndarray::Array2;
+
+    fn first_pixel(img: &Image) -> [u8; 3] {
+        match img {
+            Image::ImageRgb(rgb) => *rgb.get_pixel_at(0, 0).unwrap(),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // sRGB red is less saturated in Display P3 and converts back without loss
+    #[test]
+    fn srgb_p3_roundtrip() {
+        let img = Image::ImageRgb(ImageData::new(4, 4, Array2::from_elem((4, 4), [255, 0, 0])));
+
+        let mut metadata = Metadata::new();
+        let p3_img = io::convert_profile(&img, &mut metadata, ColorProfile::DisplayP3).unwrap();
+        let p3_red = first_pixel(&p3_img);
+        assert!(p3_red[0] < 245 && p3_red[1] > 30 && p3_red[2] > 10);
+        assert_eq!(
+            metadata.icc_profile(),
+            Some(&ColorProfile::DisplayP3.to_icc().unwrap()[..])
+        );
+
+        let srgb_img = io::convert_profile(&p3_img, &mut metadata, ColorProfile::Srgb).unwrap();
+        let srgb_red = first_pixel(&srgb_img);
+        assert!(srgb_red[0] >= 253 && srgb_red[1] <= 2 && srgb_red[2] <= 2);
+    }
+
+    // Embedded profile is used while reading a file and replaced by the target profile
+    #[test]
+    fn read_embedded_profile() {
+        let img = Image::ImageRgb(ImageData::new(
+            4,
+            4,
+            Array2::from_elem((4, 4), [234, 51, 35]),
+        ));
+        let mut metadata = Metadata::new();
+        metadata.set_icc_profile(Some(ColorProfile::DisplayP3.to_icc().unwrap()));
+
+        let path = "images/test/jade_p3.png";
+        assert!(io::write_with_metadata(path, &img, &metadata).is_ok());
+
+        let (raw_img, raw_metadata) = io::read_with_metadata(path, false).unwrap();
+        assert_eq!(first_pixel(&raw_img), [234, 51, 35]);
+        assert_eq!(raw_metadata.icc_profile(), metadata.icc_profile());
+
+        let (srgb_img, srgb_metadata) =
+            io::read_with_profile(path, false, ColorProfile::Srgb).unwrap();
+        let srgb_red = first_pixel(&srgb_img);
+        assert!(srgb_red[0] >= 250 && srgb_red[1] <= 5 && srgb_red[2] <= 5);
+        assert_eq!(
+            srgb_metadata.icc_profile(),
+            Some(&ColorProfile::Srgb.to_icc().unwrap()[..])
+        );
+    }
+
+    // Grayscale images are unchanged, hsv images and invalid profiles return `Err`
+    #[test]
+    fn unsupported_conversion() {
+        let mut metadata = Metadata::new();
+        let gray = Image::ImageGrayscale(ImageData::new(2, 2, Array2::from_elem((2, 2), [7u8])));
+        assert!(io::convert_profile(&gray, &mut metadata, ColorProfile::AdobeRgb).is_ok());
+
+        let hsv = Image::ImageHsv(ImageData::new(2, 2, Array2::from_elem((2, 2), [0f32; 3])));
+        assert!(io::convert_profile(&hsv, &mut metadata, ColorProfile::Srgb).is_err());
+
+        let rgb = Image::ImageRgb(ImageData::new(2, 2, Array2::from_elem((2, 2), [0u8; 3])));
+        metadata.set_icc_profile(Some(vec![0u8; 64]));
+        assert!(io::convert_profile(&rgb, &mut metadata, ColorProfile::Srgb).is_err());
+    }
+}
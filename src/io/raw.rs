@@ -0,0 +1,529 @@
+use crate::{
+    core::{Image, ImageData},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+
+use super::ReadOptions;
+
+/// `PixelFormat` represents the layout of a raw 8bit YUV frame (e.g. handed by a camera or a video decoder)
+///
+/// Rows of every plane are tightly packed (no padding). Chroma planes of odd sized NV12/I420 frames are rounded up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// 4:2:0, Y plane followed by an interleaved UV plane
+    Nv12,
+    /// 4:2:0, Y plane followed by a U plane and a V plane
+    I420,
+    /// 4:2:2, packed as Y0 U Y1 V
+    Yuyv,
+    /// 4:2:2, packed as U Y0 V Y1
+    Uyvy,
+}
+
+/// Core implementation for enum `PixelFormat`
+impl PixelFormat {
+    /// Returns the number of bytes of a frame with the given size
+    ///
+    /// Returns `Err` if the number of bytes can't be represented by `usize`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vision_x::io::PixelFormat;
+    /// # use vision_x::errors::VisionXResult;
+    ///
+    /// # fn main() -> VisionXResult<()> {
+    /// assert_eq!(PixelFormat::Nv12.buffer_size(640, 480)?, 460800);
+    /// assert_eq!(PixelFormat::Yuyv.buffer_size(640, 480)?, 614400);
+    /// assert!(PixelFormat::Yuyv.buffer_size(u32::MAX, u32::MAX).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn buffer_size(&self, width: u32, height: u32) -> VisionXResult<usize> {
+        let (width, height) = (width as usize, height as usize);
+        let (chroma_width, chroma_height) = (width.div_ceil(2), height.div_ceil(2));
+        let size = match self {
+            PixelFormat::Nv12 | PixelFormat::I420 => width.checked_mul(height).and_then(|len| {
+                chroma_width
+                    .checked_mul(chroma_height)
+                    .and_then(|chroma_len| chroma_len.checked_mul(2))
+                    .and_then(|chroma_len| len.checked_add(chroma_len))
+            }),
+            PixelFormat::Yuyv | PixelFormat::Uyvy => chroma_width
+                .checked_mul(height)
+                .and_then(|len| len.checked_mul(4)),
+        };
+        size.ok_or_else(|| {
+            VisionXError::from(VisionXErrorKind::InvalidSize(format!(
+                "computing buffer size of {:?} frame of size ({}, {}), which overflows",
+                self, width, height
+            )))
+        })
+    }
+
+    /// Returns the index of the Y, U and V sample of a pixel
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn sample_index(&self, width: usize, height: usize, x: usize, y: usize) -> [usize; 3] {
+        let chroma_width = width.div_ceil(2);
+        match self {
+            PixelFormat::Nv12 => {
+                let u_idx = width * height + (y / 2) * chroma_width * 2 + (x / 2) * 2;
+                [y * width + x, u_idx, u_idx + 1]
+            }
+            PixelFormat::I420 => {
+                let u_idx = width * height + (y / 2) * chroma_width + x / 2;
+                [
+                    y * width + x,
+                    u_idx,
+                    u_idx + chroma_width * height.div_ceil(2),
+                ]
+            }
+            PixelFormat::Yuyv => {
+                let pair_idx = y * chroma_width * 4 + (x / 2) * 4;
+                [pair_idx + (x % 2) * 2, pair_idx + 1, pair_idx + 3]
+            }
+            PixelFormat::Uyvy => {
+                let pair_idx = y * chroma_width * 4 + (x / 2) * 4;
+                [pair_idx + 1 + (x % 2) * 2, pair_idx, pair_idx + 2]
+            }
+        }
+    }
+}
+
+/// `YuvColorSpace` represents the matrix (BT.601 or BT.709) and the range of the YUV samples
+///
+/// Limited (TV) range stores Y in [16, 235] and U, V in [16, 240], full (PC) range uses [0, 255]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum YuvColorSpace {
+    /// BT.601 (SD video, JPEG uses the full range), limited range
+    #[default]
+    Bt601Limited,
+    /// BT.601, full range
+    Bt601Full,
+    /// BT.709 (HD video), limited range
+    Bt709Limited,
+    /// BT.709, full range
+    Bt709Full,
+}
+
+/// Coefficients used to convert between RGB and YUV samples
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct YuvCoefficients {
+    kr: f32,
+    kb: f32,
+    y_offset: f32,
+    y_scale: f32,
+    c_scale: f32,
+}
+
+/// Core implementation of struct `YuvCoefficients`
+impl YuvCoefficients {
+    /// Returns the coefficients of a color space
+    fn new(color_space: YuvColorSpace) -> Self {
+        let (kr, kb) = match color_space {
+            YuvColorSpace::Bt601Limited | YuvColorSpace::Bt601Full => (0.299, 0.114),
+            YuvColorSpace::Bt709Limited | YuvColorSpace::Bt709Full => (0.2126, 0.0722),
+        };
+        let (y_offset, y_scale, c_scale) = match color_space {
+            YuvColorSpace::Bt601Limited | YuvColorSpace::Bt709Limited => (16.0, 219.0, 224.0),
+            YuvColorSpace::Bt601Full | YuvColorSpace::Bt709Full => (0.0, 255.0, 255.0),
+        };
+
+        YuvCoefficients {
+            kr,
+            kb,
+            y_offset,
+            y_scale,
+            c_scale,
+        }
+    }
+
+    /// Converts YUV samples into an RGB pixel
+    fn to_rgb(&self, [y, u, v]: [u8; 3]) -> [u8; 3] {
+        let luma = (f32::from(y) - self.y_offset) / self.y_scale;
+        let cb = (f32::from(u) - 128.0) / self.c_scale;
+        let cr = (f32::from(v) - 128.0) / self.c_scale;
+
+        let r = luma + 2.0 * (1.0 - self.kr) * cr;
+        let b = luma + 2.0 * (1.0 - self.kb) * cb;
+        let g = (luma - self.kr * r - self.kb * b) / (1.0 - self.kr - self.kb);
+        [r, g, b].map(|value| (value * 255.0).round().clamp(0.0, 255.0) as u8)
+    }
+
+    /// Converts an RGB pixel into unrounded YUV samples
+    fn to_yuv(&self, rgb: [u8; 3]) -> [f32; 3] {
+        let [r, g, b] = rgb.map(|value| f32::from(value) / 255.0);
+        let luma = self.kr * r + (1.0 - self.kr - self.kb) * g + self.kb * b;
+        let cb = (b - luma) / (2.0 * (1.0 - self.kb));
+        let cr = (r - luma) / (2.0 * (1.0 - self.kr));
+
+        [
+            self.y_offset + luma * self.y_scale,
+            128.0 + cb * self.c_scale,
+            128.0 + cr * self.c_scale,
+        ]
+    }
+}
+
+/// Checks the size of a raw frame and of its RGB image. Returns the number of bytes of the frame
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn check_size(
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    operation: &str,
+) -> VisionXResult<usize> {
+    let rgb_len = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|len| len.checked_mul(3));
+    if width == 0
+        || height == 0
+        || (matches!(format, PixelFormat::Yuyv | PixelFormat::Uyvy) && width % 2 != 0)
+        || rgb_len.is_none()
+    {
+        return Err(VisionXErrorKind::InvalidSize(format!(
+            "{} {:?} frame of size ({}, {})",
+            operation, format, width, height
+        ))
+        .into());
+    }
+    format.buffer_size(width, height)
+}
+
+/// Converts a raw YUV frame into an 8bit RGB image
+///
+/// The frame is converted within the default `ReadOptions` limits, use `io::read_raw_with_options()` for other limits
+///
+/// Returns `Err` if the buffer is smaller than the frame (see `PixelFormat::buffer_size()`) or if the size isn't valid for the format (YUYV/UYVY need an even width)
+///
+/// # Example
+///
+/// ```
+/// use vision_x::core::Image;
+/// use vision_x::io::{self, PixelFormat, YuvColorSpace};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// // gray NV12 frame
+/// let bytes = vec![128u8; PixelFormat::Nv12.buffer_size(64, 48)?];
+/// let img = io::read_raw(&bytes, 64, 48, PixelFormat::Nv12, YuvColorSpace::Bt709Limited)?;
+/// assert_eq!(img.to_str(), "rgb");
+/// # Ok(()) }
+/// ```
+pub fn read_raw(
+    bytes: &[u8],
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    color_space: YuvColorSpace,
+) -> VisionXResult<Image> {
+    read_raw_with_options(
+        bytes,
+        width,
+        height,
+        format,
+        color_space,
+        &ReadOptions::default(),
+    )
+}
+
+/// Converts a raw YUV frame into an 8bit RGB image within the limits of `ReadOptions`, see `io::read_raw()`. The allowed formats aren't checked, raw frames having no `ImageFormat`
+///
+/// The size of the frame and the bytes of the RGB image are checked before converting it, as the size of a raw frame usually comes along with untrusted bytes
+///
+/// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the frame exceeds a limit, or if any error occurs as for `io::read_raw()`
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, PixelFormat, ReadOptions, YuvColorSpace};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let bytes = vec![128u8; PixelFormat::Yuyv.buffer_size(64, 48)?];
+/// let mut options = ReadOptions::new();
+/// options.set_max_width(Some(32));
+/// assert!(io::read_raw_with_options(&bytes, 64, 48, PixelFormat::Yuyv, YuvColorSpace::Bt601Full, &options).is_err());
+/// # Ok(()) }
+/// ```
+pub fn read_raw_with_options(
+    bytes: &[u8],
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+    color_space: YuvColorSpace,
+    options: &ReadOptions,
+) -> VisionXResult<Image> {
+    let len = check_size(width, height, format, "reading")?;
+    // the RGB image length is known not to overflow
+    options.check_size((width, height), width as u64 * height as u64 * 3)?;
+    if bytes.len() < len {
+        return Err(VisionXError::BufferSize {
+            expected: len,
+            actual: bytes.len(),
+        });
+    }
+
+    let coefficients = YuvCoefficients::new(color_space);
+    let (w, h) = (width as usize, height as usize);
+    let mut buffer: Vec<u8> = Vec::with_capacity(w * h * 3);
+    for y in 0..h {
+        for x in 0..w {
+            let yuv = format.sample_index(w, h, x, y).map(|idx| bytes[idx]);
+            buffer.extend(coefficients.to_rgb(yuv));
+        }
+    }
+
+    Ok(Image::ImageRgb(ImageData::from_raw_vec(
+        width, height, buffer,
+    )?))
+}
+
+/// Converts an 8bit grayscale, RGB or RGBA image into a raw YUV frame (alpha channel is dropped)
+///
+/// Chroma samples are the average of the pixels sharing them
+///
+/// Returns `Err` if the image isn't an 8bit grayscale, RGB or RGBA image, or if the size isn't valid for the format (YUYV/UYVY need an even width)
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, PixelFormat, YuvColorSpace};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let img = io::read("images/jpg/lenna.jpg")?;
+/// let bytes = io::write_raw(&img, PixelFormat::I420, YuvColorSpace::Bt601Limited)?;
+/// std::fs::write("images/test/jade_lenna-doctest.yuv", bytes)?;
+/// # Ok(()) }
+/// ```
+pub fn write_raw(
+    img: &Image,
+    format: PixelFormat,
+    color_space: YuvColorSpace,
+) -> VisionXResult<Vec<u8>> {
+    match img {
+        Image::ImageGrayscale(gray) => encode_raw(gray, format, color_space, |px| [px[0]; 3]),
+        Image::ImageRgb(rgb) => encode_raw(rgb, format, color_space, |px| *px),
+        Image::ImageRgba(rgba) => encode_raw(rgba, format, color_space, |px| [px[0], px[1], px[2]]),
+        value => Err(VisionXErrorKind::InvalidColorType(format!(
+            "writing {} image into {:?} frame",
+            value.to_str(),
+            format
+        ))
+        .into()),
+    }
+}
+
+/// Converts the pixels of an image into a raw YUV frame
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn encode_raw<const N: usize, F>(
+    img: &ImageData<u8, N>,
+    format: PixelFormat,
+    color_space: YuvColorSpace,
+    to_rgb: F,
+) -> VisionXResult<Vec<u8>>
+where
+    F: Fn(&[u8; N]) -> [u8; 3],
+{
+    let (width, height) = (*img.width(), *img.height());
+    let len = check_size(width, height, format, "writing")?;
+
+    let coefficients = YuvCoefficients::new(color_space);
+    let (w, h) = (width as usize, height as usize);
+    let mut bytes: Vec<u8> = vec![0; len];
+    // number of pixel rows sharing a row of chroma samples
+    let chroma_rows = match format {
+        PixelFormat::Nv12 | PixelFormat::I420 => 2,
+        PixelFormat::Yuyv | PixelFormat::Uyvy => 1,
+    };
+    // chroma samples of a single row are accumulated, then averaged once its last pixel row is converted
+    let mut chroma_sum: Vec<[f32; 2]> = vec![[0.0; 2]; w.div_ceil(2)];
+    let mut chroma_count: Vec<u8> = vec![0; w.div_ceil(2)];
+
+    for (y, row) in img.pixels().outer_iter().enumerate() {
+        for (x, px) in row.iter().enumerate() {
+            let [luma, cb, cr] = coefficients.to_yuv(to_rgb(px));
+            bytes[format.sample_index(w, h, x, y)[0]] = luma.round().clamp(0.0, 255.0) as u8;
+            chroma_sum[x / 2][0] += cb;
+            chroma_sum[x / 2][1] += cr;
+            chroma_count[x / 2] += 1;
+        }
+
+        if y % chroma_rows == chroma_rows - 1 || y == h - 1 {
+            for (chroma_x, (sum, count)) in chroma_sum
+                .iter_mut()
+                .zip(chroma_count.iter_mut())
+                .enumerate()
+            {
+                let [_, u_idx, v_idx] = format.sample_index(w, h, chroma_x * 2, y);
+                let pixels = f32::from(*count);
+                bytes[u_idx] = (sum[0] / pixels).round().clamp(0.0, 255.0) as u8;
+                bytes[v_idx] = (sum[1] / pixels).round().clamp(0.0, 255.0) as u8;
+                *sum = [0.0; 2];
+                *count = 0;
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod raw_test {
+    use crate::core::{Image, ImageData};
+    use crate::io::{self, PixelFormat, ReadOptions, YuvColorSpace};
+    use ndarray::Array2;
+
+    const FORMATS: [PixelFormat; 4] = [
+        PixelFormat::Nv12,
+        PixelFormat::I420,
+        PixelFormat::Yuyv,
+        PixelFormat::Uyvy,
+    ];
+
+    // Y, U and V samples of a red pixel match the reference values of each color space
+    #[test]
+    fn reference_values() {
+        let red = Image::ImageRgb(ImageData::new(
+            2,
+            2,
+            Array2::from_elem((2, 2), [255u8, 0, 0]),
+        ));
+        for (color_space, yuv) in [
+            (YuvColorSpace::Bt601Limited, [81, 90, 240]),
+            (YuvColorSpace::Bt601Full, [76, 85, 255]),
+            (YuvColorSpace::Bt709Limited, [63, 102, 240]),
+            (YuvColorSpace::Bt709Full, [54, 99, 255]),
+        ] {
+            let bytes = io::write_raw(&red, PixelFormat::I420, color_space).unwrap();
+            assert_eq!(bytes, vec![yuv[0], yuv[0], yuv[0], yuv[0], yuv[1], yuv[2]]);
+        }
+
+        let white = Image::ImageGrayscale(ImageData::new(2, 1, Array2::from_elem((1, 2), [255u8])));
+        let bytes = io::write_raw(&white, PixelFormat::Uyvy, YuvColorSpace::Bt601Limited).unwrap();
+        assert_eq!(bytes, vec![128, 235, 128, 235]);
+    }
+
+    // Frames with blocks of a single color are converted back without loss of color
+    #[test]
+    fn roundtrip() {
+        let colors = [
+            [255u8, 0, 0],
+            [0, 255, 0],
+            [20, 40, 230],
+            [128, 128, 128],
+            [250, 200, 10],
+            [0, 0, 0],
+        ];
+        let pixels = Array2::from_shape_fn((4, 6), |(y, x)| colors[(y / 2) * 3 + x / 2]);
+        let img = Image::ImageRgb(ImageData::new(6, 4, pixels.clone()));
+
+        for format in FORMATS {
+            for color_space in [YuvColorSpace::Bt601Limited, YuvColorSpace::Bt709Full] {
+                let bytes = io::write_raw(&img, format, color_space).unwrap();
+                assert_eq!(bytes.len(), format.buffer_size(6, 4).unwrap());
+
+                match io::read_raw(&bytes, 6, 4, format, color_space).unwrap() {
+                    Image::ImageRgb(rgb) => {
+                        for (px, expected) in rgb.pixels().iter().zip(pixels.iter()) {
+                            for (value, expected) in px.iter().zip(expected) {
+                                assert!(
+                                    value.abs_diff(*expected) <= 2,
+                                    "{:?}: {:?} != {:?}",
+                                    format,
+                                    px,
+                                    expected
+                                );
+                            }
+                        }
+                    }
+                    value => panic!("unexpected {} image", value.to_str()),
+                }
+            }
+        }
+    }
+
+    // Chroma samples are shared by the neighbouring pixels and odd sized 4:2:0 frames are rounded up
+    #[test]
+    fn subsampling() {
+        let pixels =
+            Array2::from_shape_fn(
+                (3, 3),
+                |(_, x)| if x == 0 { [255u8, 255, 255] } else { [0, 0, 0] },
+            );
+        let img = Image::ImageRgb(ImageData::new(3, 3, pixels));
+        let bytes = io::write_raw(&img, PixelFormat::Nv12, YuvColorSpace::Bt601Full).unwrap();
+        assert_eq!(bytes.len(), 9 + 8);
+        assert_eq!(&bytes[..3], &[255, 0, 0]);
+        assert!(bytes[9..].iter().all(|&value| value == 128));
+
+        let rgb = io::read_raw(&bytes, 3, 3, PixelFormat::Nv12, YuvColorSpace::Bt601Full).unwrap();
+        match rgb {
+            Image::ImageRgb(rgb) => assert_eq!(rgb.get_pixel_at(0, 2), Some(&[255, 255, 255])),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // Small buffers, odd width of packed formats and unsupported images return `Err`
+    #[test]
+    fn errors() {
+        let bytes = vec![0u8; PixelFormat::I420.buffer_size(4, 4).unwrap() - 1];
+        assert!(
+            io::read_raw(&bytes, 4, 4, PixelFormat::I420, YuvColorSpace::Bt601Limited).is_err()
+        );
+        assert!(
+            io::read_raw(&bytes, 3, 2, PixelFormat::Yuyv, YuvColorSpace::Bt601Limited).is_err()
+        );
+
+        let rgb = Image::ImageRgb(ImageData::new(3, 2, Array2::from_elem((2, 3), [0u8; 3])));
+        assert!(io::write_raw(&rgb, PixelFormat::Uyvy, YuvColorSpace::Bt601Limited).is_err());
+        let rgb16 = Image::ImageRgb16(ImageData::new(2, 2, Array2::from_elem((2, 2), [0u16; 3])));
+        assert!(io::write_raw(&rgb16, PixelFormat::Nv12, YuvColorSpace::Bt601Limited).is_err());
+
+        // sizes which overflow the buffer length
+        for format in FORMATS {
+            assert!(format.buffer_size(u32::MAX, u32::MAX).is_err());
+            assert!(io::read_raw(
+                &bytes,
+                u32::MAX - 1,
+                u32::MAX,
+                format,
+                YuvColorSpace::Bt601Full
+            )
+            .is_err());
+        }
+
+        // the RGB image must fit into the limits
+        let bytes = vec![0u8; PixelFormat::Nv12.buffer_size(16, 16).unwrap()];
+        let mut options = ReadOptions::new();
+        options.set_max_alloc(Some(16 * 16 * 3 - 1));
+        let color_space = YuvColorSpace::Bt601Full;
+        assert!(io::read_raw_with_options(
+            &bytes,
+            16,
+            16,
+            PixelFormat::Nv12,
+            color_space,
+            &options
+        )
+        .is_err());
+        options.set_max_alloc(Some(16 * 16 * 3));
+        assert!(io::read_raw_with_options(
+            &bytes,
+            16,
+            16,
+            PixelFormat::Nv12,
+            color_space,
+            &options
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    core::Image,
+    errors::{VisionXErrorKind, VisionXResult},
+};
+
+use super::ReadOptions;
+use image::{imageops::FilterType, DynamicImage, ImageReader};
+use std::path::Path;
+
+/// Reads an image file scaled down to fit within `max_width` x `max_height` pixels (keeping its aspect ratio). Images which already fit are returned unchanged
+///
+/// The target size is computed from the file header, then the image is decoded fully and resized with a Lanczos filter
+///
+/// The image is read within the default `ReadOptions` limits, use `io::read_scaled_with_options()` for other limits
+///
+/// Returns `Err` if path/file is not found, if any size is zero or if any error occurs while read operation
+///
+/// # Example
+///
+/// ```
+/// use vision_x::core::Image;
+/// use vision_x::io;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let path = "images/jpg/cat.jpg";
+/// let thumbnail = io::read_scaled(path, 256, 256)?;
+/// if let Image::ImageRgb(rgb) = thumbnail {
+///     assert!(*rgb.width() <= 256 && *rgb.height() <= 256);
+/// }
+/// # Ok(()) }
+/// ```
+pub fn read_scaled<P: AsRef<Path>>(
+    path: P,
+    max_width: u32,
+    max_height: u32,
+) -> VisionXResult<Image> {
+    read_scaled_with_options(path, max_width, max_height, &ReadOptions::default())
+}
+
+/// Reads an image file scaled down to fit within `max_width` x `max_height` pixels, within the limits of `ReadOptions`, see `io::read_scaled()`
+///
+/// The limits apply to the size of the image in the file (not to the scaled size) and to the bytes allocated while decoding it
+///
+/// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the image exceeds a limit, with `VisionXErrorKind::UnsupportedFormat` if the format is unknown or not allowed, or if any error occurs while read operation
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{self, ReadOptions};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let mut options = ReadOptions::new();
+/// options.set_max_width(Some(1000));
+/// // cat.jpg is wider than 1000 pixels, even if its thumbnail isn't
+/// assert!(io::read_scaled_with_options("images/jpg/cat.jpg", 256, 256, &options).is_err());
+/// # Ok(()) }
+/// ```
+pub fn read_scaled_with_options<P: AsRef<Path>>(
+    path: P,
+    max_width: u32,
+    max_height: u32,
+    options: &ReadOptions,
+) -> VisionXResult<Image> {
+    if max_width == 0 || max_height == 0 {
+        let err = format!("scaling image to fit ({}, {})", max_width, max_height);
+        return Err(VisionXErrorKind::InvalidSize(err).into());
+    }
+
+    let path = path.as_ref();
+    let format = options.check_format(path)?;
+    let mut reader = ImageReader::open(path)?;
+    reader.set_format(format);
+    let (width, height) = reader.into_dimensions()?;
+    // the allocation is checked by the decoders, once the decoded size is known
+    options.check_size((width, height), 0)?;
+
+    let scale = f64::min(
+        max_width as f64 / width as f64,
+        max_height as f64 / height as f64,
+    );
+    if scale >= 1.0 {
+        return super::read_with_options(path, options);
+    }
+    let target_width = ((width as f64 * scale).round() as u32).clamp(1, max_width);
+    let target_height = ((height as f64 * scale).round() as u32).clamp(1, max_height);
+
+    let img = super::read_with_options(path, options)?;
+    resize(img, target_width, target_height)
+}
+
+/// Resizes an image with a Lanczos filter (unless it already has the given size)
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn resize(img: Image, width: u32, height: u32) -> VisionXResult<Image> {
+    let dynamic_image = DynamicImage::try_from(img)?;
+    if dynamic_image.width() == width && dynamic_image.height() == height {
+        return Image::try_from(dynamic_image);
+    }
+    Image::try_from(dynamic_image.resize_exact(width, height, FilterType::Lanczos3))
+}
+
+#[cfg(test)]
+mod scaled_test {
+    use crate::core::Image;
+    use crate::errors::{VisionXError, VisionXErrorKind, VisionXResult};
+    use crate::io::{self, ReadOptions};
+
+    // Mean absolute difference between the samples of two 8bit images of same size
+    fn mean_difference(a: &Image, b: &Image) -> f64 {
+        let (a, b) = match (a, b) {
+            (Image::ImageRgb(a), Image::ImageRgb(b)) => (a.as_raw_slice(), b.as_raw_slice()),
+            (Image::ImageGrayscale(a), Image::ImageGrayscale(b)) => {
+                (a.as_raw_slice(), b.as_raw_slice())
+            }
+            (a, b) => panic!("comparing {} with {}", a.to_str(), b.to_str()),
+        };
+        assert_eq!(a.len(), b.len());
+        let total: u64 = a.iter().zip(b).map(|(&a, &b)| a.abs_diff(b) as u64).sum();
+        total as f64 / a.len() as f64
+    }
+
+    // Thumbnails fit the given size and match a resized full decode
+    #[test]
+    fn thumbnails() {
+        let path = "images/jpg/cat.jpg";
+        let reference = match io::read(path).unwrap() {
+            Image::ImageRgb(rgb) => {
+                image::DynamicImage::from(image::RgbImage::try_from(rgb).unwrap())
+            }
+            value => panic!("unexpected {} image", value.to_str()),
+        };
+
+        for (max_width, max_height) in [(800, 800), (300, 200), (100, 100), (64, 500)] {
+            let img = io::read_scaled(path, max_width, max_height).unwrap();
+            let (width, height) = match &img {
+                Image::ImageRgb(rgb) => (*rgb.width(), *rgb.height()),
+                value => panic!("unexpected {} image", value.to_str()),
+            };
+            assert!(width <= max_width && height <= max_height);
+            assert!(width == max_width || height == max_height);
+
+            let resized =
+                reference.resize_exact(width, height, image::imageops::FilterType::Lanczos3);
+            assert!(mean_difference(&img, &Image::try_from(resized).unwrap()) < 3.0);
+        }
+
+        // single component jpeg
+        let gray = match io::read(path).unwrap().grayscale() {
+            Image::ImageGrayscale(gray) => gray,
+            value => panic!("unexpected {} image", value.to_str()),
+        };
+        let path = "images/test/jade_scaled-gray.jpg";
+        io::write(path, &Image::ImageGrayscale(gray.clone())).unwrap();
+        let img = io::read_scaled(path, 200, 200).unwrap();
+        let reference = image::DynamicImage::from(image::GrayImage::try_from(gray).unwrap())
+            .resize_exact(200, 200, image::imageops::FilterType::Lanczos3);
+        assert!(mean_difference(&img, &Image::try_from(reference).unwrap()) < 3.0);
+    }
+
+    // Images which fit are not scaled, other formats are resized
+    #[test]
+    fn other_images() {
+        match io::read_scaled("images/jpg/lenna.jpg", 300, 300).unwrap() {
+            Image::ImageRgb(rgb) => assert_eq!((225, 225), (*rgb.width(), *rgb.height())),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+        match io::read_scaled("images/png/basn2c16.png", 16, 100).unwrap() {
+            Image::ImageRgb16(rgb) => assert_eq!((16, 16), (*rgb.width(), *rgb.height())),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+        assert!(io::read_scaled("images/jpg/lenna.jpg", 0, 10).is_err());
+    }
+
+    // Limits apply to the size of the image in the file, huge frames are rejected before allocating them
+    #[test]
+    fn limits() {
+        let is_limit_exceeded = |res: VisionXResult<Image>| {
+            matches!(
+                res,
+                Err(VisionXError::Kind(VisionXErrorKind::LimitExceeded(_)))
+            )
+        };
+        let mut options = ReadOptions::new();
+        options.set_max_width(Some(1000));
+        assert!(is_limit_exceeded(io::read_scaled_with_options(
+            "images/jpg/cat.jpg",
+            256,
+            256,
+            &options
+        )));
+
+        // frame of 65535 x 65535 pixels
+        let mut data = std::fs::read("images/jpg/lenna.jpg").unwrap();
+        let sof = data.windows(2).position(|w| w == [0xFF, 0xC0]).unwrap();
+        data[sof + 5..sof + 9].fill(0xFF);
+        let path = "images/test/jade_scaled-huge.jpg";
+        std::fs::write(path, &data).unwrap();
+        let mut options = ReadOptions::new();
+        options.set_max_alloc(Some(16 * 1024 * 1024));
+        assert!(is_limit_exceeded(io::read_scaled_with_options(
+            path, 64, 64, &options
+        )));
+    }
+}
@@ -0,0 +1,1142 @@
+use crate::{
+    core::{Image, ImageData},
+    errors::{VisionXError, VisionXErrorKind, VisionXResult},
+};
+
+use super::ReadOptions;
+use image::ImageFormat;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+use tiff::{
+    decoder::{ChunkType, DecodingResult},
+    encoder::{TiffEncoder, TiffKindBig},
+    tags::{
+        CompressionMethod, ExtraSamples, PhotometricInterpretation, PlanarConfiguration,
+        SampleFormat, Tag,
+    },
+};
+
+/// `Tile` is a rectangular portion of an image, returned by `TiledReader` along with its offset (top-left corner) in the whole image
+pub struct Tile {
+    x: u32,
+    y: u32,
+    image: Image,
+}
+
+/// Core implementation of struct `Tile`
+impl Tile {
+    /// Horizontal offset of the tile in the whole image
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Vertical offset of the tile in the whole image
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// Borrow the pixels of the tile
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Moves the pixels out of the tile
+    pub fn into_image(self) -> Image {
+        self.image
+    }
+}
+
+/// `TiledReader` reads an image tile by tile (left to right, top to bottom) without decoding the whole image at once
+///
+/// PNG (non-interlaced) and TIFF (strips or tiles) files are streamed, only the rows of the current band of tiles are held in memory. Any other format is decoded at once and then split into tiles
+///
+/// # Example
+///
+/// ```
+/// use vision_x::core::Image;
+/// use vision_x::io::TiledReader;
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let path = "images/jpg/lenna.jpg";
+/// let reader = TiledReader::open(path, 128, 128)?;
+/// for tile in reader {
+///     let tile = tile?;
+///     if let Image::ImageRgb(rgb) = tile.image() {
+///         assert!(*rgb.width() <= 128 && *rgb.height() <= 128);
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+pub struct TiledReader {
+    source: Box<dyn RowSource>,
+    width: u32,
+    height: u32,
+    channels: usize,
+    tile_width: u32,
+    tile_height: u32,
+    // rows decoded from the source but not yet moved into a band
+    buffer: Option<Samples>,
+    band: Option<Samples>,
+    band_y: u32,
+    band_height: u32,
+    x: u32,
+}
+
+/// Core implementation of struct `TiledReader`
+impl TiledReader {
+    /// Opens an image file to be read in tiles of (at most) `tile_width` x `tile_height` pixels. Tiles on the right and bottom edge are smaller if the image size is not a multiple of the tile size
+    ///
+    /// The image is read within the default `ReadOptions` limits, use `TiledReader::open_with_options()` for other limits
+    ///
+    /// Returns `Err` if path/file is not found, if the tile size is zero or if any error occurs while reading the file header
+    pub fn open<P: AsRef<Path>>(path: P, tile_width: u32, tile_height: u32) -> VisionXResult<Self> {
+        Self::open_with_options(path, tile_width, tile_height, &ReadOptions::default())
+    }
+
+    /// Opens an image file to be read in tiles within the limits of `ReadOptions`, see `TiledReader::open()`
+    ///
+    /// The size of the image and the bytes of a band of tiles are checked before decoding any pixel. Formats which can't be read in bands are decoded at once and checked as by `io::read_with_options()`
+    ///
+    /// Returns `Err` with `VisionXErrorKind::LimitExceeded` if the image exceeds a limit, with `VisionXErrorKind::UnsupportedFormat` if the format is unknown or not allowed, if path/file is not found, if the tile size is zero or if any error occurs while reading the file header
+    pub fn open_with_options<P: AsRef<Path>>(
+        path: P,
+        tile_width: u32,
+        tile_height: u32,
+        options: &ReadOptions,
+    ) -> VisionXResult<Self> {
+        if tile_width == 0 || tile_height == 0 {
+            let err = format!("reading tiles of size ({}, {})", tile_width, tile_height);
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+
+        let path = path.as_ref();
+        let format = options.check_format(path)?;
+        let band_bytes = |width: u32, channels: usize, sample_bytes: u64| {
+            u64::from(width) * u64::from(tile_height) * channels as u64 * sample_bytes
+        };
+        let (source, width, height, channels): (Box<dyn RowSource>, u32, u32, usize) = match format
+        {
+            ImageFormat::Png => {
+                let source = PngRows::new(path, options)?;
+                let (width, height, channels) = (source.width, source.height, source.channels);
+                let sample_bytes = if source.sixteen { 2 } else { 1 };
+                options.check_size((width, height), band_bytes(width, channels, sample_bytes))?;
+                (Box::new(source), width, height, channels)
+            }
+            ImageFormat::Tiff => {
+                let source = TiffRows::new(path, options)?;
+                let (width, height, channels) = (source.width, source.height, source.channels);
+                let sample_bytes = u64::from(source.bits) / 8;
+                options.check_size((width, height), band_bytes(width, channels, sample_bytes))?;
+                (Box::new(source), width, height, channels)
+            }
+            _ => {
+                let img = super::read_with_options(path, options)?;
+                let (width, height) = dimensions(&img);
+                let (samples, channels) = Samples::from_image(&img)?;
+                (Box::new(WholeRows(Some(samples))), width, height, channels)
+            }
+        };
+
+        Ok(TiledReader {
+            source,
+            width,
+            height,
+            channels,
+            tile_width,
+            tile_height,
+            buffer: None,
+            band: None,
+            band_y: 0,
+            band_height: 0,
+            x: 0,
+        })
+    }
+
+    /// Width of the whole image
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height of the whole image
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Reads the next band of rows (if needed) and copies the next tile out of it
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn next_tile(&mut self) -> VisionXResult<Option<Tile>> {
+        if self.band.is_none() || self.x >= self.width {
+            let y = self.band_y + self.band_height;
+            if y >= self.height {
+                self.band = None;
+                return Ok(None);
+            }
+            let rows = self.tile_height.min(self.height - y);
+            self.band = Some(self.take_rows(rows)?);
+            self.band_y = y;
+            self.band_height = rows;
+            self.x = 0;
+        }
+
+        let row_len = self.width as usize * self.channels;
+        let width = self.tile_width.min(self.width - self.x);
+        let start = self.x as usize * self.channels;
+        let end = start + width as usize * self.channels;
+        let samples = match &self.band {
+            Some(band) => band.rect(row_len, start..end),
+            None => return Ok(None),
+        };
+
+        let tile = Tile {
+            x: self.x,
+            y: self.band_y,
+            image: samples.into_image(width, self.band_height, self.channels)?,
+        };
+        self.x += self.tile_width;
+        Ok(Some(tile))
+    }
+
+    /// Decodes rows from the source until `rows` rows are available and moves them out of the buffer
+    ///
+    /// ## Note
+    /// Function is used internally (private scope)
+    fn take_rows(&mut self, rows: u32) -> VisionXResult<Samples> {
+        let len = rows as usize * self.width as usize * self.channels;
+        loop {
+            let available = self.buffer.as_ref().map_or(0, Samples::len);
+            if available >= len {
+                break;
+            }
+            let samples = match self.source.next_rows()? {
+                Some(samples) => samples,
+                None => {
+                    let err = format!(
+                        "image data ends before row {} of {}",
+                        self.band_y + self.band_height + rows,
+                        self.height
+                    );
+                    return Err(VisionXErrorKind::InvalidData(err).into());
+                }
+            };
+            match self.buffer.as_mut() {
+                Some(buffer) => buffer.append(samples)?,
+                None => self.buffer = Some(samples),
+            }
+        }
+
+        match self.buffer.take() {
+            Some(mut band) => {
+                let rest = band.split_off(len);
+                if rest.len() > 0 {
+                    self.buffer = Some(rest);
+                }
+                Ok(band)
+            }
+            None => Ok(Samples::U8(Vec::new())),
+        }
+    }
+}
+
+/// Implements `Iterator` for `TiledReader`
+impl Iterator for TiledReader {
+    type Item = VisionXResult<Tile>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tile = self.next_tile();
+        if tile.is_err() {
+            // a failure ends the iteration
+            self.band_y = self.height;
+            self.band_height = 0;
+            self.band = None;
+        }
+        tile.transpose()
+    }
+}
+
+/// `TiledWriter` writes an image tile by tile without holding the whole image in memory. Supports PNG (8bit and 16bit) and TIFF (8bit, 16bit and 32bit float) files, the format is chosen by the file extension
+///
+/// Tiles are laid on a grid of `tile_width` x `tile_height` pixels (smaller on the right and bottom edge) and must be written band by band: every tile of a row of tiles must be written (in any order) before moving to the next row. Only one band of tiles is held in memory. The color type of the image is taken from the first tile
+///
+/// The file is removed if the writer is dropped without being finished, or if `finish()` fails, so that no truncated image is left behind
+///
+/// # Example
+///
+/// ```
+/// use vision_x::io::{TiledReader, TiledWriter};
+/// # use vision_x::errors::VisionXResult;
+///
+/// # fn main() -> VisionXResult<()> {
+/// let reader = TiledReader::open("images/jpg/lenna.jpg", 256, 64)?;
+/// let (width, height) = (reader.width(), reader.height());
+/// let mut writer = TiledWriter::create("images/test/jade_lenna-tiled.tiff", width, height, 256, 64)?;
+/// for tile in reader {
+///     let tile = tile?;
+///     writer.write_tile(tile.x(), tile.y(), tile.image())?;
+/// }
+/// writer.finish()?;
+/// # Ok(()) }
+/// ```
+pub struct TiledWriter {
+    format: ImageFormat,
+    path: PathBuf,
+    finished: bool,
+    file: Option<File>,
+    sink: Option<Box<dyn RowSink>>,
+    width: u32,
+    height: u32,
+    tile_width: u32,
+    tile_height: u32,
+    channels: usize,
+    band: Option<Samples>,
+    band_y: u32,
+    written: Vec<bool>,
+}
+
+/// Core implementation of struct `TiledWriter`
+impl TiledWriter {
+    /// Creates an image file of size `width` x `height` to be written in tiles of `tile_width` x `tile_height` pixels
+    ///
+    /// Returns `Err` if the format is not supported, if any size is zero or if the file cannot be created
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        width: u32,
+        height: u32,
+        tile_width: u32,
+        tile_height: u32,
+    ) -> VisionXResult<Self> {
+        if width == 0 || height == 0 || tile_width == 0 || tile_height == 0 {
+            let err = format!(
+                "writing image of size ({}, {}) in tiles of size ({}, {})",
+                width, height, tile_width, tile_height
+            );
+            return Err(VisionXErrorKind::InvalidSize(err).into());
+        }
+
+        let path = path.as_ref();
+        let format = match ImageFormat::from_path(path) {
+            Ok(format @ (ImageFormat::Png | ImageFormat::Tiff)) => format,
+            _ => {
+                let err = format!("writing tiles into file {}", path.display());
+                return Err(VisionXErrorKind::UnsupportedFormat(err).into());
+            }
+        };
+
+        Ok(TiledWriter {
+            format,
+            path: path.to_path_buf(),
+            finished: false,
+            file: Some(File::create(path)?),
+            sink: None,
+            width,
+            height,
+            tile_width,
+            tile_height,
+            channels: 0,
+            band: None,
+            band_y: 0,
+            written: vec![false; width.div_ceil(tile_width) as usize],
+        })
+    }
+
+    /// Writes a tile at offset (`x`, `y`). The offset must lie on the tile grid of the current band and the tile must have the size of that grid cell
+    ///
+    /// Returns `Err` if the tile is misplaced, has the wrong size or color type, or if any error occurs while writing the file
+    pub fn write_tile(&mut self, x: u32, y: u32, tile: &Image) -> VisionXResult<()> {
+        if y != self.band_y || x >= self.width || x % self.tile_width != 0 {
+            let err = format!(
+                "writing tile at ({}, {}), expected a tile of the band at row {} on a grid of ({}, {})",
+                x, y, self.band_y, self.tile_width, self.tile_height
+            );
+            return Err(VisionXErrorKind::IndexOutofBound(err).into());
+        }
+
+        let width = self.tile_width.min(self.width - x);
+        let rows = self.tile_height.min(self.height - y);
+        if dimensions(tile) != (width, rows) {
+            return Err(VisionXError::SizeMismatch {
+                expected: (width, rows),
+                actual: dimensions(tile),
+            });
+        }
+
+        let (samples, channels) = Samples::from_image(tile)?;
+        if self.sink.is_none() {
+            let file = match self.file.take() {
+                Some(file) => file,
+                None => {
+                    let err = "writing tile into a failed file".to_string();
+                    return Err(VisionXErrorKind::InvalidData(err).into());
+                }
+            };
+            self.sink = Some(match self.format {
+                ImageFormat::Png => Box::new(PngSink::new(
+                    file,
+                    self.width,
+                    self.height,
+                    channels,
+                    &samples,
+                )?),
+                _ => Box::new(TiffSink::new(
+                    file,
+                    self.width,
+                    self.height,
+                    self.tile_height,
+                    channels,
+                    &samples,
+                )?),
+            });
+            self.channels = channels;
+        }
+        if channels != self.channels {
+            let err = format!(
+                "writing {} tile into an image with {} channels",
+                tile.to_str(),
+                self.channels
+            );
+            return Err(VisionXErrorKind::InvalidColorType(err).into());
+        }
+
+        let row_len = self.width as usize * self.channels;
+        let band = self
+            .band
+            .get_or_insert_with(|| samples.zeros(rows as usize * row_len));
+        band.copy_rect(&samples, row_len, x as usize * self.channels)?;
+        self.written[(x / self.tile_width) as usize] = true;
+
+        if self.written.iter().all(|&written| written) {
+            if let (Some(sink), Some(band)) = (self.sink.as_mut(), self.band.take()) {
+                sink.write_rows(&band)?;
+            }
+            self.written.fill(false);
+            self.band_y += rows;
+        }
+        Ok(())
+    }
+
+    /// Completes the file after every tile has been written
+    ///
+    /// Returns `Err` if some tiles are missing or if any error occurs while writing the file, the file is removed then
+    pub fn finish(mut self) -> VisionXResult<()> {
+        match self.sink.take() {
+            Some(sink) if self.band_y >= self.height => {
+                sink.finish()?;
+                self.finished = true;
+                Ok(())
+            }
+            _ => {
+                let err = format!(
+                    "finishing tiled image of size ({}, {}) with only {} rows written",
+                    self.width, self.height, self.band_y
+                );
+                Err(VisionXErrorKind::InvalidSize(err).into())
+            }
+        }
+    }
+}
+
+/// Removes the file of an unfinished image
+impl Drop for TiledWriter {
+    fn drop(&mut self) {
+        if !self.finished {
+            // the file is closed before being removed
+            self.sink = None;
+            self.file = None;
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Samples of an 8bit, 16bit or 32bit float image stored row after row
+///
+/// ## Note
+/// Enum is used internally (private scope)
+enum Samples {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    F32(Vec<f32>),
+}
+
+/// Applies an expression on the buffer of `Samples` and wraps the result into the same variant
+///
+/// ## Note
+/// Macro is used internally (private scope)
+macro_rules! map_samples {
+    ($samples:expr, $values:ident => $body:expr) => {
+        match $samples {
+            Samples::U8($values) => Samples::U8($body),
+            Samples::U16($values) => Samples::U16($body),
+            Samples::F32($values) => Samples::F32($body),
+        }
+    };
+}
+
+/// Core implementation of enum `Samples`
+impl Samples {
+    /// Number of samples
+    fn len(&self) -> usize {
+        match self {
+            Samples::U8(values) => values.len(),
+            Samples::U16(values) => values.len(),
+            Samples::F32(values) => values.len(),
+        }
+    }
+
+    /// Zero filled samples of the same type
+    fn zeros(&self, len: usize) -> Samples {
+        map_samples!(self, _values => vec![Default::default(); len])
+    }
+
+    /// Splits the samples at `len`, returns the samples after it
+    fn split_off(&mut self, len: usize) -> Samples {
+        map_samples!(self, values => values.split_off(len))
+    }
+
+    /// Appends samples of the same type
+    fn append(&mut self, other: Samples) -> VisionXResult<()> {
+        match (self, other) {
+            (Samples::U8(values), Samples::U8(mut other)) => values.append(&mut other),
+            (Samples::U16(values), Samples::U16(mut other)) => values.append(&mut other),
+            (Samples::F32(values), Samples::F32(mut other)) => values.append(&mut other),
+            _ => {
+                let err = "appending rows of different depths".to_string();
+                return Err(VisionXErrorKind::InvalidImageDepthSize(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the range `columns` (in samples) out of every row of `row_len` samples
+    fn rect(&self, row_len: usize, columns: std::ops::Range<usize>) -> Samples {
+        map_samples!(self, values => values
+            .chunks_exact(row_len)
+            .flat_map(|row| row[columns.clone()].iter().copied())
+            .collect())
+    }
+
+    /// Copies every row of `src` into the rows of `self` (of `row_len` samples) starting at sample `start`
+    fn copy_rect(&mut self, src: &Samples, row_len: usize, start: usize) -> VisionXResult<()> {
+        fn copy<T: Copy>(dst: &mut [T], src: &[T], row_len: usize, start: usize) {
+            let rows = dst.len() / row_len;
+            let src_len = src.len() / rows.max(1);
+            for (dst, src) in dst.chunks_exact_mut(row_len).zip(src.chunks_exact(src_len)) {
+                dst[start..start + src_len].copy_from_slice(src);
+            }
+        }
+
+        match (self, src) {
+            (Samples::U8(dst), Samples::U8(src)) => copy(dst, src, row_len, start),
+            (Samples::U16(dst), Samples::U16(src)) => copy(dst, src, row_len, start),
+            (Samples::F32(dst), Samples::F32(src)) => copy(dst, src, row_len, start),
+            _ => {
+                let err = "copying pixels of different depths".to_string();
+                return Err(VisionXErrorKind::InvalidImageDepthSize(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the samples of an image, returns the samples and the number of channels
+    fn from_image(img: &Image) -> VisionXResult<(Samples, usize)> {
+        Ok(match img {
+            Image::ImageGrayscale(data) => (Samples::U8(data.as_raw_slice().to_vec()), 1),
+            Image::ImageGrayscaleAlpha(data) => (Samples::U8(data.as_raw_slice().to_vec()), 2),
+            Image::ImageRgb(data) => (Samples::U8(data.as_raw_slice().to_vec()), 3),
+            Image::ImageRgba(data) => (Samples::U8(data.as_raw_slice().to_vec()), 4),
+            Image::ImageGrayscale16(data) => (Samples::U16(data.as_raw_slice().to_vec()), 1),
+            Image::ImageGrayscaleAlpha16(data) => (Samples::U16(data.as_raw_slice().to_vec()), 2),
+            Image::ImageRgb16(data) => (Samples::U16(data.as_raw_slice().to_vec()), 3),
+            Image::ImageRgba16(data) => (Samples::U16(data.as_raw_slice().to_vec()), 4),
+            Image::ImageGrayscale32F(data) => (Samples::F32(data.as_raw_slice().to_vec()), 1),
+            Image::ImageGrayscaleAlpha32F(data) => (Samples::F32(data.as_raw_slice().to_vec()), 2),
+            Image::ImageRgb32F(data) => (Samples::F32(data.as_raw_slice().to_vec()), 3),
+            Image::ImageRgba32F(data) => (Samples::F32(data.as_raw_slice().to_vec()), 4),
+            Image::ImageHsv(_) => {
+                let err = "splitting hsv image into tiles".to_string();
+                return Err(VisionXErrorKind::InvalidColorType(err).into());
+            }
+        })
+    }
+
+    /// Moves the samples into an image of the given size and number of channels
+    fn into_image(self, width: u32, height: u32, channels: usize) -> VisionXResult<Image> {
+        Ok(match (channels, self) {
+            (1, Samples::U8(buf)) => {
+                Image::ImageGrayscale(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (2, Samples::U8(buf)) => {
+                Image::ImageGrayscaleAlpha(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (3, Samples::U8(buf)) => Image::ImageRgb(ImageData::from_raw_vec(width, height, buf)?),
+            (4, Samples::U8(buf)) => Image::ImageRgba(ImageData::from_raw_vec(width, height, buf)?),
+            (1, Samples::U16(buf)) => {
+                Image::ImageGrayscale16(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (2, Samples::U16(buf)) => {
+                Image::ImageGrayscaleAlpha16(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (3, Samples::U16(buf)) => {
+                Image::ImageRgb16(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (4, Samples::U16(buf)) => {
+                Image::ImageRgba16(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (1, Samples::F32(buf)) => {
+                Image::ImageGrayscale32F(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (2, Samples::F32(buf)) => {
+                Image::ImageGrayscaleAlpha32F(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (3, Samples::F32(buf)) => {
+                Image::ImageRgb32F(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (4, Samples::F32(buf)) => {
+                Image::ImageRgba32F(ImageData::from_raw_vec(width, height, buf)?)
+            }
+            (channels, _) => {
+                let err = format!("reading tile with {} channels", channels);
+                return Err(VisionXErrorKind::InvalidColorType(err).into());
+            }
+        })
+    }
+}
+
+/// Returns the width and height of an image
+///
+/// ## Note
+/// Function is used internally (private scope)
+fn dimensions(img: &Image) -> (u32, u32) {
+    macro_rules! size {
+        ($data:expr) => {
+            (*$data.width(), *$data.height())
+        };
+    }
+
+    match img {
+        Image::ImageGrayscale(data) => size!(data),
+        Image::ImageGrayscaleAlpha(data) => size!(data),
+        Image::ImageRgb(data) => size!(data),
+        Image::ImageRgba(data) => size!(data),
+        Image::ImageGrayscale16(data) => size!(data),
+        Image::ImageGrayscaleAlpha16(data) => size!(data),
+        Image::ImageRgb16(data) => size!(data),
+        Image::ImageRgba16(data) => size!(data),
+        Image::ImageGrayscale32F(data) => size!(data),
+        Image::ImageGrayscaleAlpha32F(data) => size!(data),
+        Image::ImageRgb32F(data) => size!(data),
+        Image::ImageRgba32F(data) => size!(data),
+        Image::ImageHsv(data) => size!(data),
+    }
+}
+
+/// Source of decoded rows, every call returns one or more whole rows
+///
+/// ## Note
+/// Trait is used internally (private scope)
+trait RowSource {
+    /// Decodes the next rows, returns `None` once every row is decoded
+    fn next_rows(&mut self) -> VisionXResult<Option<Samples>>;
+}
+
+/// Rows of an image decoded at once
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct WholeRows(Option<Samples>);
+
+/// Implements `RowSource` for `WholeRows`
+impl RowSource for WholeRows {
+    fn next_rows(&mut self) -> VisionXResult<Option<Samples>> {
+        Ok(self.0.take())
+    }
+}
+
+/// Rows of a PNG decoded one at a time
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct PngRows {
+    reader: png::Reader<BufReader<File>>,
+    width: u32,
+    height: u32,
+    channels: usize,
+    sixteen: bool,
+}
+
+/// Core implementation of struct `PngRows`
+impl PngRows {
+    /// Reads the header of a PNG
+    fn new(path: &Path, options: &ReadOptions) -> VisionXResult<Self> {
+        let limits = png::Limits {
+            bytes: options.max_buffer_size(),
+        };
+        let mut decoder = png::Decoder::new_with_limits(BufReader::new(File::open(path)?), limits);
+        // palette and low bit depth images are expanded into 8bit samples
+        decoder.set_transformations(png::Transformations::EXPAND);
+        let reader = decoder.read_info()?;
+
+        let info = reader.info();
+        let (width, height) = (info.width, info.height);
+        if info.interlaced {
+            let err = "reading interlaced png in tiles".to_string();
+            return Err(VisionXErrorKind::UnsupportedFormat(err).into());
+        }
+
+        let (color_type, bit_depth) = reader.output_color_type();
+        Ok(PngRows {
+            reader,
+            width,
+            height,
+            channels: color_type.samples(),
+            sixteen: bit_depth == png::BitDepth::Sixteen,
+        })
+    }
+}
+
+/// Implements `RowSource` for `PngRows`
+impl RowSource for PngRows {
+    fn next_rows(&mut self) -> VisionXResult<Option<Samples>> {
+        let row = match self.reader.next_row()? {
+            Some(row) => row.data(),
+            None => return Ok(None),
+        };
+        Ok(Some(if self.sixteen {
+            Samples::U16(
+                row.chunks_exact(2)
+                    .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+                    .collect(),
+            )
+        } else {
+            Samples::U8(row.to_vec())
+        }))
+    }
+}
+
+/// Rows of a TIFF decoded one strip (or one row of tiles) at a time
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct TiffRows {
+    decoder: tiff::decoder::Decoder<BufReader<File>>,
+    width: u32,
+    height: u32,
+    channels: usize,
+    bits: u8,
+    next_chunk: u32,
+    chunk_count: u32,
+    chunks_across: u32,
+}
+
+/// Core implementation of struct `TiffRows`
+impl TiffRows {
+    /// Reads the header of a TIFF
+    fn new(path: &Path, options: &ReadOptions) -> VisionXResult<Self> {
+        let mut limits = tiff::decoder::Limits::default();
+        limits.decoding_buffer_size = options.max_buffer_size();
+        let mut decoder =
+            tiff::decoder::Decoder::new(BufReader::new(File::open(path)?))?.with_limits(limits);
+        let (width, height) = decoder.dimensions()?;
+        let (channels, bits) = match decoder.colortype()? {
+            tiff::ColorType::Gray(bits @ (8 | 16 | 32)) => (1, bits),
+            tiff::ColorType::GrayA(bits @ (8 | 16 | 32)) => (2, bits),
+            tiff::ColorType::RGB(bits @ (8 | 16 | 32)) => (3, bits),
+            tiff::ColorType::RGBA(bits @ (8 | 16 | 32)) => (4, bits),
+            color_type => {
+                return Err(VisionXErrorKind::InvalidColorType(format!(
+                    "reading {:?} tiff in tiles",
+                    color_type
+                ))
+                .into())
+            }
+        };
+
+        let (chunk_count, chunks_across) = match decoder.get_chunk_type() {
+            ChunkType::Strip => (decoder.strip_count()?, 1),
+            ChunkType::Tile => (
+                decoder.tile_count()?,
+                width.div_ceil(decoder.chunk_dimensions().0),
+            ),
+        };
+        Ok(TiffRows {
+            decoder,
+            width,
+            height,
+            channels,
+            bits,
+            next_chunk: 0,
+            chunk_count,
+            chunks_across,
+        })
+    }
+
+    /// Decodes a chunk into samples
+    fn read_chunk(&mut self, index: u32) -> VisionXResult<Samples> {
+        Ok(match self.decoder.read_chunk(index)? {
+            DecodingResult::U8(buffer) => Samples::U8(buffer),
+            DecodingResult::U16(buffer) => Samples::U16(buffer),
+            DecodingResult::F32(buffer) => Samples::F32(buffer),
+            _ => {
+                let err =
+                    "reading tiff samples which are not 8bit, 16bit or 32bit float".to_string();
+                return Err(VisionXErrorKind::InvalidImageDepthSize(err).into());
+            }
+        })
+    }
+}
+
+/// Implements `RowSource` for `TiffRows`
+impl RowSource for TiffRows {
+    fn next_rows(&mut self) -> VisionXResult<Option<Samples>> {
+        if self.next_chunk >= self.chunk_count {
+            return Ok(None);
+        }
+        let first = self.next_chunk;
+        self.next_chunk += self.chunks_across;
+        if self.chunks_across == 1 {
+            return self.read_chunk(first).map(Some);
+        }
+
+        // tiles of a row are placed side by side into one band of rows
+        let row_len = self.width as usize * self.channels;
+        let mut band: Option<Samples> = None;
+        let mut start = 0;
+        for index in first..(first + self.chunks_across).min(self.chunk_count) {
+            let (tile_width, tile_height) = self.decoder.chunk_data_dimensions(index);
+            let tile = self.read_chunk(index)?;
+            let band = band.get_or_insert_with(|| tile.zeros(tile_height as usize * row_len));
+            band.copy_rect(&tile, row_len, start)?;
+            start += tile_width as usize * self.channels;
+        }
+        Ok(band)
+    }
+}
+
+/// Destination of encoded rows
+///
+/// ## Note
+/// Trait is used internally (private scope)
+trait RowSink {
+    /// Encodes one or more whole rows
+    fn write_rows(&mut self, samples: &Samples) -> VisionXResult<()>;
+
+    /// Completes the file
+    fn finish(self: Box<Self>) -> VisionXResult<()>;
+}
+
+/// PNG encoded row by row
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct PngSink {
+    writer: png::StreamWriter<'static, BufWriter<File>>,
+}
+
+/// Core implementation of struct `PngSink`
+impl PngSink {
+    /// Writes the header of a PNG
+    fn new(
+        file: File,
+        width: u32,
+        height: u32,
+        channels: usize,
+        samples: &Samples,
+    ) -> VisionXResult<Self> {
+        let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+        encoder.set_color(match channels {
+            1 => png::ColorType::Grayscale,
+            2 => png::ColorType::GrayscaleAlpha,
+            3 => png::ColorType::Rgb,
+            _ => png::ColorType::Rgba,
+        });
+        encoder.set_depth(match samples {
+            Samples::U8(_) => png::BitDepth::Eight,
+            Samples::U16(_) => png::BitDepth::Sixteen,
+            Samples::F32(_) => {
+                let err = "writing 32bit float tiles into png".to_string();
+                return Err(VisionXErrorKind::InvalidImageDepthSize(err).into());
+            }
+        });
+        let writer = encoder.write_header()?.into_stream_writer()?;
+        Ok(PngSink { writer })
+    }
+}
+
+/// Implements `RowSink` for `PngSink`
+impl RowSink for PngSink {
+    fn write_rows(&mut self, samples: &Samples) -> VisionXResult<()> {
+        match samples {
+            Samples::U8(values) => self.writer.write_all(values)?,
+            Samples::U16(values) => {
+                let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_be_bytes()).collect();
+                self.writer.write_all(&bytes)?
+            }
+            Samples::F32(_) => {
+                let err = "writing 32bit float tiles into png".to_string();
+                return Err(VisionXErrorKind::InvalidImageDepthSize(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> VisionXResult<()> {
+        self.writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Uncompressed BigTIFF (no limit on the file size) encoded strip by strip by the tiff crate, every band of tiles is a strip
+///
+/// Strips are written as they come and the image directory pointing to them is written by `finish()`. The file isn't buffered, strips are written whole and the encoder can't be flushed
+///
+/// ## Note
+/// Struct is used internally (private scope)
+struct TiffSink {
+    encoder: TiffEncoder<File, TiffKindBig>,
+    width: u32,
+    height: u32,
+    rows_per_strip: u32,
+    channels: u16,
+    bits: u16,
+    sample_format: SampleFormat,
+    offsets: Vec<u64>,
+    byte_counts: Vec<u64>,
+}
+
+/// Core implementation of struct `TiffSink`
+impl TiffSink {
+    /// Writes the header of a BigTIFF, the image directory is written by `finish()`
+    fn new(
+        file: File,
+        width: u32,
+        height: u32,
+        rows_per_strip: u32,
+        channels: usize,
+        samples: &Samples,
+    ) -> VisionXResult<Self> {
+        let (bits, sample_format) = match samples {
+            Samples::U8(_) => (8, SampleFormat::Uint),
+            Samples::U16(_) => (16, SampleFormat::Uint),
+            Samples::F32(_) => (32, SampleFormat::IEEEFP),
+        };
+        Ok(TiffSink {
+            encoder: TiffEncoder::new_big(file)?,
+            width,
+            height,
+            rows_per_strip,
+            channels: channels as u16,
+            bits,
+            sample_format,
+            offsets: Vec::new(),
+            byte_counts: Vec::new(),
+        })
+    }
+}
+
+/// Implements `RowSink` for `TiffSink`
+impl RowSink for TiffSink {
+    fn write_rows(&mut self, samples: &Samples) -> VisionXResult<()> {
+        let mut strip = self.encoder.extra_directory()?;
+        let (offset, byte_count) = match samples {
+            Samples::U8(values) => (strip.write_data(values.as_slice())?, values.len()),
+            Samples::U16(values) => (strip.write_data(values.as_slice())?, values.len() * 2),
+            Samples::F32(values) => (strip.write_data(values.as_slice())?, values.len() * 4),
+        };
+        // the directory only serves to append the strip, it would write an empty directory when dropped
+        std::mem::forget(strip);
+        self.offsets.push(offset);
+        self.byte_counts.push(byte_count as u64);
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> VisionXResult<()> {
+        let channels = self.channels as usize;
+        let photometric = if channels < 3 {
+            PhotometricInterpretation::BlackIsZero
+        } else {
+            PhotometricInterpretation::RGB
+        };
+
+        let mut directory = self.encoder.image_directory()?;
+        directory.write_tag(Tag::ImageWidth, self.width)?;
+        directory.write_tag(Tag::ImageLength, self.height)?;
+        directory.write_tag(Tag::BitsPerSample, &vec![self.bits; channels][..])?;
+        directory.write_tag(Tag::Compression, CompressionMethod::None.to_u16())?;
+        directory.write_tag(Tag::PhotometricInterpretation, photometric.to_u16())?;
+        directory.write_tag(Tag::StripOffsets, &self.offsets[..])?;
+        directory.write_tag(Tag::SamplesPerPixel, self.channels)?;
+        directory.write_tag(Tag::RowsPerStrip, self.rows_per_strip)?;
+        directory.write_tag(Tag::StripByteCounts, &self.byte_counts[..])?;
+        directory.write_tag(
+            Tag::PlanarConfiguration,
+            PlanarConfiguration::Chunky.to_u16(),
+        )?;
+        if channels % 2 == 0 {
+            directory.write_tag(Tag::ExtraSamples, ExtraSamples::UnassociatedAlpha.to_u16())?;
+        }
+        directory.write_tag(
+            Tag::SampleFormat,
+            &vec![self.sample_format.to_u16(); channels][..],
+        )?;
+        directory.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tiled_test {
+    use crate::core::{Image, ImageData};
+    use crate::io::{self, TiledReader, TiledWriter};
+    use ndarray::Array2;
+    use std::path::Path;
+
+    // Reads every tile of a file and places them back into a whole image
+    fn reassemble_rgb(path: &str, tile_width: u32, tile_height: u32) -> ImageData<u8, 3> {
+        let reader = TiledReader::open(path, tile_width, tile_height).unwrap();
+        let (width, height) = (reader.width(), reader.height());
+        let mut img = ImageData::new(
+            width,
+            height,
+            Array2::from_elem((height as usize, width as usize), [0; 3]),
+        );
+        for tile in reader {
+            let tile = tile.unwrap();
+            if let Image::ImageRgb(data) = tile.image() {
+                assert!(*data.width() <= tile_width && *data.height() <= tile_height);
+                let point2 = (tile.x() + *data.width(), tile.y() + *data.height());
+                img.roi_mut((tile.x(), tile.y()), point2)
+                    .unwrap()
+                    .pixels_mut()
+                    .assign(data.pixels());
+            } else {
+                panic!("unexpected {} tile", tile.image().to_str());
+            }
+        }
+        img
+    }
+
+    // Tiles written into a TIFF and a PNG are read back (tiled and whole) unchanged
+    #[test]
+    fn roundtrip() {
+        let img = match io::read("images/jpg/lenna.jpg").unwrap() {
+            Image::ImageRgb(rgb) => rgb.resize(100, 70),
+            value => panic!("unexpected {} image", value.to_str()),
+        };
+
+        for path in ["images/test/jade_tiled.tiff", "images/test/jade_tiled.png"] {
+            let mut writer = TiledWriter::create(path, 100, 70, 32, 16).unwrap();
+            for y in (0..70).step_by(16) {
+                // tiles of a band are written in any order
+                for x in (0..100).step_by(32).rev() {
+                    let point2 = ((x + 32).min(100), (y + 16).min(70));
+                    let tile = Image::ImageRgb(img.crop((x, y), point2).unwrap());
+                    writer.write_tile(x, y, &tile).unwrap();
+                }
+            }
+            writer.finish().unwrap();
+
+            match io::read(path).unwrap() {
+                Image::ImageRgb(rgb) => assert_eq!(img.pixels(), rgb.pixels()),
+                value => panic!("unexpected {} image", value.to_str()),
+            }
+            assert_eq!(img.pixels(), reassemble_rgb(path, 25, 30).pixels());
+        }
+        // TIFF is written as BigTIFF, i.e., not limited to 4 GiB
+        let bytes = std::fs::read("images/test/jade_tiled.tiff").unwrap();
+        assert_eq!(&bytes[..4], b"II+\0");
+
+        // alpha is written as an extra sample
+        let rgba16 = ImageData::new(
+            9,
+            7,
+            Array2::from_shape_fn((7, 9), |(y, x)| {
+                [(x * 7000 + y) as u16, 3, 4000, 65535 - y as u16]
+            }),
+        );
+        let path = "images/test/jade_tiled-rgba16.tiff";
+        let mut writer = TiledWriter::create(path, 9, 7, 5, 4).unwrap();
+        for (x, y) in [(0, 0), (5, 0), (5, 4), (0, 4)] {
+            let point2 = ((x + 5).min(9), (y + 4).min(7));
+            let tile = Image::ImageRgba16(rgba16.crop((x, y), point2).unwrap());
+            writer.write_tile(x, y, &tile).unwrap();
+        }
+        writer.finish().unwrap();
+        match io::read(path).unwrap() {
+            Image::ImageRgba16(img) => assert_eq!(rgba16.pixels(), img.pixels()),
+            value => panic!("unexpected {} image", value.to_str()),
+        }
+    }
+
+    // Strip TIFF, 16bit PNG and JPEG (decoded at once) are read in tiles
+    #[test]
+    fn formats() {
+        let img = match io::read("images/jpg/lenna.jpg").unwrap() {
+            Image::ImageRgb(rgb) => rgb,
+            value => panic!("unexpected {} image", value.to_str()),
+        };
+        assert_eq!(
+            img.pixels(),
+            reassemble_rgb("images/jpg/lenna.jpg", 100, 100).pixels()
+        );
+
+        let path = "images/test/jade_tiled-strips.tiff";
+        io::write(path, &Image::ImageRgb(img.clone())).unwrap();
+        assert_eq!(img.pixels(), reassemble_rgb(path, 64, 48).pixels());
+
+        let gray = img.convert_depth::<u16>().resize(50, 40);
+        let gray = ImageData::new(50, 40, gray.pixels().map(|px| [px[0]]));
+        let path = "images/test/jade_tiled-16.png";
+        io::write(path, &Image::ImageGrayscale16(gray.clone())).unwrap();
+        let tiles: Vec<_> = TiledReader::open(path, 16, 16)
+            .unwrap()
+            .map(|tile| tile.unwrap())
+            .collect();
+        assert_eq!(12, tiles.len());
+        match tiles[11].image() {
+            Image::ImageGrayscale16(data) => {
+                assert_eq!((48, 32), (tiles[11].x(), tiles[11].y()));
+                assert_eq!((2, 8), (*data.width(), *data.height()));
+                assert_eq!(data.pixels()[[0, 0]], gray.pixels()[[32, 48]]);
+            }
+            value => panic!("unexpected {} tile", value.to_str()),
+        }
+    }
+
+    // Misplaced, mis-sized and missing tiles are errors, unfinished files are removed
+    #[test]
+    fn errors() {
+        assert!(TiledReader::open("images/jpg/lenna.jpg", 0, 10).is_err());
+        assert!(TiledWriter::create("images/test/jade_tiled.jpg", 10, 10, 5, 5).is_err());
+
+        let tile = Image::ImageGrayscale(ImageData::new(5, 5, Array2::from_elem((5, 5), [7])));
+        let mut writer =
+            TiledWriter::create("images/test/jade_tiled-err.png", 10, 10, 5, 5).unwrap();
+        assert!(writer.write_tile(0, 5, &tile).is_err());
+        assert!(writer.write_tile(3, 0, &tile).is_err());
+        let small = Image::ImageGrayscale(ImageData::new(4, 5, Array2::from_elem((5, 4), [7])));
+        assert!(writer.write_tile(0, 0, &small).is_err());
+        writer.write_tile(0, 0, &tile).unwrap();
+        let rgb = Image::ImageRgb(ImageData::new(5, 5, Array2::from_elem((5, 5), [7; 3])));
+        assert!(writer.write_tile(5, 0, &rgb).is_err());
+        writer.write_tile(5, 0, &tile).unwrap();
+        assert!(writer.finish().is_err());
+        assert!(!Path::new("images/test/jade_tiled-err.png").exists());
+
+        for path in [
+            "images/test/jade_tiled-dropped.tiff",
+            "images/test/jade_tiled-dropped.png",
+        ] {
+            let mut writer = TiledWriter::create(path, 10, 10, 5, 5).unwrap();
+            writer.write_tile(0, 0, &tile).unwrap();
+            writer.write_tile(5, 0, &tile).unwrap();
+            assert!(Path::new(path).exists());
+            drop(writer);
+            assert!(!Path::new(path).exists());
+        }
+    }
+}
@@ -50,4 +50,7 @@ pub mod io;
 pub mod errors;
 
 /// Contains implementation of image processing tools/operations
-mod imgproc;
+pub mod imgproc;
+
+/// Contains conversions between vision_x types and the image crate's types (`DynamicImage` and `ImageBuffer`)
+mod convert;